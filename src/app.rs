@@ -8,6 +8,8 @@ use leptos::*;
 use leptos_meta::*;
 use leptos_router::*;
 
+pub use problems::{ProblemInfo, ProblemYear, YEARS};
+
 #[component]
 pub fn App() -> impl IntoView {
     // Provides context that manages stylesheets, titles, meta tags, etc.
@@ -15,7 +17,7 @@ pub fn App() -> impl IntoView {
 
     view! {
         <Stylesheet id="leptos" href="/pkg/aoc2023.css"/>
-        <Title text="Advent of Code 2023"/>
+        <Title text="Advent of Code"/>
         <Router fallback=|| {
             let mut outside_errors = Errors::default();
             outside_errors.insert_with_default_key(AppError::NotFound);
@@ -25,23 +27,37 @@ pub fn App() -> impl IntoView {
         }>
             <main>
                 <Routes>
-                    <Route path="" view=PageLayout>
-                        <problems::Routes />
-                        <Route path="" view=HomePage />
-                    </Route>
+                    <YearRoutes />
                 </Routes>
             </main>
         </Router>
     }
 }
 
+/// The routes of every year in `YEARS` and the home page, nested in the page layout
+#[component(transparent)]
+fn YearRoutes() -> impl IntoView {
+    // The router only finds routes among the direct children of a route, so they cannot be collected into a view
+    let children = || {
+        let year_routes = problems::YEARS.iter().map(|year| (year.routes)());
+        let home_route = view! { <Route path="" view=HomePage /> }.into_view();
+        Fragment::new(year_routes.chain([home_route]).collect())
+    };
+
+    Route(RouteProps::builder().path("").view(PageLayout).children(Box::new(children)).build())
+}
+
 #[component]
 fn PageLayout() -> impl IntoView {
     view! {
-        <h1>"Advent of Code 2023"</h1>
+        <h1>"Advent of Code"</h1>
         <nav>
             <ul>
-                <li><A href="problems">Problems</A></li>
+                { problems::YEARS.iter()
+                    .map(|year| view! {
+                        <li><A href=format!("{}/problems", year.year)>{ year.year }</A></li>
+                    })
+                    .collect_view() }
             </ul>
         </nav>
         <Outlet />
@@ -51,6 +67,6 @@ fn PageLayout() -> impl IntoView {
 #[component]
 fn HomePage() -> impl IntoView {
     view! {
-        <p>"Welcome to Advent of Code 2023!"</p>
+        <p>"Welcome to Advent of Code!"</p>
     }
 }
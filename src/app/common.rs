@@ -1,8 +1,64 @@
 #[cfg(feature = "ssr")]
+mod corridors;
+#[cfg(feature = "ssr")]
+mod cycle;
+#[cfg(feature = "ssr")]
+mod dense;
+#[cfg(feature = "ssr")]
+mod geometry;
+#[cfg(feature = "ssr")]
 mod graph;
+#[cfg(feature = "ssr")]
+mod graph_export;
+#[cfg(feature = "ssr")]
+mod grid;
+#[cfg(feature = "ssr")]
+mod interval;
+#[cfg(feature = "ssr")]
+mod memo;
+#[cfg(feature = "ssr")]
+mod parallel;
+#[cfg(feature = "ssr")]
+mod parser;
+#[cfg(feature = "ssr")]
+mod polyomino;
+#[cfg(all(test, feature = "ssr"))]
+mod property;
+#[cfg(feature = "ssr")]
+mod random;
+#[cfg(feature = "ssr")]
+mod solve_error;
 
 #[cfg(feature = "ssr")]
-pub use graph::{AdjacencyHashGraph, CycleResult, DistanceHashGraph};
+pub use corridors::{contract_corridors, CorridorGrid};
+#[cfg(feature = "ssr")]
+pub use cycle::{find_cycle, find_orbit, Cycle, Orbit};
+#[cfg(feature = "ssr")]
+pub use dense::{Bitset, DenseIndex, DenseMap, DenseSet, GridIndex};
+#[cfg(feature = "ssr")]
+pub use geometry::{LatticePolygon, PointLocation};
+#[cfg(feature = "ssr")]
+pub use graph::{shortest_distances, shortest_distances_in, shortest_path, shortest_path_in, AdjacencyHashGraph, Cut, DistanceHashGraph, ImplicitGraph, LongestPath, ShortestPath, VertexMap};
+#[cfg(feature = "ssr")]
+pub use graph_export::{ExportEdge, ExportNode, GraphExport};
+#[cfg(feature = "ssr")]
+pub use grid::{Direction, Grid, ParseGridError, WrappingGrid};
+#[cfg(feature = "ssr")]
+pub use interval::{Interval, IntervalBox, IntervalSet};
+#[cfg(feature = "ssr")]
+pub use memo::{Memo, MemoStats};
+#[cfg(feature = "ssr")]
+pub use parallel::{parallel_map, thread_count, with_thread_count, ParallelTime};
+#[cfg(feature = "ssr")]
+pub use parser::{ParseError, Parser};
+#[cfg(feature = "ssr")]
+pub use polyomino::Polyomino;
+#[cfg(all(test, feature = "ssr"))]
+pub use property::{check_property, shrink_number, shrink_vec};
+#[cfg(feature = "ssr")]
+pub use random::Random;
+#[cfg(feature = "ssr")]
+pub use solve_error::SolveError;
 
 #[macro_export]
 macro_rules! create_formatted_flat_enum {
@@ -0,0 +1,159 @@
+use std::collections::HashSet;
+use super::DistanceHashGraph;
+
+/// A maze on a grid of tiles, where each tile can be stepped onto from the tiles next to it
+pub trait CorridorGrid {
+    fn width(&self) -> usize;
+
+    fn height(&self) -> usize;
+
+    fn is_passable(&self, tile: (usize, usize)) -> bool;
+
+    /// Whether a step between two adjacent passable tiles is allowed, e.g. only down a slope
+    fn can_step(&self, _from: (usize, usize), _to: (usize, usize)) -> bool {
+        true
+    }
+}
+
+/// Contracts the single-width corridors of the grid that can be reached from `start` into the edges of a graph
+///
+/// The vertices are `start` and every passable tile that does not have exactly two passable neighbors, i.e. branch
+/// points and dead ends. An edge is only included in the directions in which every step along the corridor is allowed.
+pub fn contract_corridors<G: CorridorGrid>(grid: &G, start: (usize, usize)) -> DistanceHashGraph<(usize, usize)> {
+    let mut graph = DistanceHashGraph::new();
+    graph.insert_vertex(start);
+
+    let mut vertex_stack = vec![start];
+    let mut encountered_vertices = HashSet::from([start]);
+    while let Some(vertex) = vertex_stack.pop() {
+        for into in passable_neighbors(grid, vertex) {
+            if let Some((distance, destination)) = follow_corridor(grid, start, vertex, into) {
+                graph.insert_vertex(destination);
+                graph.insert_edge(&vertex, destination, distance);
+
+                if encountered_vertices.insert(destination) {
+                    vertex_stack.push(destination);
+                }
+            }
+        }
+    }
+
+    graph
+}
+
+/// Walks from `vertex` through `into` up to the next vertex, if every step on the way is allowed
+fn follow_corridor<G: CorridorGrid>(grid: &G, start: (usize, usize), vertex: (usize, usize), into: (usize, usize)) -> Option<(usize, (usize, usize))> {
+    let (mut previous, mut current) = (vertex, into);
+    let mut distance = 1usize;
+
+    loop {
+        if !grid.can_step(previous, current) {
+            return None;
+        }
+
+        let mut next_tiles = passable_neighbors(grid, current);
+        if current == start || next_tiles.len() != 2 {
+            return Some((distance, current));
+        }
+
+        // A corridor tile has exactly two passable neighbors, one of which is where we came from
+        next_tiles.retain(|tile| *tile != previous);
+        (previous, current) = (current, next_tiles[0]);
+        distance += 1;
+    }
+}
+
+fn passable_neighbors<G: CorridorGrid>(grid: &G, tile: (usize, usize)) -> Vec<(usize, usize)> {
+    let (x, y) = tile;
+    let mut result = Vec::new();
+
+    if x > 0 {
+        result.push((x - 1, y));
+    }
+    if y > 0 {
+        result.push((x, y - 1));
+    }
+    if x < grid.width() - 1 {
+        result.push((x + 1, y));
+    }
+    if y < grid.height() - 1 {
+        result.push((x, y + 1));
+    }
+
+    result.retain(|tile| grid.is_passable(*tile));
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::app::common::GraphExport;
+    use super::*;
+
+    /// A maze drawn with `#` for walls, where `>` can only be entered moving right
+    struct Maze(Vec<Vec<char>>);
+
+    impl Maze {
+        fn from_str(s: &str) -> Maze {
+            Maze(s.lines().map(|line| line.chars().collect()).collect())
+        }
+    }
+
+    impl CorridorGrid for Maze {
+        fn width(&self) -> usize {
+            self.0[0].len()
+        }
+
+        fn height(&self) -> usize {
+            self.0.len()
+        }
+
+        fn is_passable(&self, (x, y): (usize, usize)) -> bool {
+            self.0[y][x] != '#'
+        }
+
+        fn can_step(&self, (from_x, _): (usize, usize), (to_x, to_y): (usize, usize)) -> bool {
+            self.0[to_y][to_x] != '>' || to_x > from_x
+        }
+    }
+
+    fn edges(graph: &DistanceHashGraph<(usize, usize)>) -> Vec<String> {
+        let mut edges: Vec<String> = graph.edges().into_iter()
+            .map(|edge| format!("{} -{}-> {}", edge.from, edge.label.unwrap(), edge.to))
+            .collect();
+        edges.sort();
+        edges
+    }
+
+    #[test]
+    fn should_contract_corridors_between_branch_points() {
+        let maze = Maze::from_str("\
+            .####\n\
+            ...##\n\
+            .#.##\n\
+            .....\n\
+            ###.#");
+
+        assert_eq!(edges(&contract_corridors(&maze, (0, 0))), vec![
+            "(0, 0) -1-> (0, 1)",
+            "(0, 1) -1-> (0, 0)",
+            "(0, 1) -4-> (2, 3)",
+            "(0, 1) -4-> (2, 3)",
+            "(2, 3) -1-> (3, 3)",
+            "(2, 3) -4-> (0, 1)",
+            "(2, 3) -4-> (0, 1)",
+            "(3, 3) -1-> (2, 3)",
+            "(3, 3) -1-> (3, 4)",
+            "(3, 3) -1-> (4, 3)",
+            "(3, 4) -1-> (3, 3)",
+            "(4, 3) -1-> (3, 3)",
+        ]);
+    }
+
+    #[test]
+    fn should_only_follow_allowed_steps() {
+        let maze = Maze::from_str(".>.>.");
+
+        assert_eq!(edges(&contract_corridors(&maze, (0, 0))), vec!["(0, 0) -4-> (4, 0)"]);
+        assert_eq!(edges(&contract_corridors(&maze, (2, 0))), vec!["(2, 0) -2-> (4, 0)"]);
+    }
+}
@@ -0,0 +1,173 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Where the states of an iterated function start repeating
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cycle {
+    /// The number of states before the first state that repeats
+    pub tail_length: usize,
+    /// The number of steps after which every state from the first repeated one on comes back
+    pub period: usize,
+}
+
+impl Cycle {
+    /// The first index with the same state as `index`
+    pub fn reduce(&self, index: usize) -> usize {
+        if index < self.tail_length {
+            index
+        } else {
+            self.tail_length + (index - self.tail_length) % self.period
+        }
+    }
+}
+
+/// Finds the cycle of the states from `start` on, where `step` turns a state into the next one, with Brent's algorithm,
+/// which only keeps two states at a time
+///
+/// Loops forever if the states never repeat.
+pub fn find_cycle<S: Clone + PartialEq>(start: S, mut step: impl FnMut(&mut S)) -> Cycle {
+    // The hare runs ahead of the tortoise, which teleports to it every power of two steps until the hare catches up
+    // with it from behind; the steps since the last teleport are then the period
+    let mut power = 1usize;
+    let mut period = 1usize;
+    let mut tortoise = start.clone();
+    let mut hare = start.clone();
+    step(&mut hare);
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        step(&mut hare);
+        period += 1;
+    }
+
+    // With the hare a period ahead, the two first meet at the first repeated state
+    let mut tortoise = start.clone();
+    let mut hare = start;
+    for _ in 0..period {
+        step(&mut hare);
+    }
+
+    let mut tail_length = 0usize;
+    while tortoise != hare {
+        step(&mut tortoise);
+        step(&mut hare);
+        tail_length += 1;
+    }
+
+    Cycle { tail_length, period }
+}
+
+/// The states of an iterated function up to the first repeated one, which determine the state at any index
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Orbit<S> {
+    /// The tail, followed by a single period
+    states: Vec<S>,
+    cycle: Cycle,
+}
+
+impl<S> Orbit<S> {
+    pub fn cycle(&self) -> Cycle {
+        self.cycle
+    }
+
+    /// The tail, followed by a single period
+    pub fn states(&self) -> &[S] {
+        &self.states
+    }
+
+    /// The state after `index` steps
+    pub fn get(&self, index: usize) -> &S {
+        &self.states[self.cycle.reduce(index)]
+    }
+}
+
+/// Finds the cycle of the states from `start` on by remembering every state, which takes a single step per state
+///
+/// Loops forever if the states never repeat.
+pub fn find_orbit<S: Clone + Eq + Hash>(start: S, mut step: impl FnMut(&mut S)) -> Orbit<S> {
+    let mut indices: HashMap<S, usize> = HashMap::new();
+    let mut states: Vec<S> = Vec::new();
+    let mut state = start;
+
+    loop {
+        if let Some(&tail_length) = indices.get(&state) {
+            let period = states.len() - tail_length;
+            return Orbit { states, cycle: Cycle { tail_length, period } };
+        }
+
+        indices.insert(state.clone(), states.len());
+        states.push(state.clone());
+        step(&mut state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::app::common::{check_property, shrink_number, shrink_vec, Random};
+    use super::*;
+
+    /// Steps through the map until a state comes back, comparing with every earlier state
+    fn reference_cycle(map: &[usize]) -> Cycle {
+        let mut states = vec![0usize];
+        loop {
+            let next = map[*states.last().unwrap()];
+            if let Some(tail_length) = states.iter().position(|state| *state == next) {
+                return Cycle { tail_length, period: states.len() - tail_length };
+            }
+            states.push(next);
+        }
+    }
+
+    #[test]
+    fn should_match_reference_cycle() {
+        check_property(
+            500,
+            |random: &mut Random, size| {
+                let length = 1 + random.below(size + 1);
+                (0..length).map(|_| random.below(length)).collect::<Vec<_>>()
+            },
+            // Taking the next states modulo the length keeps a map with a state left out closed
+            |map| shrink_vec(map, |next| shrink_number(*next)).into_iter()
+                .filter(|shrunk| !shrunk.is_empty())
+                .map(|shrunk| {
+                    let length = shrunk.len();
+                    shrunk.into_iter().map(|next| next % length).collect()
+                })
+                .collect(),
+            |map| {
+                let expected = reference_cycle(map);
+                let brent = find_cycle(0usize, |state| *state = map[*state]);
+                let orbit = find_orbit(0usize, |state| *state = map[*state]);
+
+                if brent != expected {
+                    return Err(format!("Brent found {:?}, expected {:?}", brent, expected));
+                }
+                if orbit.cycle() != expected {
+                    return Err(format!("hashing found {:?}, expected {:?}", orbit.cycle(), expected));
+                }
+
+                let mut state = 0usize;
+                for index in 0..3 * map.len() {
+                    if *orbit.get(index) != state {
+                        return Err(format!("state {} is {}, expected {}", index, orbit.get(index), state));
+                    }
+                    state = map[state];
+                }
+                Ok(())
+            },
+        );
+    }
+
+    #[test]
+    fn should_evaluate_far_index() {
+        // 0 -> 1 -> 2 -> 3 -> 4 -> 2
+        let orbit = find_orbit(0usize, |state| *state = if *state == 4 { 2 } else { *state + 1 });
+
+        assert_eq!(orbit.cycle(), Cycle { tail_length: 2, period: 3 });
+        assert_eq!(orbit.states(), &[0, 1, 2, 3, 4]);
+        assert_eq!(*orbit.get(1_000_000_000), 2 + (1_000_000_000 - 2) % 3);
+    }
+}
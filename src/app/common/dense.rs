@@ -0,0 +1,296 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use std::ops::Index;
+
+/// A set of the numbers below a size fixed up front
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Bitset(Vec<u64>);
+
+impl Bitset {
+    pub fn new(size: usize) -> Self {
+        Bitset(vec![0; size.div_ceil(64)])
+    }
+
+    pub fn contains(&self, index: usize) -> bool {
+        self.0[index / 64] & (1 << (index % 64)) != 0
+    }
+
+    /// Whether the number was not in the set yet
+    pub fn insert(&mut self, index: usize) -> bool {
+        let is_new = !self.contains(index);
+        self.0[index / 64] |= 1 << (index % 64);
+        is_new
+    }
+
+    pub fn remove(&mut self, index: usize) {
+        self.0[index / 64] &= !(1 << (index % 64));
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.iter().all(|word| *word == 0)
+    }
+}
+
+/// Numbers some of the keys of a type from 0 up to a size known up front
+pub trait DenseIndex<K> {
+    fn size(&self) -> usize;
+
+    /// The number of the key, or `None` if it is not one of the numbered keys
+    fn index(&self, key: &K) -> Option<usize>;
+}
+
+/// Numbers the coordinates in a window of a grid row by row, starting from its top left corner at `origin`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GridIndex {
+    origin: (isize, isize),
+    width: usize,
+    height: usize,
+}
+
+impl GridIndex {
+    /// The window of a grid of the given size, at its usual place with `(0, 0)` in the top left corner
+    pub fn new(width: usize, height: usize) -> GridIndex {
+        GridIndex { origin: (0, 0), width, height }
+    }
+
+    pub fn with_origin(origin: (isize, isize), width: usize, height: usize) -> GridIndex {
+        GridIndex { origin, width, height }
+    }
+}
+
+impl DenseIndex<(isize, isize)> for GridIndex {
+    fn size(&self) -> usize {
+        self.width * self.height
+    }
+
+    fn index(&self, &(x, y): &(isize, isize)) -> Option<usize> {
+        let (column, row) = (x.checked_sub(self.origin.0)?, y.checked_sub(self.origin.1)?);
+        let (column, row) = (usize::try_from(column).ok()?, usize::try_from(row).ok()?);
+        (column < self.width && row < self.height).then_some(row * self.width + column)
+    }
+}
+
+impl DenseIndex<(usize, usize)> for GridIndex {
+    fn size(&self) -> usize {
+        self.width * self.height
+    }
+
+    fn index(&self, &(x, y): &(usize, usize)) -> Option<usize> {
+        self.index(&(isize::try_from(x).ok()?, isize::try_from(y).ok()?))
+    }
+}
+
+/// A map kept in a flat `Vec` for the keys its index numbers, and in a hash map for any others
+#[derive(Debug, Clone)]
+pub struct DenseMap<K, V, I> {
+    index: I,
+    dense: Vec<Option<V>>,
+    sparse: HashMap<K, V>,
+    len: usize,
+}
+
+impl<K: Eq + Hash, V, I: DenseIndex<K>> DenseMap<K, V, I> {
+    pub fn new(index: I) -> DenseMap<K, V, I> {
+        let dense = std::iter::repeat_with(|| None).take(index.size()).collect();
+        DenseMap { index, dense, sparse: HashMap::new(), len: 0 }
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        match self.index.index(key) {
+            Some(index) => self.dense[index].as_ref(),
+            None => self.sparse.get(key),
+        }
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// The value that was there before, if any
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let previous = match self.index.index(&key) {
+            Some(index) => self.dense[index].replace(value),
+            None => self.sparse.insert(key, value),
+        };
+        if previous.is_none() {
+            self.len += 1;
+        }
+        previous
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.dense.iter().flatten().chain(self.sparse.values())
+    }
+}
+
+impl<K: Eq + Hash, V, I: DenseIndex<K>> Index<&K> for DenseMap<K, V, I> {
+    type Output = V;
+
+    /// Panics if the key is not in the map
+    fn index(&self, key: &K) -> &Self::Output {
+        self.get(key).expect("key not in dense map")
+    }
+}
+
+/// A set kept in a bitset for the keys its index numbers, and in a hash set for any others
+#[derive(Debug, Clone)]
+pub struct DenseSet<K, I> {
+    index: I,
+    dense: Bitset,
+    sparse: HashSet<K>,
+    len: usize,
+}
+
+impl<K: Eq + Hash, I: DenseIndex<K>> DenseSet<K, I> {
+    pub fn new(index: I) -> DenseSet<K, I> {
+        let dense = Bitset::new(index.size());
+        DenseSet { index, dense, sparse: HashSet::new(), len: 0 }
+    }
+
+    pub fn contains(&self, key: &K) -> bool {
+        match self.index.index(key) {
+            Some(index) => self.dense.contains(index),
+            None => self.sparse.contains(key),
+        }
+    }
+
+    /// Whether the key was not in the set yet
+    pub fn insert(&mut self, key: K) -> bool {
+        let is_new = match self.index.index(&key) {
+            Some(index) => self.dense.insert(index),
+            None => self.sparse.insert(key),
+        };
+        if is_new {
+            self.len += 1;
+        }
+        is_new
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_keep_bits() {
+        let mut bitset = Bitset::new(130);
+        assert!(bitset.insert(0) && bitset.insert(64) && bitset.insert(129));
+        assert!(!bitset.insert(64));
+        assert_eq!(bitset.len(), 3);
+
+        bitset.remove(64);
+        assert!(bitset.contains(129) && !bitset.contains(64) && !bitset.contains(1));
+        assert_eq!(bitset.len(), 2);
+    }
+
+    #[test]
+    fn should_number_coordinates_inside_window() {
+        let index = GridIndex::with_origin((-2, 3), 4, 2);
+        assert_eq!(DenseIndex::<(isize, isize)>::size(&index), 8);
+        assert_eq!(index.index(&(-2isize, 3isize)), Some(0));
+        assert_eq!(index.index(&(1isize, 4isize)), Some(7));
+        assert_eq!(index.index(&(2isize, 4isize)), None);
+        assert_eq!(index.index(&(-3isize, 3isize)), None);
+        assert_eq!(GridIndex::new(3, 3).index(&(usize::MAX, 0usize)), None);
+    }
+
+    #[test]
+    fn should_fall_back_to_sparse_storage() {
+        let mut map = DenseMap::new(GridIndex::new(2, 2));
+        assert_eq!(map.insert((1isize, 1isize), 'a'), None);
+        assert_eq!(map.insert((-5, 8), 'b'), None);
+        assert_eq!(map.insert((1, 1), 'c'), Some('a'));
+
+        assert_eq!(map.len(), 2);
+        assert_eq!((map[&(1, 1)], map[&(-5, 8)]), ('c', 'b'));
+        assert!(!map.contains_key(&(0, 0)));
+        let mut values: Vec<char> = map.values().copied().collect();
+        values.sort();
+        assert_eq!(values, vec!['b', 'c']);
+
+        let mut set = DenseSet::new(GridIndex::new(2, 2));
+        assert!(set.insert((0usize, 1usize)) && set.insert((7, 7)));
+        assert!(!set.insert((0, 1)) && !set.insert((7, 7)));
+        assert!(set.contains(&(7, 7)) && !set.contains(&(1, 0)));
+        assert_eq!(set.len(), 2);
+    }
+}
+
+#[cfg(test)]
+mod benches {
+    extern crate test;
+
+    use test::Bencher;
+    use super::*;
+
+    /// The tiles of a grid the size of most puzzle inputs, in a scattered order, each visited a few times
+    fn tiles() -> Vec<(usize, usize)> {
+        (0..3 * 140 * 140).map(|i| ((i * 7919) % 140, (i * 104729 / 140) % 140)).collect()
+    }
+
+    #[bench]
+    fn bench_hash_set(bencher: &mut Bencher) {
+        let tiles = tiles();
+
+        bencher.iter(|| {
+            let mut set = HashSet::new();
+            tiles.iter().filter(|tile| set.insert(**tile)).count()
+        });
+    }
+
+    #[bench]
+    fn bench_dense_set(bencher: &mut Bencher) {
+        let tiles = tiles();
+
+        bencher.iter(|| {
+            let mut set = DenseSet::new(GridIndex::new(140, 140));
+            tiles.iter().filter(|tile| set.insert(**tile)).count()
+        });
+    }
+
+    #[bench]
+    fn bench_hash_map(bencher: &mut Bencher) {
+        let tiles = tiles();
+
+        bencher.iter(|| {
+            let mut map = HashMap::new();
+            for (i, tile) in tiles.iter().enumerate() {
+                map.insert(*tile, i);
+            }
+            tiles.iter().map(|tile| map[tile]).sum::<usize>()
+        });
+    }
+
+    #[bench]
+    fn bench_dense_map(bencher: &mut Bencher) {
+        let tiles = tiles();
+
+        bencher.iter(|| {
+            let mut map = DenseMap::new(GridIndex::new(140, 140));
+            for (i, tile) in tiles.iter().enumerate() {
+                map.insert(*tile, i);
+            }
+            tiles.iter().map(|tile| map[tile]).sum::<usize>()
+        });
+    }
+}
@@ -0,0 +1,211 @@
+use std::collections::BTreeSet;
+use crate::app::math::Gcd;
+
+/// Where a point is, relative to a polygon
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PointLocation {
+    Inside,
+    OnBoundary,
+    Outside,
+}
+
+/// A polygon with lattice points as corners, in order along the boundary and with the last corner connected back to
+/// the first
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LatticePolygon(Vec<(isize, isize)>);
+
+impl LatticePolygon {
+    pub fn new(corners: Vec<(isize, isize)>) -> LatticePolygon {
+        LatticePolygon(corners)
+    }
+
+    /// Every edge as its two ends, starting from the edge from the first corner to the second
+    fn edges(&self) -> impl Iterator<Item = ((isize, isize), (isize, isize))> + '_ {
+        self.0.iter().zip(self.0.iter().cycle().skip(1)).map(|(from, to)| (*from, *to))
+    }
+
+    /// Twice the area by the shoelace formula, which is an integer for every lattice polygon, if it does not cross
+    /// itself
+    pub fn double_area(&self) -> isize {
+        self.edges()
+            .map(|((from_x, from_y), (to_x, to_y))| from_x * to_y - to_x * from_y)
+            .sum::<isize>()
+            .abs()
+    }
+
+    /// The number of lattice points on the boundary
+    pub fn boundary_count(&self) -> isize {
+        self.edges()
+            .map(|((from_x, from_y), (to_x, to_y))| from_x.abs_diff(to_x).gcd(from_y.abs_diff(to_y)).gcd as isize)
+            .sum()
+    }
+
+    /// The number of lattice points strictly inside, by Pick's theorem, if the polygon does not cross itself
+    pub fn interior_count(&self) -> isize {
+        // Pick's theorem: area = interior + boundary / 2 - 1
+        (self.double_area() - self.boundary_count() + 2) / 2
+    }
+
+    /// Whether the point is inside the polygon, by counting the edges crossed by a ray from it in the direction of x
+    pub fn locate(&self, (x, y): (isize, isize)) -> PointLocation {
+        let mut is_inside = false;
+
+        for ((from_x, from_y), (to_x, to_y)) in self.edges() {
+            let cross = (to_x - from_x) * (y - from_y) - (to_y - from_y) * (x - from_x);
+            if cross == 0 && from_x.min(to_x) <= x && x <= from_x.max(to_x) && from_y.min(to_y) <= y && y <= from_y.max(to_y) {
+                return PointLocation::OnBoundary;
+            }
+
+            // Counting an edge from its lower end up to but not including its upper end counts a corner the ray
+            // passes through once if the boundary crosses the ray there, and zero or two times if it only touches it
+            if (from_y <= y) != (to_y <= y) {
+                let crosses_right = if to_y > from_y { cross > 0 } else { cross < 0 };
+                is_inside ^= crosses_right;
+            }
+        }
+
+        if is_inside { PointLocation::Inside } else { PointLocation::Outside }
+    }
+
+    /// Two edges that meet, other than consecutive edges at their shared corner, as the indices of their first corners
+    ///
+    /// Only for polygons with horizontal and vertical edges; panics on any other edge.
+    pub fn find_self_intersection(&self) -> Option<(usize, usize)> {
+        let corner_count = self.0.len();
+        let are_consecutive = |lhs: usize, rhs: usize| (lhs + 1) % corner_count == rhs || (rhs + 1) % corner_count == lhs;
+        let mut horizontal: Vec<(isize, isize, isize, usize)> = Vec::new();
+        let mut vertical: Vec<(isize, isize, isize, usize)> = Vec::new();
+
+        for (index, ((from_x, from_y), (to_x, to_y))) in self.edges().enumerate() {
+            if from_y == to_y {
+                horizontal.push((from_y, from_x.min(to_x), from_x.max(to_x), index));
+            } else if from_x == to_x {
+                vertical.push((from_x, from_y.min(to_y), from_y.max(to_y), index));
+            } else {
+                panic!("Edge from ({}, {}) to ({}, {}) is neither horizontal nor vertical", from_x, from_y, to_x, to_y);
+            }
+        }
+
+        find_collinear_overlap(&mut horizontal, are_consecutive)
+            .or_else(|| find_collinear_overlap(&mut vertical, are_consecutive))
+            .or_else(|| find_crossing(&horizontal, &vertical, are_consecutive))
+    }
+}
+
+/// Two segments on the same line that overlap, or touch without being consecutive, for segments given as their line,
+/// their lower and upper end and their index
+fn find_collinear_overlap(segments: &mut [(isize, isize, isize, usize)], are_consecutive: impl Fn(usize, usize) -> bool) -> Option<(usize, usize)> {
+    segments.sort();
+
+    // The segment reaching furthest along the line among the ones before
+    let mut furthest: Option<(isize, isize, usize)> = None;
+    for &(line, lower, upper, index) in segments.iter() {
+        if let Some((furthest_line, furthest_upper, furthest_index)) = furthest.filter(|(furthest_line, _, _)| *furthest_line == line) {
+            if lower < furthest_upper || (lower == furthest_upper && !are_consecutive(index, furthest_index)) {
+                return Some((furthest_index.min(index), furthest_index.max(index)));
+            }
+            if furthest_upper >= upper {
+                continue;
+            }
+        }
+        furthest = Some((line, upper, index));
+    }
+
+    None
+}
+
+/// A horizontal and a vertical segment that meet, other than consecutive ones at their shared corner, by sweeping a
+/// vertical line from left to right over the horizontal segments
+fn find_crossing(horizontal: &[(isize, isize, isize, usize)], vertical: &[(isize, isize, isize, usize)], are_consecutive: impl Fn(usize, usize) -> bool) -> Option<(usize, usize)> {
+    // At the same x, horizontal segments are added before and removed after vertical ones are checked against them
+    let mut events: Vec<(isize, u8, usize)> = Vec::new();
+    for (position, &(_, left, right, _)) in horizontal.iter().enumerate() {
+        events.push((left, 0, position));
+        events.push((right, 2, position));
+    }
+    for (position, &(x, _, _, _)) in vertical.iter().enumerate() {
+        events.push((x, 1, position));
+    }
+    events.sort();
+
+    let mut active: BTreeSet<(isize, usize)> = BTreeSet::new();
+    for (_, kind, position) in events {
+        match kind {
+            0 => {
+                let (y, _, _, index) = horizontal[position];
+                active.insert((y, index));
+            },
+            2 => {
+                let (y, _, _, index) = horizontal[position];
+                active.remove(&(y, index));
+            },
+            _ => {
+                // Only the at most two consecutive segments can be skipped before another one shows up
+                let (_, lower, upper, index) = vertical[position];
+                let crossed = active.range((lower, 0)..=(upper, usize::MAX))
+                    .map(|(_, horizontal_index)| *horizontal_index)
+                    .find(|horizontal_index| !are_consecutive(index, *horizontal_index));
+                if let Some(horizontal_index) = crossed {
+                    return Some((horizontal_index.min(index), horizontal_index.max(index)));
+                }
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An L shape: a 4 by 2 rectangle with a 2 by 2 square on top of its left half
+    fn l_shape() -> LatticePolygon {
+        LatticePolygon::new(vec![(0, 0), (4, 0), (4, 2), (2, 2), (2, 4), (0, 4)])
+    }
+
+    #[test]
+    fn should_count_lattice_points() {
+        let polygon = l_shape();
+
+        assert_eq!(polygon.double_area(), 24);
+        assert_eq!(polygon.boundary_count(), 16);
+        assert_eq!(polygon.interior_count(), 5);
+
+        let triangle = LatticePolygon::new(vec![(0, 0), (4, 0), (0, 4)]);
+        assert_eq!(triangle.double_area(), 16);
+        assert_eq!(triangle.boundary_count(), 12);
+        assert_eq!(triangle.interior_count(), 3);
+    }
+
+    #[test]
+    fn should_locate_points() {
+        let polygon = l_shape();
+        let inside: Vec<_> = (-1..=5)
+            .flat_map(|y| (-1..=5).map(move |x| (x, y)))
+            .filter(|point| polygon.locate(*point) == PointLocation::Inside)
+            .collect();
+
+        assert_eq!(inside, vec![(1, 1), (2, 1), (3, 1), (1, 2), (1, 3)]);
+        assert_eq!(polygon.locate((3, 2)), PointLocation::OnBoundary);
+        assert_eq!(polygon.locate((3, 3)), PointLocation::Outside);
+        assert_eq!(polygon.locate((5, 2)), PointLocation::Outside);
+    }
+
+    #[test]
+    fn should_find_self_intersection() {
+        assert_eq!(l_shape().find_self_intersection(), None);
+
+        // A figure eight, crossing at (2, 2)
+        let crossing = LatticePolygon::new(vec![(0, 0), (2, 0), (2, 4), (4, 4), (4, 2), (0, 2)]);
+        assert_eq!(crossing.find_self_intersection(), Some((1, 4)));
+
+        // Two squares touching at a corner
+        let touching = LatticePolygon::new(vec![(0, 0), (2, 0), (2, 2), (4, 2), (4, 4), (2, 4), (2, 2), (0, 2)]);
+        assert!(touching.find_self_intersection().is_some());
+
+        // Doubling back along the same line
+        let doubling_back = LatticePolygon::new(vec![(0, 0), (4, 0), (2, 0), (2, 2), (0, 2)]);
+        assert!(doubling_back.find_self_intersection().is_some());
+    }
+}
@@ -1,7 +1,14 @@
-use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
 use std::fmt::{Debug, Formatter};
 use std::hash::Hash;
-use std::iter::once;
+use super::dense::{DenseIndex, DenseMap};
+use super::graph_export::{ExportEdge, ExportNode, GraphExport};
+
+mod longest_path;
+mod structure;
+
+pub use longest_path::LongestPath;
 
 #[derive(Clone)]
 pub struct DistanceHashGraph<Vertex>(HashMap<Vertex, Vec<(usize, Vertex)>>);
@@ -13,29 +20,6 @@ impl<Vertex> DistanceHashGraph<Vertex> {
 }
 
 impl<Vertex: Eq + Hash> DistanceHashGraph<Vertex> {
-    pub fn longest_distance_between(&self, from: Vertex, to: Vertex) -> Option<usize> {
-        let mut results: BinaryHeap<usize> = BinaryHeap::new();
-        let mut state_stack: Vec<(usize, HashSet<&Vertex>, &Vertex)> = vec![(0, HashSet::from([&from]), &from)];
-
-        while let Some((distance, visited, vertex)) = state_stack.pop() {
-            if let Some(next_vertices) = self.0.get(vertex) {
-                for (next_distance, next_vertex) in next_vertices.iter() {
-                    let distance = distance + *next_distance;
-                    if next_vertex == &to {
-                        results.push(distance);
-                    } else if !visited.contains(&next_vertex) {
-                        let mut next_visited = visited.clone();
-                        next_visited.insert(next_vertex);
-
-                        state_stack.push((distance, next_visited, next_vertex));
-                    }
-                }
-            }
-        }
-
-        results.pop()
-    }
-
     /// Inserts a vertex into the graph
     pub fn insert_vertex(&mut self, vertex: Vertex) {
         if !self.0.contains_key(&vertex) {
@@ -70,6 +54,24 @@ impl<Vertex: Debug + Hash> Debug for DistanceHashGraph<Vertex> {
     }
 }
 
+impl<Vertex: Debug> GraphExport for DistanceHashGraph<Vertex> {
+    fn nodes(&self) -> Vec<ExportNode> {
+        self.0.keys()
+            .map(|vertex| ExportNode { id: format!("{:?}", vertex), shape: None })
+            .collect()
+    }
+
+    fn edges(&self) -> Vec<ExportEdge> {
+        self.0.iter()
+            .flat_map(|(from, edges)| edges.iter().map(move |(distance, to)| ExportEdge {
+                from: format!("{:?}", from),
+                to: format!("{:?}", to),
+                label: Some(distance.to_string()),
+            }))
+            .collect()
+    }
+}
+
 pub struct AdjacencyHashGraph<Vertex>(HashMap<Vertex, HashSet<Vertex>>);
 
 impl<Vertex> AdjacencyHashGraph<Vertex> {
@@ -79,106 +81,36 @@ impl<Vertex> AdjacencyHashGraph<Vertex> {
 }
 
 impl<Vertex: Clone + Eq + Hash> AdjacencyHashGraph<Vertex> {
-    // well, assuming the number is at most two for now
-    pub fn splits_graph(&self, edge: (&Vertex, &Vertex), forbidden_edges: Vec<(&Vertex, &Vertex)>) -> CycleResult<Vertex> {
-        let mut forbidden_edges: HashSet<_> = forbidden_edges.into_iter()
-            .flat_map(|(from, to)| once((from, to)).chain(once((to, from))))
-            .collect();
-
-        let (left, right) = edge;
-        forbidden_edges.insert((left, right));
-        forbidden_edges.insert((right, left));
-
-        let mut left_visited: HashMap<&Vertex, Vec<&Vertex>> = HashMap::from([(left, vec![left])]);
-        let mut left_stack: Vec<(&Vertex, Vec<&Vertex>)> = vec![(left, vec![left])];
-        let mut right_visited: HashMap<&Vertex, Vec<&Vertex>> = HashMap::from([(right, vec![right])]);
-        let mut right_stack: Vec<(&Vertex, Vec<&Vertex>)> = vec![(right, vec![right])];
-
-        while left_stack.len() > 0 || right_stack.len() > 0 {
-            if let Some((current_left, left_cycle_stack)) = left_stack.pop() {
-                for adjacent in self.0.get(current_left).unwrap() {
-                    if !forbidden_edges.contains(&(current_left, adjacent)) {
-                        if let Some(right_cycle_stack) = right_visited.get(adjacent) {
-                            let mut cycle: HashSet<(Vertex, Vertex)> = HashSet::new();
-
-                            // Add original edge
-                            cycle.insert((left.clone(), right.clone()));
-                            cycle.insert((right.clone(), left.clone()));
-
-                            // Add left edges
-                            for i in 0..(left_cycle_stack.len() - 1) {
-                                cycle.insert((left_cycle_stack[i].clone(), left_cycle_stack[i + 1].clone()));
-                                cycle.insert((left_cycle_stack[i + 1].clone(), left_cycle_stack[i].clone()));
-                            }
-
-                            // Add right edges
-                            for i in 0..(right_cycle_stack.len() - 1) {
-                                cycle.insert((right_cycle_stack[i].clone(), right_cycle_stack[i + 1].clone()));
-                                cycle.insert((right_cycle_stack[i + 1].clone(), right_cycle_stack[i].clone()));
-                            }
-
-                            // Add new edge
-                            cycle.insert(((*left_cycle_stack.last().unwrap()).clone(), (*right_cycle_stack.last().unwrap()).clone()));
-                            cycle.insert(((*right_cycle_stack.last().unwrap()).clone(), (*left_cycle_stack.last().unwrap()).clone()));
-
-                            return CycleResult::Cycle(cycle);
-                        }
-
-                        if !left_visited.contains_key(adjacent) {
-                            let mut left_cycle_stack = left_cycle_stack.clone();
-                            left_cycle_stack.push(adjacent);
-
-                            left_visited.insert(adjacent, left_cycle_stack.clone());
-                            left_stack.push((adjacent, left_cycle_stack));
-                        }
-                    }
-                }
-            }
-
-            if let Some((current_right, right_cycle_stack)) = right_stack.pop() {
-                for adjacent in self.0.get(current_right).unwrap() {
-                    if !forbidden_edges.contains(&(current_right, adjacent)) {
-                        if let Some(left_cycle_stack) = left_visited.get(adjacent) {
-                            let mut cycle: HashSet<(Vertex, Vertex)> = HashSet::new();
-
-                            // Add original edge
-                            cycle.insert((left.clone(), right.clone()));
-                            cycle.insert((right.clone(), left.clone()));
-
-                            // Add left edges
-                            for i in 0..(left_cycle_stack.len() - 1) {
-                                cycle.insert((left_cycle_stack[i].clone(), left_cycle_stack[i + 1].clone()));
-                                cycle.insert((left_cycle_stack[i + 1].clone(), left_cycle_stack[i].clone()));
-                            }
-
-                            // Add right edges
-                            for i in 0..(right_cycle_stack.len() - 1) {
-                                cycle.insert((right_cycle_stack[i].clone(), right_cycle_stack[i + 1].clone()));
-                                cycle.insert((right_cycle_stack[i + 1].clone(), right_cycle_stack[i].clone()));
-                            }
-
-                            // Add new edge
-                            cycle.insert(((*left_cycle_stack.last().unwrap()).clone(), (*right_cycle_stack.last().unwrap()).clone()));
-                            cycle.insert(((*right_cycle_stack.last().unwrap()).clone(), (*left_cycle_stack.last().unwrap()).clone()));
-
-                            return CycleResult::Cycle(cycle);
-                        }
-
-                        if !right_visited.contains_key(adjacent) {
-                            let mut right_cycle_stack = right_cycle_stack.clone();
-                            right_cycle_stack.push(adjacent);
-
-                            right_visited.insert(adjacent, right_cycle_stack.clone());
-                            right_stack.push((adjacent, right_cycle_stack));
-                        }
-                    }
-                }
+    /// The smallest set of edges whose removal splits the graph in two, treating every edge as undirected
+    ///
+    /// Returns `None` if the graph has fewer than two vertices
+    pub fn minimum_cut(&self) -> Option<Cut<Vertex>> {
+        let network = FlowNetwork::from(self);
+        let mut best: Option<(usize, usize)> = None;
+
+        // Every cut separates the first vertex from some other vertex
+        for sink in 1..network.vertex_count() {
+            let limit = best.map_or(usize::MAX, |(size, _)| size);
+            let size = network.augment(&mut network.empty_flow(), 0, sink, limit);
+
+            if size < limit {
+                best = Some((size, sink));
             }
         }
 
-        CycleResult::Split(left_visited.len(), right_visited.len())
+        best.map(|(_, sink)| network.cut(0, sink))
     }
 
+    /// The smallest set of edges whose removal separates `source` from `sink`, treating every edge as undirected
+    ///
+    /// Panics if either vertex is not in the graph
+    pub fn minimum_cut_between(&self, source: &Vertex, sink: &Vertex) -> Cut<Vertex> {
+        let network = FlowNetwork::from(self);
+        let source = network.index_of(source);
+        let sink = network.index_of(sink);
+
+        network.cut(source, sink)
+    }
 }
 
 impl<Vertex: Eq + Hash> AdjacencyHashGraph<Vertex> {
@@ -217,8 +149,394 @@ impl<Vertex: Debug + Hash> Debug for AdjacencyHashGraph<Vertex> {
     }
 }
 
-#[derive(Debug, Clone)]
-pub enum CycleResult<Vertex> {
-    Split(usize, usize),
-    Cycle(HashSet<(Vertex, Vertex)>)
+impl<Vertex: Debug + Eq + Hash> GraphExport for AdjacencyHashGraph<Vertex> {
+    fn nodes(&self) -> Vec<ExportNode> {
+        self.0.keys()
+            .map(|vertex| ExportNode { id: format!("{:?}", vertex), shape: None })
+            .collect()
+    }
+
+    /// Every edge once if the graph is undirected, from the lesser vertex to the greater one
+    fn edges(&self) -> Vec<ExportEdge> {
+        let is_directed = self.is_directed();
+
+        self.0.iter()
+            .flat_map(|(from, adjacent)| adjacent.iter().map(move |to| (format!("{:?}", from), format!("{:?}", to))))
+            .filter(|(from, to)| is_directed || from <= to)
+            .map(|(from, to)| ExportEdge { from, to, label: None })
+            .collect()
+    }
+
+    /// Whether some edge is missing its reverse
+    fn is_directed(&self) -> bool {
+        self.0.iter()
+            .any(|(from, adjacent)| adjacent.iter().any(|to| !self.0.get(to).is_some_and(|reverse| reverse.contains(from))))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cut<Vertex> {
+    pub size: usize,
+    /// The edges across the cut, from the side of the source to the other side
+    pub edges: Vec<(Vertex, Vertex)>,
+    /// The number of vertices on the side of the source, and on the other side
+    pub partition_sizes: (usize, usize),
+}
+
+/// An undirected graph with compactly indexed vertices, where every edge can carry one unit of flow in either direction
+struct FlowNetwork<'a, Vertex> {
+    vertices: Vec<&'a Vertex>,
+    indices: HashMap<&'a Vertex, usize>,
+    /// For every vertex, its neighbors and the position of the edge back in the neighbor's list
+    edges: Vec<Vec<(usize, usize)>>,
+}
+
+impl<'a, Vertex: Eq + Hash> From<&'a AdjacencyHashGraph<Vertex>> for FlowNetwork<'a, Vertex> {
+    fn from(graph: &'a AdjacencyHashGraph<Vertex>) -> Self {
+        let vertices: Vec<&Vertex> = graph.0.keys().collect();
+        let indices: HashMap<&Vertex, usize> = vertices.iter().enumerate().map(|(index, vertex)| (*vertex, index)).collect();
+
+        let undirected_edges: HashSet<(usize, usize)> = graph.0.iter()
+            .flat_map(|(from, adjacent)| adjacent.iter().map(move |to| (from, to)))
+            .map(|(from, to)| (indices[from], indices[to]))
+            .filter(|(from, to)| from != to)
+            .map(|(from, to)| (from.min(to), from.max(to)))
+            .collect();
+
+        let mut edges: Vec<Vec<(usize, usize)>> = vec![Vec::new(); vertices.len()];
+        for (from, to) in undirected_edges {
+            let (from_position, to_position) = (edges[from].len(), edges[to].len());
+            edges[from].push((to, to_position));
+            edges[to].push((from, from_position));
+        }
+
+        FlowNetwork { vertices, indices, edges }
+    }
+}
+
+impl<'a, Vertex: Clone + Eq + Hash> FlowNetwork<'a, Vertex> {
+    fn vertex_count(&self) -> usize {
+        self.vertices.len()
+    }
+
+    fn index_of(&self, vertex: &Vertex) -> usize {
+        self.indices[vertex]
+    }
+
+    /// The flow along every edge, in the same layout as the edges
+    fn empty_flow(&self) -> Vec<Vec<i8>> {
+        self.edges.iter().map(|edges| vec![0; edges.len()]).collect()
+    }
+
+    /// Adds flow along shortest augmenting paths until none are left or `limit` is reached, and returns the added flow
+    fn augment(&self, flow: &mut [Vec<i8>], source: usize, sink: usize, limit: usize) -> usize {
+        let mut total = 0usize;
+
+        while total < limit {
+            let previous = self.reachable_from(flow, source);
+            if previous[sink].is_none() {
+                break;
+            }
+
+            let mut vertex = sink;
+            while let Some((from, position)) = previous[vertex] {
+                let (_, back_position) = self.edges[from][position];
+                flow[from][position] += 1;
+                flow[vertex][back_position] -= 1;
+                vertex = from;
+            }
+
+            total += 1;
+        }
+
+        total
+    }
+
+    /// For every vertex reachable along edges that can carry more flow, the vertex and edge position it was reached by
+    fn reachable_from(&self, flow: &[Vec<i8>], source: usize) -> Vec<Option<(usize, usize)>> {
+        let mut previous: Vec<Option<(usize, usize)>> = vec![None; self.vertex_count()];
+        let mut visited = vec![false; self.vertex_count()];
+        visited[source] = true;
+        let mut queue = VecDeque::from([source]);
+
+        while let Some(vertex) = queue.pop_front() {
+            for (position, &(next_vertex, _)) in self.edges[vertex].iter().enumerate() {
+                if flow[vertex][position] < 1 && !visited[next_vertex] {
+                    visited[next_vertex] = true;
+                    previous[next_vertex] = Some((vertex, position));
+                    queue.push_back(next_vertex);
+                }
+            }
+        }
+
+        previous
+    }
+
+    /// The minimum cut between two vertices, found from a maximum flow between them
+    fn cut(&self, source: usize, sink: usize) -> Cut<Vertex> {
+        let mut flow = self.empty_flow();
+        self.augment(&mut flow, source, sink, usize::MAX);
+
+        let previous = self.reachable_from(&flow, source);
+        let on_source_side: Vec<bool> = (0..self.vertex_count())
+            .map(|vertex| vertex == source || previous[vertex].is_some())
+            .collect();
+
+        let edges: Vec<(Vertex, Vertex)> = (0..self.vertex_count())
+            .filter(|vertex| on_source_side[*vertex])
+            .flat_map(|vertex| self.edges[vertex].iter().map(move |(next_vertex, _)| (vertex, *next_vertex)))
+            .filter(|(_, next_vertex)| !on_source_side[*next_vertex])
+            .map(|(vertex, next_vertex)| (self.vertices[vertex].clone(), self.vertices[next_vertex].clone()))
+            .collect();
+        let source_side_size = on_source_side.iter().filter(|on_source_side| **on_source_side).count();
+
+        Cut {
+            size: edges.len(),
+            edges,
+            partition_sizes: (source_side_size, self.vertex_count() - source_side_size),
+        }
+    }
+}
+
+/// A graph whose edges are only computed when they are needed
+pub trait ImplicitGraph {
+    type Vertex: Clone + Eq + Hash + Ord;
+
+    /// The vertices reachable from the given vertex in one step, with the cost of that step
+    fn neighbors(&self, vertex: &Self::Vertex) -> Vec<(usize, Self::Vertex)>;
+
+    /// A lower bound on the cost from the given vertex to a target, turning Dijkstra's algorithm into A*
+    ///
+    /// Should never decrease by more than the cost of a step; the default of 0 always works
+    fn heuristic(&self, _vertex: &Self::Vertex) -> usize {
+        0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShortestPath<Vertex> {
+    pub distance: usize,
+    /// The vertices along the path, from the start up to and including the target
+    pub path: Vec<Vertex>,
+}
+
+/// Where a search keeps what it has found out about each vertex
+pub trait VertexMap<Vertex, V> {
+    fn get(&self, vertex: &Vertex) -> Option<&V>;
+
+    fn insert(&mut self, vertex: Vertex, value: V);
+}
+
+impl<Vertex: Eq + Hash, V> VertexMap<Vertex, V> for HashMap<Vertex, V> {
+    fn get(&self, vertex: &Vertex) -> Option<&V> {
+        HashMap::get(self, vertex)
+    }
+
+    fn insert(&mut self, vertex: Vertex, value: V) {
+        HashMap::insert(self, vertex, value);
+    }
+}
+
+impl<Vertex: Eq + Hash, V, I: DenseIndex<Vertex>> VertexMap<Vertex, V> for DenseMap<Vertex, V, I> {
+    fn get(&self, vertex: &Vertex) -> Option<&V> {
+        DenseMap::get(self, vertex)
+    }
+
+    fn insert(&mut self, vertex: Vertex, value: V) {
+        DenseMap::insert(self, vertex, value);
+    }
+}
+
+/// Finds the shortest path from `start` to the nearest vertex satisfying `is_target`, if any is reachable
+pub fn shortest_path<G: ImplicitGraph>(graph: &G, start: G::Vertex, is_target: impl Fn(&G::Vertex) -> bool) -> Option<ShortestPath<G::Vertex>> {
+    shortest_path_in(graph, start, is_target, HashMap::new(), HashMap::new())
+}
+
+/// Finds the shortest path like [`shortest_path`], keeping the distance to and the previous vertex of every vertex
+/// it reaches in the given empty maps
+pub fn shortest_path_in<G: ImplicitGraph>(
+    graph: &G,
+    start: G::Vertex,
+    is_target: impl Fn(&G::Vertex) -> bool,
+    mut distances: impl VertexMap<G::Vertex, usize>,
+    mut previous: impl VertexMap<G::Vertex, G::Vertex>,
+) -> Option<ShortestPath<G::Vertex>> {
+    distances.insert(start.clone(), 0);
+    let mut queue = BinaryHeap::from([Reverse((graph.heuristic(&start), 0, start))]);
+
+    while let Some(Reverse((_, distance, vertex))) = queue.pop() {
+        // Skip vertices that were queued again after a shorter path to them was found
+        if distances.get(&vertex).is_some_and(|known_distance| *known_distance < distance) {
+            continue;
+        }
+
+        if is_target(&vertex) {
+            let mut path = vec![vertex];
+            while let Some(previous_vertex) = previous.get(path.last().unwrap()) {
+                path.push(previous_vertex.clone());
+            }
+            path.reverse();
+
+            return Some(ShortestPath { distance, path });
+        }
+
+        for (cost, next_vertex) in graph.neighbors(&vertex) {
+            let next_distance = distance + cost;
+            if distances.get(&next_vertex).map_or(true, |known_distance| next_distance < *known_distance) {
+                distances.insert(next_vertex.clone(), next_distance);
+                previous.insert(next_vertex.clone(), vertex.clone());
+                queue.push(Reverse((next_distance + graph.heuristic(&next_vertex), next_distance, next_vertex)));
+            }
+        }
+    }
+
+    None
+}
+
+/// The distances from `start` to every vertex that can be reached within `max_distance`, or at all if there is no
+/// maximum
+pub fn shortest_distances<G: ImplicitGraph>(graph: &G, start: G::Vertex, max_distance: Option<usize>) -> HashMap<G::Vertex, usize> {
+    shortest_distances_in(graph, start, max_distance, HashMap::new())
+}
+
+/// The distances like [`shortest_distances`], in the given empty map
+pub fn shortest_distances_in<G: ImplicitGraph, M: VertexMap<G::Vertex, usize>>(graph: &G, start: G::Vertex, max_distance: Option<usize>, mut distances: M) -> M {
+    distances.insert(start.clone(), 0);
+    let mut queue = BinaryHeap::from([Reverse((0, start))]);
+
+    while let Some(Reverse((distance, vertex))) = queue.pop() {
+        if distances.get(&vertex).is_some_and(|known_distance| *known_distance < distance) {
+            continue;
+        }
+
+        for (cost, next_vertex) in graph.neighbors(&vertex) {
+            let next_distance = distance + cost;
+            if max_distance.is_some_and(|max_distance| next_distance > max_distance) {
+                continue;
+            }
+
+            if distances.get(&next_vertex).map_or(true, |known_distance| next_distance < *known_distance) {
+                distances.insert(next_vertex.clone(), next_distance);
+                queue.push(Reverse((next_distance, next_vertex)));
+            }
+        }
+    }
+
+    distances
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::app::common::GridIndex;
+    use super::*;
+
+    /// A 4 by 4 grid where stepping onto a cell costs its value
+    struct CostGrid([[usize; 4]; 4]);
+
+    impl ImplicitGraph for CostGrid {
+        type Vertex = (usize, usize);
+
+        fn neighbors(&self, &(x, y): &(usize, usize)) -> Vec<(usize, (usize, usize))> {
+            [(x.wrapping_sub(1), y), (x + 1, y), (x, y.wrapping_sub(1)), (x, y + 1)].into_iter()
+                .filter(|(x, y)| *x < 4 && *y < 4)
+                .map(|(x, y)| (self.0[y][x], (x, y)))
+                .collect()
+        }
+
+        fn heuristic(&self, &(x, y): &(usize, usize)) -> usize {
+            (3 - x) + (3 - y)
+        }
+    }
+
+    #[test]
+    fn should_find_shortest_path() {
+        let grid = CostGrid([
+            [0, 1, 9, 9],
+            [9, 1, 9, 1],
+            [9, 1, 1, 1],
+            [9, 9, 9, 1],
+        ]);
+
+        let result = shortest_path(&grid, (0, 0), |vertex| *vertex == (3, 3));
+
+        assert_eq!(result, Some(ShortestPath {
+            distance: 6,
+            path: vec![(0, 0), (1, 0), (1, 1), (1, 2), (2, 2), (3, 2), (3, 3)],
+        }));
+    }
+
+    #[test]
+    fn should_find_distances_within_maximum() {
+        let grid = CostGrid([[1; 4]; 4]);
+
+        let distances = shortest_distances(&grid, (0, 0), Some(2));
+
+        assert_eq!(distances.len(), 6);
+        assert_eq!(distances[&(1, 1)], 2);
+    }
+
+    #[test]
+    fn should_find_same_distances_in_dense_map() {
+        let grid = CostGrid([
+            [0, 1, 9, 9],
+            [9, 1, 9, 1],
+            [9, 1, 1, 1],
+            [9, 9, 9, 1],
+        ]);
+
+        let sparse = shortest_distances(&grid, (0, 0), None);
+        let dense = shortest_distances_in(&grid, (0, 0), None, DenseMap::new(GridIndex::new(4, 4)));
+
+        assert_eq!(dense.len(), sparse.len());
+        assert!(sparse.iter().all(|(vertex, distance)| dense[vertex] == *distance));
+        assert_eq!(
+            shortest_path_in(&grid, (0, 0), |vertex| *vertex == (3, 3), DenseMap::new(GridIndex::new(4, 4)), DenseMap::new(GridIndex::new(4, 4))),
+            shortest_path(&grid, (0, 0), |vertex| *vertex == (3, 3)),
+        );
+    }
+
+    /// Two groups of four fully connected vertices, joined by the edges 3-4 and 2-5
+    fn two_groups() -> AdjacencyHashGraph<usize> {
+        let mut graph = AdjacencyHashGraph::new();
+        let groups = [[0, 1, 2, 3], [4, 5, 6, 7]];
+        let edges = groups.iter()
+            .flat_map(|group| group.iter().flat_map(move |from| group.iter().filter(move |to| from != *to).map(move |to| (*from, *to))))
+            .chain([(3, 4), (4, 3), (2, 5), (5, 2)]);
+
+        for (from, to) in edges {
+            graph.insert_vertex(from);
+            graph.insert_vertex(to);
+            graph.insert_edge(&from, to);
+        }
+
+        graph
+    }
+
+    #[test]
+    fn should_export_undirected_graph_once_per_edge() {
+        let graph = two_groups();
+
+        assert!(!graph.is_directed());
+        assert_eq!(graph.edges().len(), 14);
+        assert!(graph.to_dot().starts_with("graph {"));
+    }
+
+    #[test]
+    fn should_find_minimum_cut() {
+        let cut = two_groups().minimum_cut().unwrap();
+
+        let mut edges: Vec<_> = cut.edges.iter().map(|(from, to)| (*from.min(to), *from.max(to))).collect();
+        edges.sort();
+        assert_eq!(cut.size, 2);
+        assert_eq!(edges, vec![(2, 5), (3, 4)]);
+        assert_eq!(cut.partition_sizes.0 * cut.partition_sizes.1, 16);
+    }
+
+    #[test]
+    fn should_find_minimum_cut_between_vertices() {
+        let cut = two_groups().minimum_cut_between(&0, &1);
+
+        assert_eq!(cut.size, 3);
+        assert_eq!(cut.partition_sizes, (1, 7));
+    }
 }
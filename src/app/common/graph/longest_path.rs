@@ -0,0 +1,279 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use crate::app::common::{parallel_map, thread_count, Bitset};
+use super::DistanceHashGraph;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LongestPath<Vertex> {
+    pub distance: usize,
+    /// The vertices along the path, from the start up to and including the target
+    pub path: Vec<Vertex>,
+}
+
+impl<Vertex: Clone + Eq + Hash> DistanceHashGraph<Vertex> {
+    /// Finds the longest path from `from` to `to` that visits no vertex twice, if there is any path at all
+    ///
+    /// The search is split over the threads of the current thread count; with a single thread it runs on the current one.
+    pub fn longest_path_between(&self, from: &Vertex, to: &Vertex) -> Option<LongestPath<Vertex>> {
+        let vertices: Vec<&Vertex> = self.0.keys().collect();
+        let indices: HashMap<&Vertex, usize> = vertices.iter().enumerate().map(|(index, vertex)| (*vertex, index)).collect();
+        let (&start, &target) = (indices.get(from)?, indices.get(to)?);
+        if start == target {
+            return Some(LongestPath { distance: 0, path: vec![from.clone()] });
+        }
+
+        // An edge to a vertex that was never inserted leads nowhere, so it can't be part of a path to the target
+        let edges: Vec<Vec<(usize, usize)>> = vertices.iter()
+            .map(|vertex| self.0[*vertex].iter().filter_map(|(distance, to)| Some((*distance, *indices.get(to)?))).collect())
+            .collect();
+
+        let search = LongestPathSearch::new(&edges, start, target);
+        let found = search.run(start, thread_count())?;
+
+        Some(LongestPath {
+            distance: found.distance,
+            path: found.path.into_iter().map(|index| vertices[index].clone()).collect(),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Found {
+    distance: usize,
+    path: Vec<usize>,
+}
+
+/// A partial path, extended and shortened again while searching
+#[derive(Debug, Clone)]
+struct SearchState {
+    visited: Bitset,
+    path: Vec<usize>,
+    distance: usize,
+    /// The sum of the longest outgoing edge of every vertex that has not been visited yet
+    remaining: usize,
+}
+
+struct LongestPathSearch<'a> {
+    edges: &'a [Vec<(usize, usize)>],
+    /// The vertex the search aims for; this is the only way into the actual target if there is just one
+    target: usize,
+    /// The edge from `target` to the actual target, if the last edge is forced
+    forced_last_edge: Option<(usize, usize)>,
+    longest_edges: Vec<usize>,
+    /// One more than the longest distance found so far by any thread, or 0 if none was found yet
+    best_distance: AtomicUsize,
+}
+
+impl<'a> LongestPathSearch<'a> {
+    fn new(edges: &'a [Vec<(usize, usize)>], start: usize, target: usize) -> Self {
+        let mut edges_into_target = edges.iter().enumerate()
+            .flat_map(|(from, edges)| edges.iter().filter(|(_, to)| *to == target).map(move |(distance, _)| (from, *distance)));
+
+        // If the target can only be entered from one vertex, reaching that vertex means the rest of the path is known
+        let forced_last_edge = match (edges_into_target.next(), edges_into_target.next()) {
+            (Some((from, distance)), None) if from != start && from != target => Some((from, distance)),
+            _ => None,
+        };
+
+        LongestPathSearch {
+            edges,
+            target: forced_last_edge.map_or(target, |(from, _)| from),
+            forced_last_edge: forced_last_edge.map(|(_, distance)| (target, distance)),
+            longest_edges: edges.iter().map(|edges| edges.iter().map(|(distance, _)| *distance).max().unwrap_or(0)).collect(),
+            best_distance: AtomicUsize::new(0),
+        }
+    }
+
+    fn run(&self, start: usize, thread_count: usize) -> Option<Found> {
+        let mut visited = Bitset::new(self.edges.len());
+        visited.insert(start);
+        if let Some((target, _)) = self.forced_last_edge {
+            visited.insert(target);
+        }
+
+        let remaining = (0..self.edges.len())
+            .filter(|vertex| !visited.contains(*vertex) && *vertex != self.target)
+            .map(|vertex| self.longest_edges[vertex])
+            .sum();
+        let mut initial = SearchState { visited, path: vec![start], distance: 0, remaining };
+
+        let found = if thread_count == 1 {
+            let mut found = None;
+            self.search(&mut initial, &mut found);
+            found
+        } else {
+            self.search_in_parallel(initial, thread_count)
+        };
+
+        found.map(|mut found| {
+            if let Some((target, distance)) = self.forced_last_edge {
+                found.distance += distance;
+                found.path.push(target);
+            }
+            found
+        })
+    }
+
+    /// Splits the search into many partial paths, and lets the threads take turns picking the next one
+    fn search_in_parallel(&self, initial: SearchState, thread_count: usize) -> Option<Found> {
+        let mut states = vec![initial];
+        while states.len() < 16 * thread_count {
+            let next_states: Vec<SearchState> = states.iter()
+                .flat_map(|state| self.extensions(state))
+                .collect();
+            if next_states.is_empty() || next_states.len() == states.len() {
+                break;
+            }
+            states = next_states;
+        }
+
+        // The best distance is shared, so every partial path is cut short by the best path found on any thread
+        parallel_map(&states, |state| {
+            let mut found = None;
+            self.search(&mut state.clone(), &mut found);
+            found
+        })
+            .into_iter()
+            .flatten()
+            .max_by_key(|found| found.distance)
+    }
+
+    /// The partial paths that are one vertex longer, keeping paths that already reached the target as they are
+    fn extensions(&self, state: &SearchState) -> Vec<SearchState> {
+        let vertex = *state.path.last().unwrap();
+        if vertex == self.target {
+            return vec![state.clone()];
+        }
+
+        self.edges[vertex].iter()
+            .filter(|(_, next_vertex)| !state.visited.contains(*next_vertex))
+            .map(|&(distance, next_vertex)| {
+                let mut next_state = state.clone();
+                next_state.visit(next_vertex, distance, self.remaining_after(next_vertex));
+                next_state
+            })
+            .collect()
+    }
+
+    fn search(&self, state: &mut SearchState, found: &mut Option<Found>) {
+        let vertex = *state.path.last().unwrap();
+
+        if vertex == self.target {
+            if found.as_ref().map_or(true, |found| state.distance > found.distance) {
+                *found = Some(Found { distance: state.distance, path: state.path.clone() });
+                self.best_distance.fetch_max(state.distance + 1, Ordering::Relaxed);
+            }
+            return;
+        }
+
+        // Even using the longest edge out of every vertex left can't beat the best path found so far
+        let upper_bound = state.distance + self.longest_edges[vertex] + state.remaining;
+        if upper_bound < self.best_distance.load(Ordering::Relaxed) {
+            return;
+        }
+
+        for &(distance, next_vertex) in self.edges[vertex].iter() {
+            if !state.visited.contains(next_vertex) {
+                let longest_edge = self.remaining_after(next_vertex);
+                state.visit(next_vertex, distance, longest_edge);
+                self.search(state, found);
+                state.leave(distance, longest_edge);
+            }
+        }
+    }
+
+    /// How much `remaining` drops when the vertex is visited
+    fn remaining_after(&self, vertex: usize) -> usize {
+        if vertex == self.target { 0 } else { self.longest_edges[vertex] }
+    }
+}
+
+impl SearchState {
+    fn visit(&mut self, vertex: usize, distance: usize, longest_edge: usize) {
+        self.visited.insert(vertex);
+        self.path.push(vertex);
+        self.distance += distance;
+        self.remaining -= longest_edge;
+    }
+
+    fn leave(&mut self, distance: usize, longest_edge: usize) {
+        let vertex = self.path.pop().unwrap();
+        self.visited.remove(vertex);
+        self.distance -= distance;
+        self.remaining += longest_edge;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::app::common::with_thread_count;
+    use super::*;
+
+    /// A 3 by 3 grid of vertices with edges both ways between neighbors, longer along the top row
+    fn grid() -> DistanceHashGraph<(usize, usize)> {
+        let mut graph = DistanceHashGraph::new();
+        for x in 0..3 {
+            for y in 0..3 {
+                graph.insert_vertex((x, y));
+            }
+        }
+        for x in 0..3 {
+            for y in 0..3 {
+                for (next_x, next_y) in [(x + 1, y), (x, y + 1)].into_iter().filter(|(x, y)| *x < 3 && *y < 3) {
+                    let distance = if y == 0 && next_y == 0 { 5 } else { 1 };
+                    graph.insert_edge(&(x, y), (next_x, next_y), distance);
+                    graph.insert_edge(&(next_x, next_y), (x, y), distance);
+                }
+            }
+        }
+        graph
+    }
+
+    #[test]
+    fn should_find_longest_path() {
+        let result = grid().longest_path_between(&(0, 0), &(2, 2)).unwrap();
+
+        assert_eq!(result.distance, 16);
+        assert_eq!(result.path, vec![(0, 0), (1, 0), (2, 0), (2, 1), (1, 1), (0, 1), (0, 2), (1, 2), (2, 2)]);
+    }
+
+    #[test]
+    fn should_find_same_distance_on_threads() {
+        let graph = grid();
+
+        for thread_count in 2..=4 {
+            let (result, _) = with_thread_count(thread_count, || graph.longest_path_between(&(0, 0), &(2, 2)).unwrap());
+            assert_eq!(result.distance, 16);
+        }
+    }
+
+    #[test]
+    fn should_return_none_without_path() {
+        let mut graph = grid();
+        graph.insert_vertex((5, 5));
+
+        assert_eq!(graph.longest_path_between(&(0, 0), &(5, 5)), None);
+    }
+
+    #[test]
+    fn should_stay_put_when_start_is_target() {
+        let mut graph = DistanceHashGraph::new();
+        for vertex in 0..3 {
+            graph.insert_vertex(vertex);
+        }
+        graph.insert_edge(&0, 1, 1);
+        graph.insert_edge(&1, 0, 1);
+        graph.insert_edge(&1, 2, 1);
+
+        assert_eq!(graph.longest_path_between(&0, &0), Some(LongestPath { distance: 0, path: vec![0] }));
+    }
+
+    #[test]
+    fn should_skip_edges_to_missing_vertices() {
+        let mut graph = grid();
+        graph.insert_edge(&(1, 1), (5, 5), 100);
+
+        assert_eq!(graph.longest_path_between(&(0, 0), &(2, 2)).unwrap().distance, 16);
+    }
+}
@@ -0,0 +1,285 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use super::AdjacencyHashGraph;
+
+impl<Vertex: Clone + Eq + Hash> AdjacencyHashGraph<Vertex> {
+    /// The strongly connected components, in topological order: no edge leads from a component to an earlier one
+    pub fn strongly_connected_components(&self) -> Vec<Vec<Vertex>> {
+        let graph = IndexedGraph::from(self);
+        let vertex_count = graph.vertices.len();
+
+        // Tarjan's algorithm, with an explicit call stack of vertices and the position of the next edge to follow
+        let mut order: Vec<Option<usize>> = vec![None; vertex_count];
+        let mut low_link = vec![0usize; vertex_count];
+        let mut on_stack = vec![false; vertex_count];
+        let mut stack: Vec<usize> = Vec::new();
+        let mut next_order = 0usize;
+        let mut components: Vec<Vec<Vertex>> = Vec::new();
+
+        for root in 0..vertex_count {
+            if order[root].is_some() {
+                continue;
+            }
+
+            let mut call_stack = vec![(root, 0usize)];
+            order[root] = Some(next_order);
+            low_link[root] = next_order;
+            next_order += 1;
+            stack.push(root);
+            on_stack[root] = true;
+
+            while let Some((vertex, position)) = call_stack.last_mut() {
+                let vertex = *vertex;
+
+                if let Some(&next_vertex) = graph.successors[vertex].get(*position) {
+                    *position += 1;
+
+                    if let Some(next_vertex_order) = order[next_vertex] {
+                        if on_stack[next_vertex] {
+                            low_link[vertex] = low_link[vertex].min(next_vertex_order);
+                        }
+                    } else {
+                        order[next_vertex] = Some(next_order);
+                        low_link[next_vertex] = next_order;
+                        next_order += 1;
+                        stack.push(next_vertex);
+                        on_stack[next_vertex] = true;
+                        call_stack.push((next_vertex, 0));
+                    }
+                } else {
+                    call_stack.pop();
+                    if let Some((parent, _)) = call_stack.last() {
+                        low_link[*parent] = low_link[*parent].min(low_link[vertex]);
+                    }
+
+                    if Some(low_link[vertex]) == order[vertex] {
+                        let mut component = Vec::new();
+                        loop {
+                            let member = stack.pop().unwrap();
+                            on_stack[member] = false;
+                            component.push(graph.vertices[member].clone());
+                            if member == vertex {
+                                break;
+                            }
+                        }
+                        components.push(component);
+                    }
+                }
+            }
+        }
+
+        // Tarjan's algorithm finds a component only after every component it leads to
+        components.reverse();
+        components
+    }
+
+    /// Every vertex, such that all edges lead to later vertices, or `None` if the graph has a cycle
+    pub fn topological_order(&self) -> Option<Vec<Vertex>> {
+        let graph = IndexedGraph::from(self);
+
+        let mut in_degrees = vec![0usize; graph.vertices.len()];
+        for next_vertex in graph.successors.iter().flatten() {
+            in_degrees[*next_vertex] += 1;
+        }
+
+        let mut queue: VecDeque<usize> = (0..graph.vertices.len()).filter(|vertex| in_degrees[*vertex] == 0).collect();
+        let mut result = Vec::with_capacity(graph.vertices.len());
+        while let Some(vertex) = queue.pop_front() {
+            result.push(graph.vertices[vertex].clone());
+
+            for &next_vertex in graph.successors[vertex].iter() {
+                in_degrees[next_vertex] -= 1;
+                if in_degrees[next_vertex] == 0 {
+                    queue.push_back(next_vertex);
+                }
+            }
+        }
+
+        if result.len() == graph.vertices.len() { Some(result) } else { None }
+    }
+
+    /// Every vertex that can be reached from `start`, including `start` itself
+    pub fn reachable_from(&self, start: &Vertex) -> HashSet<Vertex> {
+        let mut reachable = HashSet::from([start.clone()]);
+        let mut stack = vec![start];
+
+        while let Some(vertex) = stack.pop() {
+            for next_vertex in self.0.get(vertex).into_iter().flatten() {
+                if reachable.insert(next_vertex.clone()) {
+                    stack.push(next_vertex);
+                }
+            }
+        }
+
+        reachable
+    }
+
+    /// The immediate dominator of every vertex reachable from `root` other than `root` itself, i.e. the last vertex
+    /// before it that every path from `root` to it passes through
+    pub fn dominators(&self, root: &Vertex) -> HashMap<Vertex, Vertex> {
+        let graph = IndexedGraph::from(self);
+        let root = graph.indices[root];
+
+        let postorder = graph.postorder_from(root);
+        let mut postorder_positions: Vec<Option<usize>> = vec![None; graph.vertices.len()];
+        for (position, vertex) in postorder.iter().enumerate() {
+            postorder_positions[*vertex] = Some(position);
+        }
+
+        let mut predecessors: Vec<Vec<usize>> = vec![vec![]; graph.vertices.len()];
+        for &vertex in postorder.iter() {
+            for &next_vertex in graph.successors[vertex].iter() {
+                predecessors[next_vertex].push(vertex);
+            }
+        }
+
+        // Cooper, Harvey and Kennedy's iterative algorithm, walking up the dominator tree found so far
+        let mut dominators: Vec<Option<usize>> = vec![None; graph.vertices.len()];
+        dominators[root] = Some(root);
+        let position = |vertex: usize| postorder_positions[vertex].unwrap();
+        let mut changed = true;
+        while changed {
+            changed = false;
+
+            for &vertex in postorder.iter().rev().filter(|vertex| **vertex != root) {
+                let mut processed = predecessors[vertex].iter().copied().filter(|predecessor| dominators[*predecessor].is_some());
+                let mut dominator = processed.next().unwrap();
+
+                for mut predecessor in processed {
+                    while predecessor != dominator {
+                        while position(predecessor) < position(dominator) {
+                            predecessor = dominators[predecessor].unwrap();
+                        }
+                        while position(dominator) < position(predecessor) {
+                            dominator = dominators[dominator].unwrap();
+                        }
+                    }
+                }
+
+                if dominators[vertex] != Some(dominator) {
+                    dominators[vertex] = Some(dominator);
+                    changed = true;
+                }
+            }
+        }
+
+        postorder.into_iter()
+            .filter(|vertex| *vertex != root)
+            .map(|vertex| (graph.vertices[vertex].clone(), graph.vertices[dominators[vertex].unwrap()].clone()))
+            .collect()
+    }
+}
+
+/// A graph with its vertices numbered, including vertices that only appear as the end of an edge
+struct IndexedGraph<'a, Vertex> {
+    vertices: Vec<&'a Vertex>,
+    indices: HashMap<&'a Vertex, usize>,
+    successors: Vec<Vec<usize>>,
+}
+
+impl<'a, Vertex: Eq + Hash> From<&'a AdjacencyHashGraph<Vertex>> for IndexedGraph<'a, Vertex> {
+    fn from(value: &'a AdjacencyHashGraph<Vertex>) -> Self {
+        let mut vertices: Vec<&Vertex> = Vec::with_capacity(value.0.len());
+        let mut indices: HashMap<&Vertex, usize> = HashMap::with_capacity(value.0.len());
+        for vertex in value.0.keys().chain(value.0.values().flatten()) {
+            if !indices.contains_key(vertex) {
+                indices.insert(vertex, vertices.len());
+                vertices.push(vertex);
+            }
+        }
+
+        let successors = vertices.iter()
+            .map(|vertex| value.0.get(*vertex).into_iter().flatten().map(|next_vertex| indices[next_vertex]).collect())
+            .collect();
+
+        IndexedGraph { vertices, indices, successors }
+    }
+}
+
+impl<'a, Vertex> IndexedGraph<'a, Vertex> {
+    /// The vertices reachable from `root`, each after all vertices it leads to that are not on the way to it
+    fn postorder_from(&self, root: usize) -> Vec<usize> {
+        let mut visited = vec![false; self.vertices.len()];
+        let mut postorder = Vec::new();
+        let mut call_stack = vec![(root, 0usize)];
+        visited[root] = true;
+
+        while let Some((vertex, position)) = call_stack.last_mut() {
+            let vertex = *vertex;
+            if let Some(&next_vertex) = self.successors[vertex].get(*position) {
+                *position += 1;
+                if !visited[next_vertex] {
+                    visited[next_vertex] = true;
+                    call_stack.push((next_vertex, 0));
+                }
+            } else {
+                call_stack.pop();
+                postorder.push(vertex);
+            }
+        }
+
+        postorder
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph(edges: &[(usize, usize)]) -> AdjacencyHashGraph<usize> {
+        let mut graph = AdjacencyHashGraph::new();
+        for &(from, to) in edges {
+            graph.insert_vertex(from);
+            graph.insert_vertex(to);
+            graph.insert_edge(&from, to);
+        }
+        graph
+    }
+
+    #[test]
+    fn should_find_components_in_topological_order() {
+        let graph = graph(&[(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 3), (5, 3)]);
+
+        let components: Vec<Vec<usize>> = graph.strongly_connected_components().into_iter()
+            .map(|mut component| { component.sort(); component })
+            .collect();
+
+        assert_eq!(components.len(), 3);
+        assert_eq!(components.last(), Some(&vec![3, 4]));
+        assert!(components.contains(&vec![0, 1, 2]));
+        assert!(components.contains(&vec![5]));
+    }
+
+    #[test]
+    fn should_order_acyclic_graph() {
+        let graph = graph(&[(0, 1), (0, 2), (1, 3), (2, 3), (3, 4)]);
+
+        let order = graph.topological_order().unwrap();
+        let position = |vertex: usize| order.iter().position(|v| *v == vertex).unwrap();
+
+        assert_eq!(order.len(), 5);
+        assert!(position(0) < position(1) && position(0) < position(2));
+        assert!(position(1) < position(3) && position(2) < position(3) && position(3) < position(4));
+    }
+
+    #[test]
+    fn should_not_order_cyclic_graph() {
+        assert_eq!(graph(&[(0, 1), (1, 2), (2, 1)]).topological_order(), None);
+    }
+
+    #[test]
+    fn should_find_reachable_vertices() {
+        let graph = graph(&[(0, 1), (1, 2), (3, 1)]);
+
+        assert_eq!(graph.reachable_from(&1), HashSet::from([1, 2]));
+        assert_eq!(graph.reachable_from(&0), HashSet::from([0, 1, 2]));
+    }
+
+    #[test]
+    fn should_find_dominators() {
+        // Two ways around from 1 to 4, and a loop back from 5 to 1
+        let graph = graph(&[(0, 1), (1, 2), (1, 3), (2, 4), (3, 4), (4, 5), (5, 1), (6, 5)]);
+
+        assert_eq!(graph.dominators(&0), HashMap::from([(1, 0), (2, 1), (3, 1), (4, 1), (5, 4)]));
+    }
+}
@@ -0,0 +1,118 @@
+use std::fmt::Write;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportNode {
+    pub id: String,
+    /// The GraphViz shape to draw the node with, if not the default
+    pub shape: Option<&'static str>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportEdge {
+    pub from: String,
+    pub to: String,
+    pub label: Option<String>,
+}
+
+/// A model that can be exported as a graph, for drawing it while debugging
+pub trait GraphExport {
+    fn nodes(&self) -> Vec<ExportNode>;
+
+    fn edges(&self) -> Vec<ExportEdge>;
+
+    fn is_directed(&self) -> bool {
+        true
+    }
+
+    /// The graph in the GraphViz DOT language
+    fn to_dot(&self) -> String {
+        let (nodes, edges) = sorted(self.nodes(), self.edges());
+        let (keyword, connector) = if self.is_directed() { ("digraph", "->") } else { ("graph", "--") };
+
+        let mut dot = format!("{} {{\n", keyword);
+        for node in nodes {
+            match node.shape {
+                Some(shape) => writeln!(dot, "    {} [shape={}];", quoted(&node.id), shape),
+                None => writeln!(dot, "    {};", quoted(&node.id)),
+            }.unwrap();
+        }
+        for edge in edges {
+            match edge.label {
+                Some(label) => writeln!(dot, "    {} {} {} [label={}];", quoted(&edge.from), connector, quoted(&edge.to), quoted(&label)),
+                None => writeln!(dot, "    {} {} {};", quoted(&edge.from), connector, quoted(&edge.to)),
+            }.unwrap();
+        }
+        dot.push('}');
+
+        dot
+    }
+
+    /// The graph as a JSON object with a list of nodes and a list of edges
+    fn to_json(&self) -> String {
+        let (nodes, edges) = sorted(self.nodes(), self.edges());
+        let optional = |value: Option<&str>| value.map_or("null".to_string(), quoted);
+
+        let nodes: Vec<String> = nodes.iter()
+            .map(|node| format!("{{\"id\":{},\"shape\":{}}}", quoted(&node.id), optional(node.shape)))
+            .collect();
+        let edges: Vec<String> = edges.iter()
+            .map(|edge| format!("{{\"from\":{},\"to\":{},\"label\":{}}}", quoted(&edge.from), quoted(&edge.to), optional(edge.label.as_deref())))
+            .collect();
+
+        format!("{{\"directed\":{},\"nodes\":[{}],\"edges\":[{}]}}", self.is_directed(), nodes.join(","), edges.join(","))
+    }
+}
+
+/// Sorts nodes and edges, so the same graph is always exported the same way
+fn sorted(mut nodes: Vec<ExportNode>, mut edges: Vec<ExportEdge>) -> (Vec<ExportNode>, Vec<ExportEdge>) {
+    nodes.sort_by(|lhs, rhs| lhs.id.cmp(&rhs.id));
+    edges.sort_by(|lhs, rhs| (&lhs.from, &lhs.to, &lhs.label).cmp(&(&rhs.from, &rhs.to, &rhs.label)));
+    (nodes, edges)
+}
+
+/// A string literal, escaped the same way for DOT and JSON
+fn quoted(value: &str) -> String {
+    let mut result = String::from('"');
+    for c in value.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            c if c.is_control() => write!(result, "\\u{:04x}", c as u32).unwrap(),
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A single edge between two nodes whose names need escaping
+    struct Quotes;
+
+    impl GraphExport for Quotes {
+        fn nodes(&self) -> Vec<ExportNode> {
+            vec![
+                ExportNode { id: "b\\c".to_string(), shape: None },
+                ExportNode { id: "\"a\"".to_string(), shape: Some("box") },
+            ]
+        }
+
+        fn edges(&self) -> Vec<ExportEdge> {
+            vec![ExportEdge { from: "\"a\"".to_string(), to: "b\\c".to_string(), label: Some("x\ny".to_string()) }]
+        }
+    }
+
+    #[test]
+    fn should_export_dot() {
+        assert_eq!(Quotes.to_dot(), "digraph {\n    \"\\\"a\\\"\" [shape=box];\n    \"b\\\\c\";\n    \"\\\"a\\\"\" -> \"b\\\\c\" [label=\"x\\ny\"];\n}");
+    }
+
+    #[test]
+    fn should_export_json() {
+        assert_eq!(Quotes.to_json(), r#"{"directed":true,"nodes":[{"id":"\"a\"","shape":"box"},{"id":"b\\c","shape":null}],"edges":[{"from":"\"a\"","to":"b\\c","label":"x\ny"}]}"#);
+    }
+}
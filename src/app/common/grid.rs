@@ -0,0 +1,309 @@
+use std::fmt::{Debug, Display, Formatter};
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+/// One of the four directions on a grid, with north pointing towards the first row
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// In clockwise order, starting from north
+    pub fn all() -> impl Iterator<Item = Direction> {
+        [Direction::North, Direction::East, Direction::South, Direction::West].into_iter()
+    }
+
+    /// The position of the direction in `all`, for keeping something per direction in an array
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    pub fn opposite(self) -> Direction {
+        match self {
+            Direction::North => Direction::South,
+            Direction::East => Direction::West,
+            Direction::South => Direction::North,
+            Direction::West => Direction::East,
+        }
+    }
+
+    /// The direction after a quarter turn counterclockwise
+    pub fn turn_left(self) -> Direction {
+        self.turn_right().opposite()
+    }
+
+    /// The direction after a quarter turn clockwise
+    pub fn turn_right(self) -> Direction {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    /// The change in coordinates of a single step, with y increasing to the south
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::North => (0, -1),
+            Direction::East => (1, 0),
+            Direction::South => (0, 1),
+            Direction::West => (-1, 0),
+        }
+    }
+}
+
+/// The eight tiles around a tile, clockwise from the one north of it
+const RING: [(isize, isize); 8] = [(0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0), (-1, -1)];
+
+/// A rectangle of tiles, indexed by `(x, y)` with `(0, 0)` in the top left corner
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    /// Row by row
+    tiles: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Parses one row of tiles per line, or fails if the lines are not all equally long or a character is not a tile
+    pub fn parse_with(s: &str, mut parse_tile: impl FnMut(char) -> Option<T>) -> Result<Grid<T>, ParseGridError> {
+        let mut width = 0usize;
+        let mut height = 0usize;
+        let mut tiles: Vec<T> = Vec::new();
+
+        for (y, line) in s.lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                tiles.push(parse_tile(c).ok_or(ParseGridError::InvalidTile { x, y, tile: c })?);
+            }
+
+            if y == 0 {
+                width = tiles.len();
+            } else if tiles.len() != (y + 1) * width {
+                return Err(ParseGridError::UnevenRow { y, length: tiles.len() - y * width, width });
+            }
+            height += 1;
+        }
+
+        Ok(Grid { width, height, tiles })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The coordinates of every tile, row by row
+    pub fn coords(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// The coordinates of the first tile, row by row, that matches the predicate
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<(usize, usize)> {
+        self.tiles.iter().position(predicate).map(|index| (index % self.width, index / self.width))
+    }
+
+    /// The tile next to `coords` in `direction`, if it is on the grid
+    pub fn step(&self, coords: (usize, usize), direction: Direction) -> Option<(usize, usize)> {
+        self.offset(coords, direction.offset())
+    }
+
+    /// The tiles next to `coords` horizontally or vertically, in clockwise order starting from north
+    pub fn neighbors(&self, coords: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Direction::all().filter_map(move |direction| self.step(coords, direction))
+    }
+
+    /// The tiles next to `coords`, also diagonally, in clockwise order starting from north
+    pub fn neighbors_with_diagonals(&self, coords: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        RING.into_iter().filter_map(move |offset| self.offset(coords, offset))
+    }
+
+    fn offset(&self, (x, y): (usize, usize), (dx, dy): (isize, isize)) -> Option<(usize, usize)> {
+        let x = x.checked_add_signed(dx).filter(|x| *x < self.width)?;
+        let y = y.checked_add_signed(dy).filter(|y| *y < self.height)?;
+        Some((x, y))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.tiles.chunks(self.width.max(1))
+    }
+
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [T]> {
+        self.tiles.chunks_mut(self.width.max(1))
+    }
+
+    /// The tiles of every column, each from top to bottom
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        (0..self.width).map(move |x| self.tiles[x..].iter().step_by(self.width))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { width: self.width, height: self.height, tiles: self.tiles.iter().map(f).collect() }
+    }
+
+    /// The grid as if repeated infinitely in every direction
+    pub fn wrapping(&self) -> WrappingGrid<'_, T> {
+        WrappingGrid(self)
+    }
+
+    /// One line of text per row, with a character per tile
+    pub fn render(&self, render_tile: impl Fn(&T) -> char) -> String {
+        self.rows()
+            .map(|row| row.iter().map(&render_tile).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// The grid mirrored along its main diagonal, so that its rows become columns
+    pub fn transpose(&self) -> Grid<T> {
+        Grid {
+            width: self.height,
+            height: self.width,
+            tiles: self.columns().flatten().cloned().collect(),
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    /// Panics if the coordinates are off the grid
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        assert!(x < self.width && y < self.height, "Tile ({}, {}) is off the grid", x, y);
+        &self.tiles[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    /// Panics if the coordinates are off the grid
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        assert!(x < self.width && y < self.height, "Tile ({}, {}) is off the grid", x, y);
+        &mut self.tiles[y * self.width + x]
+    }
+}
+
+impl<T: TryFrom<char>> FromStr for Grid<T> {
+    type Err = ParseGridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse_with(s, |c| T::try_from(c).ok())
+    }
+}
+
+impl<T: Debug> Debug for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.rows()).finish()
+    }
+}
+
+/// A grid repeated infinitely in every direction, indexed by any `(x, y)`
+#[derive(Debug)]
+pub struct WrappingGrid<'a, T>(&'a Grid<T>);
+
+impl<'a, T> Index<(isize, isize)> for WrappingGrid<'a, T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (isize, isize)) -> &Self::Output {
+        let (width, height) = (self.0.width as isize, self.0.height as isize);
+        &self.0[(x.rem_euclid(width) as usize, y.rem_euclid(height) as usize)]
+    }
+}
+
+#[derive(Clone)]
+pub enum ParseGridError {
+    UnevenRow { y: usize, length: usize, width: usize },
+    InvalidTile { x: usize, y: usize, tile: char },
+}
+
+impl Display for ParseGridError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseGridError::UnevenRow { y, length, width } =>
+                write!(f, "row {} of grid has {} tiles instead of {}", y, length, width),
+            ParseGridError::InvalidTile { x, y, tile } =>
+                write!(f, "invalid tile \"{}\" at ({}, {}) of grid", tile, x, y),
+        }
+    }
+}
+
+impl Debug for ParseGridError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl std::error::Error for ParseGridError { }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(s: &str) -> Grid<u32> {
+        Grid::parse_with(s, |c| c.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn should_parse_and_render() {
+        let grid = digits("123\n456");
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.position(|digit| *digit > 4), Some((1, 1)));
+        assert_eq!(grid.render(|digit| char::from_digit(*digit, 10).unwrap()), "123\n456");
+        assert_eq!(grid.transpose().render(|digit| char::from_digit(*digit, 10).unwrap()), "14\n25\n36");
+    }
+
+    #[test]
+    fn should_not_parse_invalid_grid() {
+        assert!(matches!(Grid::parse_with("12\n3", |c| c.to_digit(10)), Err(ParseGridError::UnevenRow { y: 1, length: 1, width: 2 })));
+        assert!(matches!(Grid::parse_with("12\n3x", |c| c.to_digit(10)), Err(ParseGridError::InvalidTile { x: 1, y: 1, tile: 'x' })));
+    }
+
+    #[test]
+    fn should_find_neighbors() {
+        let grid = digits("123\n456\n789");
+
+        assert_eq!(grid.neighbors((0, 0)).collect::<Vec<_>>(), vec![(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbors((1, 1)).count(), 4);
+        assert_eq!(grid.neighbors_with_diagonals((2, 0)).collect::<Vec<_>>(), vec![(2, 1), (1, 1), (1, 0)]);
+        assert_eq!(grid.neighbors_with_diagonals((1, 1)).count(), 8);
+        assert_eq!(grid.step((2, 2), Direction::East), None);
+        assert_eq!(grid.step((2, 2), Direction::North), Some((2, 1)));
+    }
+
+    #[test]
+    fn should_iterate_rows_and_columns() {
+        let grid = digits("12\n34\n56");
+
+        assert_eq!(grid.rows().map(|row| row.to_vec()).collect::<Vec<_>>(), vec![vec![1, 2], vec![3, 4], vec![5, 6]]);
+        assert_eq!(grid.columns().map(|column| column.copied().collect::<Vec<_>>()).collect::<Vec<_>>(), vec![vec![1, 3, 5], vec![2, 4, 6]]);
+        assert_eq!(grid.wrapping()[(-1, 4)], 4);
+        assert_eq!(grid.wrapping()[(2, -3)], 1);
+    }
+
+    #[test]
+    fn should_turn() {
+        for direction in Direction::all() {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.opposite());
+        }
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+    }
+
+    #[test]
+    fn should_index_directions_in_order() {
+        for (index, direction) in Direction::all().enumerate() {
+            assert_eq!(direction.index(), index);
+        }
+    }
+}
@@ -0,0 +1,306 @@
+use std::fmt::{Debug, Formatter};
+use std::ops::{Add, Deref, Mul, Sub};
+use crate::app::math::{One, Zero};
+
+/// The numbers from `start` up to but not including `end`, empty if `end` is not after `start`
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T> Interval<T>
+where
+    T: Copy + Ord + Zero + Add<Output = T> + Sub<Output = T>
+{
+    pub fn new(start: T, end: T) -> Interval<T> {
+        Interval { start, end }
+    }
+
+    pub fn with_length(start: T, length: T) -> Interval<T> {
+        Interval { start, end: start + length }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    pub fn length(&self) -> T {
+        if self.is_empty() { T::zero() } else { self.end - self.start }
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    pub fn overlaps(&self, rhs: &Interval<T>) -> bool {
+        !self.intersection(rhs).is_empty()
+    }
+
+    /// The numbers in both intervals, which may be empty
+    pub fn intersection(&self, rhs: &Interval<T>) -> Interval<T> {
+        Interval { start: self.start.max(rhs.start), end: self.end.min(rhs.end) }
+    }
+
+    /// The numbers below `value`, and the ones from `value` on
+    pub fn split_at(&self, value: T) -> (Interval<T>, Interval<T>) {
+        let value = value.clamp(self.start, self.end.max(self.start));
+        (Interval { start: self.start, end: value }, Interval { start: value, end: self.end })
+    }
+
+    /// The interval moved up by `offset`
+    pub fn shift(&self, offset: T) -> Interval<T> {
+        Interval { start: self.start + offset, end: self.end + offset }
+    }
+
+    /// The interval of the same length, moved to start at `start`
+    pub fn with_start(&self, start: T) -> Interval<T> {
+        Interval::with_length(start, self.length())
+    }
+}
+
+impl<T: Debug> Debug for Interval<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{:?}, {:?})", self.start, self.end)
+    }
+}
+
+/// A union of intervals, kept as disjoint, non-empty intervals in increasing order that do not touch
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T>(Vec<Interval<T>>);
+
+impl<T> Default for IntervalSet<T>
+where
+    T: Copy + Ord + Zero + Add<Output = T> + Sub<Output = T>
+{
+    fn default() -> Self {
+        IntervalSet::new()
+    }
+}
+
+impl<T> IntervalSet<T>
+where
+    T: Copy + Ord + Zero + Add<Output = T> + Sub<Output = T>
+{
+    pub fn new() -> IntervalSet<T> {
+        IntervalSet(Vec::new())
+    }
+
+    pub fn insert(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+
+        // The intervals before it, then the ones it overlaps or touches and is merged with, then the ones after it
+        let first_merged = self.0.partition_point(|existing| existing.end < interval.start);
+        let after_merged = self.0.partition_point(|existing| existing.start <= interval.end);
+        let merged = self.0[first_merged..after_merged].iter()
+            .fold(interval, |merged, existing| Interval::new(merged.start.min(existing.start), merged.end.max(existing.end)));
+
+        self.0.splice(first_merged..after_merged, [merged]);
+    }
+
+    pub fn union(&self, rhs: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut result = self.clone();
+        for interval in rhs.0.iter() {
+            result.insert(*interval);
+        }
+        result
+    }
+
+    pub fn intersection(&self, rhs: &IntervalSet<T>) -> IntervalSet<T> {
+        // Walk both in order, always moving past the interval that ends first
+        let mut result = Vec::new();
+        let (mut lhs_index, mut rhs_index) = (0usize, 0usize);
+        while let (Some(lhs), Some(rhs)) = (self.0.get(lhs_index), rhs.0.get(rhs_index)) {
+            let intersection = lhs.intersection(rhs);
+            if !intersection.is_empty() {
+                result.push(intersection);
+            }
+
+            if lhs.end < rhs.end {
+                lhs_index += 1;
+            } else {
+                rhs_index += 1;
+            }
+        }
+
+        IntervalSet(result)
+    }
+
+    /// The numbers in this set but not in `rhs`
+    pub fn difference(&self, rhs: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut result = Vec::new();
+        for interval in self.0.iter() {
+            let mut remaining = *interval;
+            for removed in rhs.0.iter().filter(|removed| removed.overlaps(interval)) {
+                let (before, _) = remaining.split_at(removed.start);
+                if !before.is_empty() {
+                    result.push(before);
+                }
+                (_, remaining) = remaining.split_at(removed.end);
+            }
+
+            if !remaining.is_empty() {
+                result.push(remaining);
+            }
+        }
+
+        IntervalSet(result)
+    }
+
+    /// The set moved up by `offset`
+    pub fn shift(&self, offset: T) -> IntervalSet<T> {
+        IntervalSet(self.0.iter().map(|interval| interval.shift(offset)).collect())
+    }
+}
+
+impl<T> From<Interval<T>> for IntervalSet<T>
+where
+    T: Copy + Ord + Zero + Add<Output = T> + Sub<Output = T>
+{
+    fn from(value: Interval<T>) -> Self {
+        IntervalSet::from_iter([value])
+    }
+}
+
+impl<T> FromIterator<Interval<T>> for IntervalSet<T>
+where
+    T: Copy + Ord + Zero + Add<Output = T> + Sub<Output = T>
+{
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut result = IntervalSet::new();
+        for interval in iter {
+            result.insert(interval);
+        }
+        result
+    }
+}
+
+impl<T> Deref for IntervalSet<T> {
+    type Target = [Interval<T>];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: Debug> Debug for IntervalSet<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.0.iter()).finish()
+    }
+}
+
+/// The points whose coordinate along each of the `N` axes is in the interval for that axis
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IntervalBox<T, const N: usize>(pub [Interval<T>; N]);
+
+impl<T, const N: usize> IntervalBox<T, N>
+where
+    T: Copy + Ord + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T>
+{
+    pub fn is_empty(&self) -> bool {
+        self.0.iter().any(|interval| interval.is_empty())
+    }
+
+    /// The number of points in the box, for integers
+    pub fn volume(&self) -> T {
+        self.0.iter().fold(T::one(), |volume, interval| volume * interval.length())
+    }
+
+    /// The points with a coordinate below `value` along `axis`, and the ones with a coordinate from `value` on
+    pub fn split_at(&self, axis: usize, value: T) -> (IntervalBox<T, N>, IntervalBox<T, N>) {
+        let (lower, upper) = self.0[axis].split_at(value);
+        let (mut below, mut above) = (*self, *self);
+        below.0[axis] = lower;
+        above.0[axis] = upper;
+        (below, above)
+    }
+}
+
+impl<T: Debug, const N: usize> Debug for IntervalBox<T, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.0.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::app::common::{check_property, shrink_vec, Random};
+    use super::*;
+
+    fn random_set(random: &mut Random, size: usize) -> Vec<(isize, isize)> {
+        (0..random.below(size + 1)).map(|_| (random.between(-15..=15) as isize, random.between(-15..=15) as isize)).collect()
+    }
+
+    fn to_set(intervals: &[(isize, isize)]) -> IntervalSet<isize> {
+        intervals.iter().map(|(start, end)| Interval::new(*start, *end)).collect()
+    }
+
+    fn members(set: &IntervalSet<isize>) -> Vec<isize> {
+        set.iter().flat_map(|interval| interval.start..interval.end).collect()
+    }
+
+    fn is_normalized(set: &IntervalSet<isize>) -> bool {
+        set.iter().all(|interval| !interval.is_empty()) && set.windows(2).all(|pair| pair[0].end < pair[1].start)
+    }
+
+    #[test]
+    fn should_match_membership() {
+        check_property(
+            500,
+            |random, size| (random_set(random, size), random_set(random, size), random.between(-5..=5) as isize),
+            |(lhs, rhs, offset)| {
+                let fewer_lhs = shrink_vec(lhs, |_| vec![]).into_iter().map(|lhs| (lhs, rhs.clone(), *offset));
+                let fewer_rhs = shrink_vec(rhs, |_| vec![]).into_iter().map(|rhs| (lhs.clone(), rhs, *offset));
+                fewer_lhs.chain(fewer_rhs).collect()
+            },
+            |(lhs, rhs, offset)| {
+                let (lhs_set, rhs_set) = (to_set(lhs), to_set(rhs));
+                let in_lhs = |member: isize| lhs.iter().any(|(start, end)| *start <= member && member < *end);
+                let in_rhs = |member: isize| rhs.iter().any(|(start, end)| *start <= member && member < *end);
+                let expected = |filter: &dyn Fn(isize) -> bool| (-30..30).filter(|member| filter(*member)).collect::<Vec<_>>();
+
+                let results = [
+                    ("set", lhs_set.clone(), expected(&in_lhs)),
+                    ("union", lhs_set.union(&rhs_set), expected(&|member| in_lhs(member) || in_rhs(member))),
+                    ("intersection", lhs_set.intersection(&rhs_set), expected(&|member| in_lhs(member) && in_rhs(member))),
+                    ("difference", lhs_set.difference(&rhs_set), expected(&|member| in_lhs(member) && !in_rhs(member))),
+                    ("shift", lhs_set.shift(*offset), expected(&|member| in_lhs(member - offset))),
+                ];
+
+                for (name, set, expected) in results {
+                    if !is_normalized(&set) {
+                        return Err(format!("{} not normalized: {:?}", name, set));
+                    }
+                    if members(&set) != expected {
+                        return Err(format!("{} is {:?}, expected {:?}", name, set, expected));
+                    }
+                }
+                Ok(())
+            },
+        );
+    }
+
+    #[test]
+    fn should_split_interval() {
+        let interval = Interval::with_length(3usize, 4);
+
+        assert_eq!(interval.split_at(5), (Interval::new(3, 5), Interval::new(5, 7)));
+        assert_eq!(interval.split_at(1), (Interval::new(3, 3), interval));
+        assert_eq!(interval.split_at(9), (interval, Interval::new(7, 7)));
+        assert_eq!(interval.with_start(10).length(), 4);
+        assert_eq!(Interval::new(5usize, 2).length(), 0);
+    }
+
+    #[test]
+    fn should_split_box() {
+        let cube = IntervalBox([Interval::new(0usize, 4); 3]);
+        let (below, above) = cube.split_at(1, 1);
+
+        assert_eq!(below.volume(), 16);
+        assert_eq!(above.volume(), 48);
+        assert_eq!(above.0[1], Interval::new(1, 4));
+        assert!(cube.split_at(2, 0).0.is_empty());
+    }
+}
@@ -0,0 +1,101 @@
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::hash::Hash;
+use std::iter::Sum;
+use std::ops::Add;
+
+/// How often a memo had a value already and how often it had to compute one
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct MemoStats {
+    pub hits: usize,
+    pub misses: usize,
+}
+
+impl Add for MemoStats {
+    type Output = MemoStats;
+
+    fn add(self, rhs: MemoStats) -> MemoStats {
+        MemoStats {
+            hits: self.hits + rhs.hits,
+            misses: self.misses + rhs.misses,
+        }
+    }
+}
+
+impl Sum for MemoStats {
+    fn sum<I: Iterator<Item = MemoStats>>(iter: I) -> MemoStats {
+        iter.fold(MemoStats::default(), |lhs, rhs| lhs + rhs)
+    }
+}
+
+impl Display for MemoStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} memo hits, {} misses", self.hits, self.misses)
+    }
+}
+
+/// The values of a recursive function, by key
+///
+/// Keys should be cheap to hash, such as offsets into input the function is defined on, rather than the input itself.
+pub struct Memo<K, V> {
+    values: RefCell<HashMap<K, V>>,
+    stats: Cell<MemoStats>,
+}
+
+impl<K: Eq + Hash, V: Clone> Default for Memo<K, V> {
+    fn default() -> Self {
+        Memo::new()
+    }
+}
+
+impl<K: Eq + Hash, V: Clone> Memo<K, V> {
+    pub fn new() -> Memo<K, V> {
+        Memo {
+            values: RefCell::new(HashMap::new()),
+            stats: Cell::new(MemoStats::default()),
+        }
+    }
+
+    /// The value for the key, computed by `compute` the first time, which may look up other keys in the same memo
+    pub fn get_or_compute(&self, key: K, compute: impl FnOnce() -> V) -> V {
+        let mut stats = self.stats.get();
+        if let Some(value) = self.values.borrow().get(&key) {
+            stats.hits += 1;
+            self.stats.set(stats);
+            return value.clone();
+        }
+        stats.misses += 1;
+        self.stats.set(stats);
+
+        let value = compute();
+        self.values.borrow_mut().insert(key, value.clone());
+
+        value
+    }
+
+    pub fn stats(&self) -> MemoStats {
+        self.stats.get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fibonacci(memo: &Memo<usize, usize>, n: usize) -> usize {
+        memo.get_or_compute(n, || if n < 2 { n } else { fibonacci(memo, n - 1) + fibonacci(memo, n - 2) })
+    }
+
+    #[test]
+    fn should_compute_each_value_once() {
+        let memo = Memo::new();
+
+        assert_eq!(fibonacci(&memo, 80), 23_416_728_348_467_685);
+        // Every value from 0 to 80 is computed once, and each of 3 to 80 looks up the value two before it
+        assert_eq!(memo.stats(), MemoStats { hits: 78, misses: 81 });
+
+        assert_eq!(fibonacci(&memo, 80), 23_416_728_348_467_685);
+        assert_eq!(memo.stats(), MemoStats { hits: 79, misses: 81 });
+    }
+}
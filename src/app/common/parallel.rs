@@ -0,0 +1,120 @@
+use std::cell::Cell;
+use std::panic::resume_unwind;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+thread_local! {
+    /// The number of threads the parallel parts started on this thread may use
+    static THREAD_COUNT: Cell<usize> = const { Cell::new(1) };
+    /// The time spent in the parallel parts started on this thread so far
+    static PARALLEL_TIME: Cell<ParallelTime> = const { Cell::new(ParallelTime { wall: Duration::ZERO, busy: Duration::ZERO }) };
+}
+
+/// Time spent in the parallel parts of a computation
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ParallelTime {
+    /// The time from the start to the end of each part, added up
+    pub wall: Duration,
+    /// The time the threads of each part were busy, added up over all threads
+    pub busy: Duration,
+}
+
+/// Runs `run` with its parallel parts spread over `thread_count` threads, and returns the time spent in those parts
+///
+/// The threads of the parallel parts run their own parallel parts on a single thread, so they do not multiply.
+pub fn with_thread_count<T>(thread_count: usize, run: impl FnOnce() -> T) -> (T, ParallelTime) {
+    let previous_thread_count = THREAD_COUNT.with(|count| count.replace(thread_count.max(1)));
+    let previous_time = PARALLEL_TIME.with(|time| time.take());
+
+    let result = run();
+
+    THREAD_COUNT.with(|count| count.set(previous_thread_count));
+    let time = PARALLEL_TIME.with(|time| time.replace(previous_time));
+
+    (result, time)
+}
+
+/// The number of threads a parallel part started here would use, which is always 1 without the `parallel` feature
+pub fn thread_count() -> usize {
+    if cfg!(feature = "parallel") { THREAD_COUNT.with(|count| count.get()) } else { 1 }
+}
+
+/// Maps every item on the threads of the current thread count, each thread taking the next item when it is done with
+/// one, and keeps the results in the order of the items
+pub fn parallel_map<T: Sync, U: Send>(items: &[T], map: impl Fn(&T) -> U + Sync) -> Vec<U> {
+    let thread_count = thread_count().min(items.len());
+    if thread_count <= 1 {
+        return items.iter().map(map).collect();
+    }
+
+    let start = Instant::now();
+    let next_index = AtomicUsize::new(0);
+    let worker_results: Vec<(Vec<(usize, U)>, Duration)> = std::thread::scope(|scope| {
+        let workers: Vec<_> = (0..thread_count)
+            .map(|_| scope.spawn(|| {
+                let worker_start = Instant::now();
+                let mut mapped = Vec::new();
+                loop {
+                    let index = next_index.fetch_add(1, Ordering::Relaxed);
+                    let Some(item) = items.get(index) else {
+                        break;
+                    };
+                    mapped.push((index, map(item)));
+                }
+                (mapped, worker_start.elapsed())
+            }))
+            .collect();
+
+        workers.into_iter()
+            .map(|worker| worker.join().unwrap_or_else(|panic| resume_unwind(panic)))
+            .collect()
+    });
+
+    let mut busy = Duration::ZERO;
+    let mut results: Vec<Option<U>> = items.iter().map(|_| None).collect();
+    for (mapped, worker_busy) in worker_results {
+        busy += worker_busy;
+        for (index, result) in mapped {
+            results[index] = Some(result);
+        }
+    }
+
+    PARALLEL_TIME.with(|time| {
+        let ParallelTime { wall: total_wall, busy: total_busy } = time.get();
+        time.set(ParallelTime { wall: total_wall + start.elapsed(), busy: total_busy + busy });
+    });
+
+    results.into_iter().map(|result| result.unwrap()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_keep_order_on_any_thread_count() {
+        let items: Vec<usize> = (0..100).collect();
+
+        for count in 1..=4 {
+            let (squares, _) = with_thread_count(count, || parallel_map(&items, |item| item * item));
+            assert_eq!(squares, items.iter().map(|item| item * item).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn should_count_time_of_parallel_parts_only() {
+        let (_, time) = with_thread_count(1, || parallel_map(&[1, 2], |item| item + 1));
+        assert_eq!(time, ParallelTime::default());
+
+        let (_, time) = with_thread_count(2, || {
+            parallel_map(&[10, 20], |millis| std::thread::sleep(Duration::from_millis(*millis)))
+        });
+        if cfg!(feature = "parallel") {
+            assert!(time.busy >= Duration::from_millis(30), "{:?}", time);
+            assert!(time.wall >= Duration::from_millis(20), "{:?}", time);
+        } else {
+            assert_eq!(time, ParallelTime::default());
+        }
+        assert_eq!(thread_count(), 1);
+    }
+}
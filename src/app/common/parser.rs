@@ -0,0 +1,385 @@
+use std::fmt::{Debug, Display, Formatter};
+use std::str::FromStr;
+use super::grid::{Grid, ParseGridError};
+
+/// What a parser expected and what it found instead, at a line and column counted from 1
+#[derive(Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}, column {}: expected {}, found {}", self.line, self.column, self.expected, self.found)
+    }
+}
+
+impl Debug for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl std::error::Error for ParseError { }
+
+/// A cursor over puzzle input that keeps track of its line and column
+///
+/// Each method consumes a piece of the input and fails without consuming anything if the piece is not there, except
+/// for the ones made up of several pieces, which fail where the first missing piece should have been.
+#[derive(Debug, Clone, Copy)]
+pub struct Parser<'a> {
+    rest: &'a str,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(input: &'a str) -> Parser<'a> {
+        Parser { rest: input, line: 1, column: 1 }
+    }
+
+    /// Parses the whole input, up to trailing whitespace
+    pub fn parse_all<T>(input: &'a str, parse: impl FnOnce(&mut Parser<'a>) -> Result<T, ParseError>) -> Result<T, ParseError> {
+        let mut parser = Parser::new(input);
+        let value = parse(&mut parser)?;
+        parser.take_while(char::is_whitespace);
+        parser.end()?;
+
+        Ok(value)
+    }
+
+    /// An error at the current position
+    pub fn error(&self, expected: impl Display) -> ParseError {
+        let found = if self.rest.is_empty() {
+            "end of input".to_string()
+        } else if self.is_at_line_end() {
+            "end of line".to_string()
+        } else {
+            // The rest of the word, or a single whitespace character
+            let first = self.rest.chars().next().unwrap();
+            let word: String = self.rest.chars().skip(1).take_while(|c| !first.is_whitespace() && !c.is_whitespace()).take(20).collect();
+            format!("\"{}{}\"", first, word)
+        };
+
+        ParseError { line: self.line, column: self.column, expected: expected.to_string(), found }
+    }
+
+    pub fn is_at_end(&self) -> bool {
+        self.rest.is_empty()
+    }
+
+    /// Whether the rest of the line is empty
+    pub fn is_at_line_end(&self) -> bool {
+        self.rest.is_empty() || self.rest.starts_with('\n') || self.rest.starts_with("\r\n")
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.rest.chars().next()
+    }
+
+    /// Consumes the next `length` bytes
+    fn advance(&mut self, length: usize) -> &'a str {
+        let (consumed, rest) = self.rest.split_at(length);
+        for c in consumed.chars() {
+            if c == '\n' {
+                self.line += 1;
+                self.column = 1;
+            } else {
+                self.column += 1;
+            }
+        }
+        self.rest = rest;
+
+        consumed
+    }
+
+    /// The characters up to the first one that does not match the predicate, which may be none
+    pub fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &'a str {
+        let length = self.rest.find(|c| !predicate(c)).unwrap_or(self.rest.len());
+        self.advance(length)
+    }
+
+    pub fn spaces(&mut self) {
+        self.take_while(|c| c == ' ');
+    }
+
+    /// A single character, which `parse` turns into a value or rejects
+    pub fn character<T>(&mut self, expected: &str, parse: impl FnOnce(char) -> Option<T>) -> Result<T, ParseError> {
+        let value = self.peek().and_then(parse).ok_or_else(|| self.error(expected))?;
+        self.advance(self.peek().unwrap().len_utf8());
+
+        Ok(value)
+    }
+
+    pub fn literal(&mut self, literal: &str) -> Result<(), ParseError> {
+        if self.try_literal(literal) {
+            Ok(())
+        } else {
+            Err(self.error(format!("\"{}\"", literal)))
+        }
+    }
+
+    /// Consumes the literal if it comes next
+    pub fn try_literal(&mut self, literal: &str) -> bool {
+        if self.rest.starts_with(literal) {
+            self.advance(literal.len());
+            true
+        } else {
+            false
+        }
+    }
+
+    /// A non-empty run of letters and digits
+    pub fn word(&mut self) -> Result<&'a str, ParseError> {
+        let word = self.take_while(char::is_alphanumeric);
+        if word.is_empty() { Err(self.error("word")) } else { Ok(word) }
+    }
+
+    /// A non-empty run of digits
+    pub fn unsigned<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        let start = *self;
+        let digits = self.take_while(|c| c.is_ascii_digit());
+        if digits.is_empty() {
+            return Err(self.error("number"));
+        }
+
+        digits.parse::<T>().map_err(|_| {
+            *self = start;
+            self.error(format!("number that fits in {}", std::any::type_name::<T>()))
+        })
+    }
+
+    /// A non-empty run of digits, possibly after a sign
+    pub fn integer<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        let start = *self;
+        if !self.try_literal("-") {
+            self.try_literal("+");
+        }
+        let signed_length = start.rest.len() - self.rest.len();
+
+        let digits = self.take_while(|c| c.is_ascii_digit());
+        if digits.is_empty() {
+            *self = start;
+            return Err(self.error("integer"));
+        }
+
+        start.rest[..signed_length + digits.len()].parse::<T>().map_err(|_| {
+            *self = start;
+            self.error(format!("integer that fits in {}", std::any::type_name::<T>()))
+        })
+    }
+
+    /// Integers separated by spaces
+    pub fn integers<T: FromStr>(&mut self) -> Result<Vec<T>, ParseError> {
+        self.spaced(|parser| parser.integer::<T>())
+    }
+
+    /// At least one item, with the separator and any spaces around it between each two
+    pub fn separated<T>(&mut self, separator: &str, mut item: impl FnMut(&mut Parser<'a>) -> Result<T, ParseError>) -> Result<Vec<T>, ParseError> {
+        let mut items = vec![item(self)?];
+        loop {
+            let before_separator = *self;
+            self.spaces();
+            if !self.try_literal(separator) {
+                *self = before_separator;
+                return Ok(items);
+            }
+            self.spaces();
+            items.push(item(self)?);
+        }
+    }
+
+    /// At least one item, with spaces between each two, up to the first thing after spaces that is not an item
+    pub fn spaced<T>(&mut self, mut item: impl FnMut(&mut Parser<'a>) -> Result<T, ParseError>) -> Result<Vec<T>, ParseError> {
+        let mut items = vec![item(self)?];
+        loop {
+            let before_spaces = *self;
+            self.spaces();
+            if self.rest.len() == before_spaces.rest.len() {
+                return Ok(items);
+            }
+            match item(self) {
+                Ok(next) => items.push(next),
+                Err(_) => {
+                    *self = before_spaces;
+                    return Ok(items);
+                }
+            }
+        }
+    }
+
+    /// A key, a colon and then a value after any spaces
+    pub fn key_value<K, V>(
+        &mut self,
+        key: impl FnOnce(&mut Parser<'a>) -> Result<K, ParseError>,
+        value: impl FnOnce(&mut Parser<'a>) -> Result<V, ParseError>,
+    ) -> Result<(K, V), ParseError> {
+        let key = key(self)?;
+        self.literal(":")?;
+        self.spaces();
+        let value = value(self)?;
+
+        Ok((key, value))
+    }
+
+    /// A value after a fixed key
+    pub fn labelled<T>(&mut self, label: &str, value: impl FnOnce(&mut Parser<'a>) -> Result<T, ParseError>) -> Result<T, ParseError> {
+        self.key_value(|parser| parser.literal(label), value).map(|(_, value)| value)
+    }
+
+    pub fn line_end(&mut self) -> Result<(), ParseError> {
+        if self.try_literal("\n") || self.try_literal("\r\n") {
+            Ok(())
+        } else {
+            Err(self.error("end of line"))
+        }
+    }
+
+    pub fn end(&self) -> Result<(), ParseError> {
+        if self.is_at_end() { Ok(()) } else { Err(self.error("end of input")) }
+    }
+
+    /// An item on each line, up to a blank line or the end
+    pub fn lines<T>(&mut self, mut item: impl FnMut(&mut Parser<'a>) -> Result<T, ParseError>) -> Result<Vec<T>, ParseError> {
+        let mut items: Vec<T> = Vec::new();
+        loop {
+            items.push(item(self)?);
+            if self.is_at_end() {
+                return Ok(items);
+            }
+            self.line_end()?;
+            if self.is_at_line_end() {
+                return Ok(items);
+            }
+        }
+    }
+
+    /// Sections separated by blank lines, where each section ends at the start of a line
+    pub fn sections<T>(&mut self, mut section: impl FnMut(&mut Parser<'a>) -> Result<T, ParseError>) -> Result<Vec<T>, ParseError> {
+        let mut sections: Vec<T> = Vec::new();
+        loop {
+            sections.push(section(self)?);
+            if self.is_at_end() {
+                return Ok(sections);
+            }
+            self.line_end()?;
+            while self.is_at_line_end() && !self.is_at_end() {
+                self.line_end()?;
+            }
+            if self.is_at_end() {
+                return Ok(sections);
+            }
+        }
+    }
+
+    /// A grid with a tile per character, up to a blank line or the end
+    pub fn grid<T>(&mut self, parse_tile: impl FnMut(char) -> Option<T>) -> Result<Grid<T>, ParseError> {
+        let length: usize = self.rest.split_inclusive('\n')
+            .take_while(|line| !line.trim_end_matches(['\r', '\n']).is_empty())
+            .map(|line| line.len())
+            .sum();
+        if length == 0 {
+            return Err(self.error("grid"));
+        }
+
+        let grid = Grid::parse_with(&self.rest[..length], parse_tile).map_err(|error| {
+            let (x, y, expected, found) = match error {
+                ParseGridError::UnevenRow { y, length, width } =>
+                    (length.min(width), y, format!("row of {} tiles", width), format!("{} tiles", length)),
+                ParseGridError::InvalidTile { x, y, tile } =>
+                    (x, y, "tile".to_string(), format!("\"{}\"", tile)),
+            };
+            let column = if y == 0 { self.column + x } else { x + 1 };
+            ParseError { line: self.line + y, column, expected, found }
+        })?;
+        self.advance(length);
+
+        Ok(grid)
+    }
+
+    /// The position of the first tile, row by row, of a grid parsed from here that matches the predicate, or an error
+    /// here if no tile does
+    pub fn grid_position<T>(&self, grid: &Grid<T>, expected: &str, predicate: impl Fn(&T) -> bool) -> Result<(usize, usize), ParseError> {
+        grid.position(predicate)
+            .ok_or_else(|| ParseError { found: "none in the grid".to_string(), ..self.error(expected) })
+    }
+
+    /// An error at tile `(x, y)` of a grid parsed from here
+    pub fn grid_error(&self, (x, y): (usize, usize), expected: impl Display) -> ParseError {
+        let mut parser = *self;
+        for _ in 0..y {
+            parser.take_while(|c| c != '\n');
+            parser.try_literal("\n");
+        }
+        for c in parser.rest.chars().take(x) {
+            parser.advance(c.len_utf8());
+        }
+
+        parser.error(expected)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_puzzle_format() {
+        let input = "seeds: 79 14\n\nsoil:\n 50  -98 2\n+52 50 48\n\n\nwater:\n0 15 37\n";
+        let (seeds, maps) = Parser::parse_all(input, |parser| {
+            let seeds = parser.labelled("seeds", |parser| parser.integers::<usize>())?;
+            parser.line_end()?;
+            parser.line_end()?;
+            let maps = parser.sections(|parser| parser.key_value(|parser| parser.word(), |parser| {
+                parser.line_end()?;
+                parser.lines(|parser| {
+                    parser.spaces();
+                    parser.integers::<isize>()
+                })
+            }))?;
+            Ok((seeds, maps))
+        }).unwrap();
+
+        assert_eq!(seeds, vec![79, 14]);
+        assert_eq!(maps, vec![("soil", vec![vec![50, -98, 2], vec![52, 50, 48]]), ("water", vec![vec![0, 15, 37]])]);
+        assert_eq!(Parser::parse_all("0, 15,  37", |parser| parser.separated(",", |parser| parser.unsigned::<usize>())), Ok(vec![0, 15, 37]));
+    }
+
+    #[test]
+    fn should_report_position() {
+        let error = Parser::parse_all("1 2\n3 x", |parser| parser.lines(|parser| parser.integers::<usize>())).unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 2: expected end of line, found \" \"");
+
+        let error = Parser::parse_all("12\n300", |parser| parser.lines(|parser| parser.unsigned::<u8>())).unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 1: expected number that fits in u8, found \"300\"");
+
+        let error = Parser::parse_all("a: b", |parser| parser.labelled("a", |parser| parser.literal("c"))).unwrap_err();
+        assert_eq!(error.to_string(), "line 1, column 4: expected \"c\", found \"b\"");
+    }
+
+    #[test]
+    fn should_parse_grid() {
+        let digits = |c: char| c.to_digit(10);
+        let grids = Parser::parse_all("12\n34\n\n5\n", |parser| parser.sections(|parser| parser.grid(digits))).unwrap();
+        assert_eq!(grids.iter().map(|grid| (grid.width(), grid.height())).collect::<Vec<_>>(), vec![(2, 2), (1, 1)]);
+
+        let error = Parser::parse_all("12\n34\n\n5x\n", |parser| parser.sections(|parser| parser.grid(digits))).unwrap_err();
+        assert_eq!(error.to_string(), "line 4, column 2: expected tile, found \"x\"");
+    }
+
+    #[test]
+    fn should_report_tiles_of_grid() {
+        let (grid, start) = Parser::parse_all("ab\ncd\n", |parser| {
+            let start = *parser;
+            let grid = parser.grid(Some)?;
+            Ok((grid, start))
+        }).unwrap();
+
+        assert_eq!(start.grid_position(&grid, "d", |c| *c == 'd'), Ok((1, 1)));
+        assert_eq!(start.grid_position(&grid, "e", |c| *c == 'e').unwrap_err().to_string(), "line 1, column 1: expected e, found none in the grid");
+        assert_eq!(start.grid_error((1, 1), "e").to_string(), "line 2, column 2: expected e, found \"d\"");
+    }
+}
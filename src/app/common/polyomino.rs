@@ -0,0 +1,89 @@
+use std::collections::{HashMap, HashSet};
+use super::Random;
+
+const RING: [(isize, isize); 8] = [(0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0), (-1, -1)];
+
+/// A set of grid cells without holes, whose cells never touch diagonally only, so its boundary is a single simple loop
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polyomino(HashSet<(usize, usize)>);
+
+impl Polyomino {
+    /// Grows a random polyomino of at most `cell_count` cells inside a `width` by `height` grid
+    pub fn random(random: &mut Random, width: usize, height: usize, cell_count: usize) -> Self {
+        let start = (random.below(width), random.below(height));
+        let mut cells = vec![start];
+        let mut polyomino = Self(HashSet::from([start]));
+
+        let mut attempts = 0usize;
+        while cells.len() < cell_count && attempts < 50 * cell_count {
+            attempts += 1;
+
+            let (x, y) = *random.choose(&cells);
+            let (dx, dy) = *random.choose(&[(0, -1), (1, 0), (0, 1), (-1, 0)]);
+            let (Some(x), Some(y)) = (x.checked_add_signed(dx), y.checked_add_signed(dy)) else { continue };
+
+            if x < width && y < height && polyomino.can_grow_into((x, y)) {
+                polyomino.0.insert((x, y));
+                cells.push((x, y));
+            }
+        }
+
+        polyomino
+    }
+
+    pub fn contains(&self, cell: (usize, usize)) -> bool {
+        self.0.contains(&cell)
+    }
+
+    /// The corners of the cells on the boundary, in clockwise order
+    pub fn boundary(&self) -> Vec<(usize, usize)> {
+        let mut next_corners: HashMap<(usize, usize), (usize, usize)> = HashMap::new();
+
+        for &(x, y) in self.0.iter() {
+            if y == 0 || !self.contains((x, y - 1)) {
+                next_corners.insert((x, y), (x + 1, y));
+            }
+            if !self.contains((x + 1, y)) {
+                next_corners.insert((x + 1, y), (x + 1, y + 1));
+            }
+            if !self.contains((x, y + 1)) {
+                next_corners.insert((x + 1, y + 1), (x, y + 1));
+            }
+            if x == 0 || !self.contains((x - 1, y)) {
+                next_corners.insert((x, y + 1), (x, y));
+            }
+        }
+
+        let start = *next_corners.keys().min().unwrap();
+        let mut boundary = vec![start];
+        let mut corner = next_corners[&start];
+        while corner != start {
+            boundary.push(corner);
+            corner = next_corners[&corner];
+        }
+
+        boundary
+    }
+
+    /// Whether adding the cell keeps the polyomino free of holes and diagonal-only contacts
+    fn can_grow_into(&self, cell: (usize, usize)) -> bool {
+        if self.contains(cell) {
+            return false;
+        }
+
+        let (x, y) = cell;
+        let ring: Vec<bool> = RING.iter()
+            .map(|&(dx, dy)| match (x.checked_add_signed(dx), y.checked_add_signed(dy)) {
+                (Some(x), Some(y)) => self.contains((x, y)),
+                _ => false,
+            })
+            .collect();
+
+        let runs = (0..ring.len())
+            .filter(|&i| ring[i] && !ring[(i + ring.len() - 1) % ring.len()])
+            .count();
+        let touches_side = ring.iter().step_by(2).any(|&inside| inside);
+
+        runs == 1 && touches_side
+    }
+}
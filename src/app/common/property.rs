@@ -0,0 +1,97 @@
+use std::fmt::Debug;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use super::Random;
+
+/// Checks a property on `case_count` generated inputs of growing size. When it fails or panics, the input is shrunk for
+/// as long as a smaller candidate still fails, and the smallest failing input is reported.
+pub fn check_property<T: Debug>(
+    case_count: usize,
+    generate: impl Fn(&mut Random, usize) -> T,
+    shrink: impl Fn(&T) -> Vec<T>,
+    property: impl Fn(&T) -> Result<(), String>,
+) {
+    for case in 0..case_count {
+        let seed = case as u64;
+        let size = 1 + case * 10 / case_count.max(1);
+        let input = generate(&mut Random::new(seed), size);
+
+        if let Err(error) = holds(&property, &input) {
+            let (minimized, error) = minimize(input, error, &shrink, &property);
+            panic!("property failed for seed {} and size {}: {}\nminimized counterexample: {:#?}", seed, size, error, minimized);
+        }
+    }
+}
+
+fn minimize<T>(
+    mut input: T,
+    mut error: String,
+    shrink: &impl Fn(&T) -> Vec<T>,
+    property: &impl Fn(&T) -> Result<(), String>,
+) -> (T, String) {
+    'shrinking: loop {
+        for candidate in shrink(&input) {
+            if let Err(candidate_error) = holds(property, &candidate) {
+                input = candidate;
+                error = candidate_error;
+                continue 'shrinking;
+            }
+        }
+
+        return (input, error);
+    }
+}
+
+fn holds<T>(property: &impl Fn(&T) -> Result<(), String>, input: &T) -> Result<(), String> {
+    catch_unwind(AssertUnwindSafe(|| property(input))).unwrap_or_else(|panic| {
+        let message = panic.downcast_ref::<String>().cloned()
+            .or_else(|| panic.downcast_ref::<&str>().map(|message| message.to_string()))
+            .unwrap_or_default();
+        Err(format!("panicked: {}", message))
+    })
+}
+
+/// Smaller numbers to try in place of `value`
+pub fn shrink_number(value: usize) -> Vec<usize> {
+    let mut candidates = vec![0, value / 2, value - value.min(1)];
+    candidates.retain(|candidate| *candidate < value);
+    candidates.dedup();
+    candidates
+}
+
+/// The vector with one element removed, or one element shrunk
+pub fn shrink_vec<T: Clone>(items: &[T], shrink_item: impl Fn(&T) -> Vec<T>) -> Vec<Vec<T>> {
+    let removed = (0..items.len()).map(|index| {
+        let mut candidate = items.to_vec();
+        candidate.remove(index);
+        candidate
+    });
+    let shrunk = (0..items.len()).flat_map(|index| {
+        shrink_item(&items[index]).into_iter().map(move |item| {
+            let mut candidate = items.to_vec();
+            candidate[index] = item;
+            candidate
+        })
+    });
+
+    removed.chain(shrunk).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_minimize_counterexample() {
+        let result = std::panic::catch_unwind(|| {
+            check_property(
+                20,
+                |random, size| (0..size).map(|_| random.below(100)).collect::<Vec<_>>(),
+                |items| shrink_vec(items, |item| shrink_number(*item)),
+                |items| if items.iter().all(|item| *item < 50) { Ok(()) } else { Err("item too large".to_string()) },
+            )
+        });
+
+        let message = *result.unwrap_err().downcast::<String>().unwrap();
+        assert!(message.contains("[\n    50,\n]"), "{}", message);
+    }
+}
@@ -0,0 +1,56 @@
+use std::ops::RangeInclusive;
+
+/// A small seeded pseudo-random number generator (SplitMix64), so generated inputs are reproducible
+#[derive(Debug, Clone)]
+pub struct Random {
+    state: u64,
+}
+
+impl Random {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..bound`
+    pub fn below(&mut self, bound: usize) -> usize {
+        assert!(bound > 0, "cannot pick a number below 0");
+        ((self.next_u64() as u128 * bound as u128) >> 64) as usize
+    }
+
+    /// A number in the given inclusive range
+    pub fn between(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "cannot pick a number from an empty range");
+        let width = (end as i128 - start as i128 + 1) as u128;
+        (start as i128 + ((self.next_u64() as u128 * width) >> 64) as i128) as i64
+    }
+
+    /// Whether an event with probability `numerator / denominator` happens
+    pub fn chance(&mut self, numerator: usize, denominator: usize) -> bool {
+        self.below(denominator) < numerator
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    /// A word of lowercase letters
+    pub fn lowercase_word(&mut self, length: usize) -> String {
+        (0..length).map(|_| (b'a' + self.below(26) as u8) as char).collect()
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.below(i + 1);
+            items.swap(i, j);
+        }
+    }
+}
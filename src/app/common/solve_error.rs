@@ -0,0 +1,40 @@
+use std::fmt::{Debug, Display, Formatter};
+use super::ParseError;
+
+/// Why a solver could not solve an input
+#[derive(Clone)]
+pub enum SolveError {
+    /// The input is malformed
+    Parse(ParseError),
+    /// The input is well-formed, but does not have the structure the solver relies on
+    Unsupported(String),
+}
+
+impl SolveError {
+    pub fn unsupported(reason: impl Into<String>) -> SolveError {
+        SolveError::Unsupported(reason.into())
+    }
+}
+
+impl From<ParseError> for SolveError {
+    fn from(error: ParseError) -> Self {
+        SolveError::Parse(error)
+    }
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SolveError::Parse(error) => Display::fmt(error, f),
+            SolveError::Unsupported(reason) => write!(f, "unsupported input: {}", reason),
+        }
+    }
+}
+
+impl Debug for SolveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl std::error::Error for SolveError { }
@@ -1,6 +1,21 @@
+mod axis_box;
+mod big_int;
 mod gcd;
 mod traits;
 mod crt;
+mod line;
+mod linear;
+mod polynomial;
+mod rational;
+mod vector;
 
-pub use crt::Mod;
-pub use gcd::Gcd;
+pub use axis_box::{Cuboid, Rectangle};
+pub use big_int::{sum_of_products, BigInt};
+pub use crt::{crt, CrtError, Mod};
+pub use gcd::{bezout, lcm, lcm_of, Bezout, Gcd};
+pub use line::{Crossing, Intersection, Line2, Segment2};
+pub use linear::solve_linear_system;
+pub use polynomial::{lagrange_interpolate, FiniteDifferences};
+pub use rational::Rational;
+pub use traits::{checked::Checked, modular::Modular, one::One, signed::Signed, zero::Zero};
+pub use vector::{Vector2, Vector3};
@@ -0,0 +1,102 @@
+use std::ops::{Add, Sub};
+use super::vector::{Vector2, Vector3};
+
+/// The points from `min` to `max` in the plane, both included, with sides along the axes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rectangle<T> {
+    pub min: Vector2<T>,
+    pub max: Vector2<T>,
+}
+
+/// The points from `min` to `max` in space, both included, with sides along the axes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cuboid<T> {
+    pub min: Vector3<T>,
+    pub max: Vector3<T>,
+}
+
+impl<T: Clone> Cuboid<T> {
+    /// The projection onto the plane of the x and y axes
+    pub fn footprint(&self) -> Rectangle<T> {
+        Rectangle { min: self.min.clone().xy(), max: self.max.clone().xy() }
+    }
+}
+
+impl<T: Clone + Add<Output = T> + Sub<Output = T>> Cuboid<T> {
+    /// The cuboid of the same size, moved to have its lowest corner at `min`
+    pub fn moved_to(&self, min: Vector3<T>) -> Cuboid<T> {
+        Cuboid { max: min.clone() + (self.max.clone() - self.min.clone()), min }
+    }
+}
+
+fn min_max<T: PartialOrd>(lhs: T, rhs: T) -> (T, T) {
+    if rhs < lhs { (rhs, lhs) } else { (lhs, rhs) }
+}
+
+/// Whether the closed ranges from `lhs_min` to `lhs_max` and from `rhs_min` to `rhs_max` share a number
+fn ranges_overlap<T: PartialOrd>(lhs_min: &T, lhs_max: &T, rhs_min: &T, rhs_max: &T) -> bool {
+    lhs_min <= rhs_max && rhs_min <= lhs_max
+}
+
+macro_rules! impl_axis_box {
+    ($axis_box:ident, $vector:ident, $($axis:ident),*) => {
+        impl<T: PartialOrd> $axis_box<T> {
+            /// The smallest box with both points as corners
+            pub fn from_corners(lhs: $vector<T>, rhs: $vector<T>) -> $axis_box<T> {
+                $(let $axis = min_max(lhs.$axis, rhs.$axis);)*
+                $axis_box {
+                    min: $vector { $($axis: $axis.0),* },
+                    max: $vector { $($axis: $axis.1),* },
+                }
+            }
+
+            pub fn contains(&self, point: &$vector<T>) -> bool {
+                $(self.min.$axis <= point.$axis && point.$axis <= self.max.$axis)&&*
+            }
+
+            pub fn overlaps(&self, rhs: &$axis_box<T>) -> bool {
+                $(ranges_overlap(&self.min.$axis, &self.max.$axis, &rhs.min.$axis, &rhs.max.$axis))&&*
+            }
+        }
+
+        impl<T> $axis_box<T> {
+            pub fn map<U>(self, f: impl Fn(T) -> U) -> $axis_box<U> {
+                $axis_box { min: self.min.map(&f), max: self.max.map(&f) }
+            }
+        }
+    };
+}
+
+impl_axis_box!(Rectangle, Vector2, x, y);
+impl_axis_box!(Cuboid, Vector3, x, y, z);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_contain_boundary() {
+        let rectangle = Rectangle::from_corners(Vector2::new(3, -1), Vector2::new(0, 2));
+        assert_eq!(rectangle, Rectangle { min: Vector2::new(0, -1), max: Vector2::new(3, 2) });
+        assert!(rectangle.contains(&Vector2::new(3, -1)));
+        assert!(rectangle.contains(&Vector2::new(1, 0)));
+        assert!(!rectangle.contains(&Vector2::new(4, 0)));
+    }
+
+    #[test]
+    fn should_overlap_when_touching() {
+        let cuboid = Cuboid { min: Vector3::new(0, 0, 0), max: Vector3::new(2, 2, 2) };
+        let touching = Cuboid { min: Vector3::new(2, 1, 1), max: Vector3::new(3, 3, 3) };
+        let apart = Cuboid { min: Vector3::new(1, 3, 1), max: Vector3::new(1, 4, 1) };
+
+        assert!(cuboid.overlaps(&touching) && touching.overlaps(&cuboid));
+        assert!(!cuboid.overlaps(&apart) && !apart.overlaps(&cuboid));
+        assert!(cuboid.footprint().overlaps(&Rectangle { min: Vector2::new(2, 2), max: Vector2::new(5, 5) }));
+    }
+
+    #[test]
+    fn should_keep_size_when_moved() {
+        let cuboid = Cuboid { min: Vector3::new(1usize, 0, 5), max: Vector3::new(1, 2, 7) };
+        assert_eq!(cuboid.moved_to(Vector3::new(1, 0, 1)), Cuboid { min: Vector3::new(1, 0, 1), max: Vector3::new(1, 2, 3) });
+    }
+}
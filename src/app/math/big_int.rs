@@ -0,0 +1,510 @@
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display, Formatter};
+use std::iter::{Product, Sum};
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Rem, RemAssign, Sub};
+use std::str::FromStr;
+use super::traits::{checked::Checked, one::One, signed::Signed, zero::Zero};
+
+/// An integer of any size, for answers and intermediate values that do not fit in a primitive
+///
+/// Division and remainder truncate towards zero, like they do for the primitive integers.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct BigInt {
+    negative: bool,
+    /// Base 2^32 digits, least significant first, without leading zeros; empty for zero
+    magnitude: Vec<u32>,
+}
+
+impl BigInt {
+    fn from_parts(negative: bool, mut magnitude: Vec<u32>) -> BigInt {
+        while magnitude.last() == Some(&0) {
+            magnitude.pop();
+        }
+        BigInt { negative: negative && !magnitude.is_empty(), magnitude }
+    }
+
+    /// The value as a `usize`, if it fits in one
+    pub fn to_usize(&self) -> Option<usize> {
+        if self.negative || self.magnitude.len() * 32 > usize::BITS as usize {
+            return None;
+        }
+        // Shifting in two steps, as a single shift by 32 overflows when `usize` is only 32 bits
+        Some(self.magnitude.iter().rev().fold(0usize, |value, digit| (value << 16 << 16) | *digit as usize))
+    }
+
+    /// The largest integer whose square is at most this number, which should not be negative
+    pub fn sqrt(&self) -> BigInt {
+        assert!(!self.negative, "Square root of negative number");
+        if self.is_zero() {
+            return BigInt::zero();
+        }
+
+        // Newton's method, starting above the root so it decreases until it hits the floor
+        let bit_length = self.magnitude.len() * 32 - self.magnitude.last().unwrap().leading_zeros() as usize;
+        let mut root = BigInt::power_of_two((bit_length + 1) / 2);
+        loop {
+            let next_root = (&root + &(self / &root)) / BigInt::from(2usize);
+            if next_root >= root {
+                return root;
+            }
+            root = next_root;
+        }
+    }
+
+    fn power_of_two(exponent: usize) -> BigInt {
+        let mut magnitude = vec![0u32; exponent / 32 + 1];
+        magnitude[exponent / 32] = 1 << (exponent % 32);
+        BigInt::from_parts(false, magnitude)
+    }
+}
+
+/// Sums products of `usize` factors, computed in a `usize` for as long as that does not overflow
+pub fn sum_of_products<T: AsRef<[usize]>>(terms: impl IntoIterator<Item = T>) -> BigInt {
+    let big_product = |factors: &[usize]| factors.iter().map(|factor| BigInt::from(*factor)).product::<BigInt>();
+
+    let mut terms = terms.into_iter();
+    let mut sum = 0usize;
+    while let Some(term) = terms.next() {
+        let factors = term.as_ref();
+        let checked_sum = factors.iter()
+            .try_fold(1usize, |product, factor| product.checked_mul(*factor))
+            .and_then(|product| sum.checked_add(product));
+
+        match checked_sum {
+            Some(checked_sum) => sum = checked_sum,
+            None => {
+                let big_sum = BigInt::from(sum) + big_product(factors);
+                return terms.fold(big_sum, |big_sum, term| big_sum + big_product(term.as_ref()));
+            }
+        }
+    }
+
+    BigInt::from(sum)
+}
+
+fn compare_magnitudes(lhs: &[u32], rhs: &[u32]) -> Ordering {
+    lhs.len().cmp(&rhs.len()).then_with(|| lhs.iter().rev().cmp(rhs.iter().rev()))
+}
+
+fn add_magnitudes(lhs: &[u32], rhs: &[u32]) -> Vec<u32> {
+    let mut result = Vec::with_capacity(lhs.len().max(rhs.len()) + 1);
+    let mut carry = 0u64;
+    for index in 0..lhs.len().max(rhs.len()) {
+        let sum = *lhs.get(index).unwrap_or(&0) as u64 + *rhs.get(index).unwrap_or(&0) as u64 + carry;
+        result.push(sum as u32);
+        carry = sum >> 32;
+    }
+    result.push(carry as u32);
+    result
+}
+
+/// Subtracts the magnitudes, the left of which should be the larger one
+fn sub_magnitudes(lhs: &[u32], rhs: &[u32]) -> Vec<u32> {
+    let mut result = Vec::with_capacity(lhs.len());
+    let mut borrow = 0i64;
+    for index in 0..lhs.len() {
+        let difference = lhs[index] as i64 - *rhs.get(index).unwrap_or(&0) as i64 - borrow;
+        result.push(difference.rem_euclid(1 << 32) as u32);
+        borrow = if difference < 0 { 1 } else { 0 };
+    }
+    result
+}
+
+fn mul_magnitudes(lhs: &[u32], rhs: &[u32]) -> Vec<u32> {
+    let mut result = vec![0u32; lhs.len() + rhs.len()];
+    for (lhs_index, lhs_digit) in lhs.iter().enumerate() {
+        let mut carry = 0u64;
+        for (rhs_index, rhs_digit) in rhs.iter().enumerate() {
+            let product = *lhs_digit as u64 * *rhs_digit as u64 + result[lhs_index + rhs_index] as u64 + carry;
+            result[lhs_index + rhs_index] = product as u32;
+            carry = product >> 32;
+        }
+        result[lhs_index + rhs.len()] = carry as u32;
+    }
+    result
+}
+
+/// The quotient and remainder of the magnitudes, by long division one bit at a time
+fn div_rem_magnitudes(lhs: &[u32], rhs: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    assert!(!rhs.is_empty(), "Division of BigInt by zero");
+
+    if let [divisor] = rhs {
+        let divisor = *divisor as u64;
+        let mut quotient = vec![0u32; lhs.len()];
+        let mut remainder = 0u64;
+        for index in (0..lhs.len()).rev() {
+            let current = (remainder << 32) | lhs[index] as u64;
+            quotient[index] = (current / divisor) as u32;
+            remainder = current % divisor;
+        }
+        return (quotient, vec![remainder as u32]);
+    }
+
+    let mut quotient = vec![0u32; lhs.len()];
+    let mut remainder: Vec<u32> = Vec::with_capacity(rhs.len() + 1);
+    for bit in (0..lhs.len() * 32).rev() {
+        // Shift the next bit of the dividend into the remainder
+        let mut carry = (lhs[bit / 32] >> (bit % 32)) & 1;
+        for digit in remainder.iter_mut() {
+            let shifted_out = *digit >> 31;
+            *digit = (*digit << 1) | carry;
+            carry = shifted_out;
+        }
+        if carry != 0 {
+            remainder.push(carry);
+        }
+
+        if compare_magnitudes(&remainder, rhs) != Ordering::Less {
+            remainder = sub_magnitudes(&remainder, rhs);
+            while remainder.last() == Some(&0) {
+                remainder.pop();
+            }
+            quotient[bit / 32] |= 1 << (bit % 32);
+        }
+    }
+
+    (quotient, remainder)
+}
+
+impl From<usize> for BigInt {
+    fn from(value: usize) -> Self {
+        BigInt::from(value as i128)
+    }
+}
+
+impl From<i128> for BigInt {
+    fn from(value: i128) -> Self {
+        let magnitude = value.unsigned_abs();
+        BigInt::from_parts(value < 0, (0..4).map(|index| (magnitude >> (32 * index)) as u32).collect())
+    }
+}
+
+impl Zero for BigInt {
+    fn zero() -> Self {
+        BigInt { negative: false, magnitude: Vec::new() }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.magnitude.is_empty()
+    }
+}
+
+impl One for BigInt {
+    fn one() -> Self {
+        BigInt { negative: false, magnitude: vec![1] }
+    }
+
+    fn is_one(&self) -> bool {
+        !self.negative && self.magnitude == [1]
+    }
+}
+
+impl Signed for BigInt {
+    fn abs(&self) -> Self {
+        BigInt { negative: false, magnitude: self.magnitude.clone() }
+    }
+
+    fn is_negative(&self) -> bool {
+        self.negative
+    }
+}
+
+/// Only division by zero fails, as nothing overflows
+impl Checked for BigInt {
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        Some(self + rhs)
+    }
+
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        Some(self - rhs)
+    }
+
+    fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        Some(self * rhs)
+    }
+
+    fn checked_div(&self, rhs: &Self) -> Option<Self> {
+        if rhs.is_zero() { None } else { Some(self / rhs) }
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, false) => compare_magnitudes(&self.magnitude, &other.magnitude),
+            (true, true) => compare_magnitudes(&other.magnitude, &self.magnitude),
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> Self::Output {
+        BigInt::from_parts(!self.negative, self.magnitude)
+    }
+}
+
+impl<'a, 'b> Add<&'b BigInt> for &'a BigInt {
+    type Output = BigInt;
+
+    fn add(self, rhs: &'b BigInt) -> Self::Output {
+        if self.negative == rhs.negative {
+            BigInt::from_parts(self.negative, add_magnitudes(&self.magnitude, &rhs.magnitude))
+        } else if compare_magnitudes(&self.magnitude, &rhs.magnitude) != Ordering::Less {
+            BigInt::from_parts(self.negative, sub_magnitudes(&self.magnitude, &rhs.magnitude))
+        } else {
+            BigInt::from_parts(rhs.negative, sub_magnitudes(&rhs.magnitude, &self.magnitude))
+        }
+    }
+}
+
+impl<'a, 'b> Sub<&'b BigInt> for &'a BigInt {
+    type Output = BigInt;
+
+    fn sub(self, rhs: &'b BigInt) -> Self::Output {
+        self + &-rhs.clone()
+    }
+}
+
+impl<'a, 'b> Mul<&'b BigInt> for &'a BigInt {
+    type Output = BigInt;
+
+    fn mul(self, rhs: &'b BigInt) -> Self::Output {
+        BigInt::from_parts(self.negative != rhs.negative, mul_magnitudes(&self.magnitude, &rhs.magnitude))
+    }
+}
+
+impl<'a, 'b> Div<&'b BigInt> for &'a BigInt {
+    type Output = BigInt;
+
+    /// Panics when dividing by zero
+    fn div(self, rhs: &'b BigInt) -> Self::Output {
+        let (quotient, _) = div_rem_magnitudes(&self.magnitude, &rhs.magnitude);
+        BigInt::from_parts(self.negative != rhs.negative, quotient)
+    }
+}
+
+impl<'a, 'b> Rem<&'b BigInt> for &'a BigInt {
+    type Output = BigInt;
+
+    /// Panics when dividing by zero
+    fn rem(self, rhs: &'b BigInt) -> Self::Output {
+        let (_, remainder) = div_rem_magnitudes(&self.magnitude, &rhs.magnitude);
+        BigInt::from_parts(self.negative, remainder)
+    }
+}
+
+impl Add for BigInt {
+    type Output = BigInt;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        &self + &rhs
+    }
+}
+
+impl Sub for BigInt {
+    type Output = BigInt;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        &self - &rhs
+    }
+}
+
+impl Mul for BigInt {
+    type Output = BigInt;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl Div for BigInt {
+    type Output = BigInt;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        &self / &rhs
+    }
+}
+
+impl Rem for BigInt {
+    type Output = BigInt;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        &self % &rhs
+    }
+}
+
+impl AddAssign for BigInt {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = &*self + &rhs;
+    }
+}
+
+impl<'a> RemAssign<&'a BigInt> for BigInt {
+    fn rem_assign(&mut self, rhs: &'a BigInt) {
+        *self = &*self % rhs;
+    }
+}
+
+impl Sum for BigInt {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(BigInt::zero(), |sum, value| sum + value)
+    }
+}
+
+impl Product for BigInt {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(BigInt::one(), |product, value| product * value)
+    }
+}
+
+#[derive(Clone)]
+pub struct ParseBigIntError(String);
+
+impl Display for ParseBigIntError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid value \"{}\" for BigInt", &self.0)
+    }
+}
+
+impl Debug for ParseBigIntError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl std::error::Error for ParseBigIntError { }
+
+impl FromStr for BigInt {
+    type Err = ParseBigIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, s),
+        };
+        if digits.is_empty() || !digits.bytes().all(|digit| digit.is_ascii_digit()) {
+            return Err(ParseBigIntError(s.to_string()));
+        }
+
+        let ten = BigInt::from(10usize);
+        let magnitude = digits.bytes()
+            .fold(BigInt::zero(), |value, digit| &(&value * &ten) + &BigInt::from((digit - b'0') as usize));
+        Ok(BigInt::from_parts(negative, magnitude.magnitude))
+    }
+}
+
+impl Display for BigInt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+
+        // Split off nine decimal digits at a time, least significant first
+        let mut chunks: Vec<u32> = Vec::new();
+        let mut magnitude = self.magnitude.clone();
+        while !magnitude.is_empty() {
+            let (quotient, remainder) = div_rem_magnitudes(&magnitude, &[1_000_000_000]);
+            chunks.push(remainder[0]);
+            magnitude = BigInt::from_parts(false, quotient).magnitude;
+        }
+
+        if self.negative {
+            write!(f, "-")?;
+        }
+        write!(f, "{}", chunks.pop().unwrap())?;
+        for chunk in chunks.iter().rev() {
+            write!(f, "{:0>9}", chunk)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Debug for BigInt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::app::math::{bezout, Bezout, Gcd, Rational};
+    use super::*;
+
+    const SAMPLES: [i128; 9] = [0, 1, -1, 7, -12, 4_294_967_296, -18_446_744_073_709_551_617, 123_456_789_012_345_678_901, i64::MIN as i128];
+
+    #[test]
+    fn should_match_i128_arithmetic() {
+        for lhs in SAMPLES {
+            for rhs in SAMPLES {
+                let (big_lhs, big_rhs) = (BigInt::from(lhs), BigInt::from(rhs));
+
+                assert_eq!(&big_lhs + &big_rhs, BigInt::from(lhs + rhs), "{} + {}", lhs, rhs);
+                assert_eq!(&big_lhs - &big_rhs, BigInt::from(lhs - rhs), "{} - {}", lhs, rhs);
+                assert_eq!(big_lhs.cmp(&big_rhs), lhs.cmp(&rhs), "{} <=> {}", lhs, rhs);
+                if let Some(product) = lhs.checked_mul(rhs) {
+                    assert_eq!(&big_lhs * &big_rhs, BigInt::from(product), "{} * {}", lhs, rhs);
+                }
+                if rhs != 0 {
+                    assert_eq!(&big_lhs / &big_rhs, BigInt::from(lhs / rhs), "{} / {}", lhs, rhs);
+                    assert_eq!(&big_lhs % &big_rhs, BigInt::from(lhs % rhs), "{} % {}", lhs, rhs);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn should_go_beyond_i128() {
+        let value = "-340282366920938463463374607431768211456000000000000000000001".parse::<BigInt>().unwrap();
+        let square = &value * &value;
+
+        assert_eq!(value.to_string(), "-340282366920938463463374607431768211456000000000000000000001");
+        assert_eq!(&square / &value, value);
+        assert_eq!((&square - &BigInt::one()).sqrt(), -value.clone() - BigInt::one());
+        assert_eq!(square.sqrt(), -value);
+        assert_eq!("12a".parse::<BigInt>().map_err(|error| error.to_string()), Err("invalid value \"12a\" for BigInt".to_string()));
+    }
+
+    #[test]
+    fn should_convert_to_usize() {
+        assert_eq!(BigInt::from(usize::MAX).to_usize(), Some(usize::MAX));
+        assert_eq!((BigInt::from(usize::MAX) + BigInt::one()).to_usize(), None);
+        assert_eq!(BigInt::from(-3i128).to_usize(), None);
+    }
+
+    fn power_of_three(exponent: usize) -> BigInt {
+        (0..exponent).map(|_| BigInt::from(3usize)).product()
+    }
+
+    #[test]
+    fn should_find_gcd() {
+        let lhs = power_of_three(50) * BigInt::from(4usize);
+        let rhs = power_of_three(40) * BigInt::from(10usize);
+
+        assert_eq!(lhs.clone().gcd(rhs.clone()).gcd, power_of_three(40) * BigInt::from(2usize));
+
+        let Bezout { lhs_coefficient, rhs_coefficient, gcd } = bezout(-lhs.clone(), rhs.clone());
+        assert_eq!(gcd, power_of_three(40) * BigInt::from(2usize));
+        assert_eq!(&lhs_coefficient * &-lhs + &rhs_coefficient * &rhs, gcd);
+        assert_eq!(Rational::new(BigInt::from(6usize), BigInt::from(-4i128)), Rational::new(BigInt::from(-3i128), BigInt::from(2usize)));
+    }
+
+    #[test]
+    fn should_switch_to_big_int_on_overflow() {
+        assert_eq!(sum_of_products([[2, 3], [4, 5]]), BigInt::from(26usize));
+        assert_eq!(
+            sum_of_products([vec![usize::MAX, 2], vec![1], vec![3]]),
+            BigInt::from(usize::MAX) * BigInt::from(2usize) + BigInt::from(4usize)
+        );
+    }
+}
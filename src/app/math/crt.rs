@@ -1,49 +1,55 @@
-use super::gcd::Gcd;
+use super::gcd::{Gcd, GcdResult};
 
+/// The congruence `x ≡ value (mod modulo)`
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Mod {
     pub value: usize,
     pub modulo: usize
 }
 
-impl Mod {
-    pub fn try_mul(self, rhs: Self) -> Option<Mod> {
-        let result = self.modulo.gcd(rhs.modulo);
-        let lcm = (self.modulo / result.gcd) * rhs.modulo;
-
-        if self.value == rhs.value {
-            Some(Mod {
-                value: self.value,
-                modulo: lcm
-            })
-        } else if self.value > rhs.value {
-            let diff = self.value - rhs.value;
-            if diff % result.gcd == 0 {
-                let value = ((diff / result.gcd) * result.rhs_inverse) % lcm;
-                let value = (value * rhs.modulo) % lcm;
-                let value = (value + rhs.value) % lcm;
-                Some(Mod {
-                    value,
-                    modulo: lcm
-                })
-            } else {
-                None
-            }
-        } else {
-            let diff = rhs.value - self.value;
-            if diff % result.gcd == 0 {
-                let value = ((diff / result.gcd) * result.lhs_inverse) % lcm;
-                let value = (value * self.modulo) % lcm;
-                let value = (value + self.value) % lcm;
-                Some(Mod {
-                    value,
-                    modulo: lcm
-                })
-            } else {
-                None
-            }
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CrtError {
+    /// No number satisfies all congruences at once
+    NoSolution,
+    /// The combined modulus does not fit in a `usize`
+    Overflow,
+}
+
+/// Combines congruences with positive moduli into the single congruence that holds for exactly the numbers that
+/// satisfy all of them, with the least common multiple as modulus
+///
+/// The moduli need not be coprime. No congruences at all are satisfied by every number, i.e. `x ≡ 0 (mod 1)`.
+pub fn crt(congruences: impl IntoIterator<Item = Mod>) -> Result<Mod, CrtError> {
+    let mut result = (0u128, 1u128);
+
+    for congruence in congruences {
+        result = merge(result, (congruence.value as u128, congruence.modulo as u128))?;
+        if result.1 > usize::MAX as u128 {
+            return Err(CrtError::Overflow);
         }
     }
+
+    let (value, modulo) = result;
+    Ok(Mod { value: value as usize, modulo: modulo as usize })
+}
+
+/// Merges two congruences, with both moduli below 2^64 so no product below overflows
+fn merge((lhs_value, lhs_modulo): (u128, u128), (rhs_value, rhs_modulo): (u128, u128)) -> Result<(u128, u128), CrtError> {
+    let lhs_value = lhs_value % lhs_modulo;
+    let rhs_value = rhs_value % rhs_modulo;
+
+    // The lhs inverse satisfies lhs_inverse * lhs_modulo ≡ gcd (mod rhs_modulo)
+    let GcdResult { lhs_inverse, gcd, .. } = lhs_modulo.gcd(rhs_modulo);
+    let difference = (rhs_value + rhs_modulo - lhs_value % rhs_modulo) % rhs_modulo;
+    if difference % gcd != 0 {
+        return Err(CrtError::NoSolution);
+    }
+
+    // Solve lhs_value + lhs_modulo * k ≡ rhs_value (mod rhs_modulo) for k, modulo rhs_modulo / gcd
+    let step = rhs_modulo / gcd;
+    let k = (difference / gcd) % step * (lhs_inverse % step) % step;
+
+    Ok((lhs_value + lhs_modulo * k, lhs_modulo * step))
 }
 
 #[cfg(test)]
@@ -55,8 +61,44 @@ mod tests {
         let lhs = Mod { value: 4, modulo: 7 };
         let rhs = Mod { value: 2, modulo: 9 };
 
-        let result = lhs.try_mul(rhs);
+        assert_eq!(crt([lhs, rhs]), Ok(Mod { value: 11, modulo: 63 }));
+    }
+
+    #[test]
+    fn should_match_brute_force_for_small_moduli() {
+        for lhs_modulo in 1..=12 {
+            for rhs_modulo in 1..=12 {
+                for lhs_value in 0..lhs_modulo {
+                    for rhs_value in 0..rhs_modulo {
+                        let lcm = (1..).find(|x| x % lhs_modulo == 0 && x % rhs_modulo == 0).unwrap();
+                        let expected = (0..lcm).find(|x| x % lhs_modulo == lhs_value && x % rhs_modulo == rhs_value)
+                            .map(|value| Mod { value, modulo: lcm })
+                            .ok_or(CrtError::NoSolution);
+
+                        let result = crt([Mod { value: lhs_value, modulo: lhs_modulo }, Mod { value: rhs_value, modulo: rhs_modulo }]);
+                        assert_eq!(result, expected, "{} mod {} and {} mod {}", lhs_value, lhs_modulo, rhs_value, rhs_modulo);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn should_fold_many_congruences() {
+        let congruences = [2, 3, 5, 7, 11, 13].map(|modulo| Mod { value: 1, modulo });
+
+        assert_eq!(crt(congruences), Ok(Mod { value: 1, modulo: 30030 }));
+        assert_eq!(crt([]), Ok(Mod { value: 0, modulo: 1 }));
+    }
+
+    #[test]
+    fn should_not_overflow_on_large_moduli() {
+        let lhs = Mod { value: usize::MAX - 1, modulo: usize::MAX };
+        let rhs = Mod { value: 0, modulo: 2 };
 
-        assert_eq!(result, Some(Mod { value: 11, modulo: 63 }))
+        assert_eq!(crt([lhs, Mod { value: 3, modulo: usize::MAX - 2 }]), Err(CrtError::Overflow));
+        assert_eq!(crt([lhs, lhs]), Ok(lhs));
+        assert_eq!(crt([Mod { value: 1, modulo: 1 << 40 }, Mod { value: 0, modulo: 1 << 41 }]), Err(CrtError::NoSolution));
+        assert_eq!(crt([Mod { value: 1 << 40, modulo: 1 << 62 }, rhs]), Ok(Mod { value: 1 << 40, modulo: 1 << 62 }));
     }
 }
@@ -1,6 +1,10 @@
 use std::ops::{AddAssign, Div, Mul, RemAssign, Sub};
-use super::traits::{zero::Zero, one::One};
+use super::traits::{checked::Checked, one::One, signed::Signed, zero::Zero};
 
+/// The greatest common divisor of two non-negative numbers, with `lhs_inverse * lhs ≡ gcd (mod rhs)` and
+/// `rhs_inverse * rhs ≡ gcd (mod lhs)`
+///
+/// The inverses are non-negative, so they fit in unsigned numbers; see [`bezout`] for the signed coefficients.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct GcdResult<T> {
     pub lhs_inverse: T,
@@ -8,6 +12,9 @@ pub struct GcdResult<T> {
     pub gcd: T,
 }
 
+/// The extended Euclidean algorithm
+///
+/// For negative numbers, the gcd may come out negative.
 pub trait Gcd: Sized {
     type Scalar;
 
@@ -67,8 +74,54 @@ where
     }
 }
 
+/// Coefficients with `lhs_coefficient * lhs + rhs_coefficient * rhs == gcd`, with a non-negative gcd
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bezout<T> {
+    pub lhs_coefficient: T,
+    pub rhs_coefficient: T,
+    pub gcd: T,
+}
+
+/// The gcd of two numbers of any sign with the coefficients of Bézout's identity, if the least common multiple fits
+pub fn bezout<T>(lhs: T, rhs: T) -> Bezout<T>
+where
+    T: Clone + Gcd + Signed + Sub<Output = T> + Mul<Output = T> + Div<Output = T>
+{
+    let (lhs_magnitude, rhs_magnitude) = (lhs.abs(), rhs.abs());
+    let GcdResult { lhs_inverse, gcd, .. } = lhs_magnitude.clone().gcd(rhs_magnitude.clone());
+
+    // The inverse gives the gcd up to a multiple of the rhs, which is what the rhs coefficient makes up for
+    let rhs_inverse = if rhs_magnitude.is_zero() {
+        T::zero()
+    } else {
+        (gcd.clone() - lhs_inverse.clone() * lhs_magnitude) / rhs_magnitude
+    };
+
+    Bezout {
+        lhs_coefficient: if lhs.is_negative() { -lhs_inverse } else { lhs_inverse },
+        rhs_coefficient: if rhs.is_negative() { -rhs_inverse } else { rhs_inverse },
+        gcd,
+    }
+}
+
+/// The least common multiple of two non-negative numbers, which is zero if either is zero, if it fits
+pub fn lcm<T: Clone + Zero + Gcd + Checked>(lhs: T, rhs: T) -> Option<T> {
+    if lhs.is_zero() || rhs.is_zero() {
+        return Some(T::zero());
+    }
+
+    let gcd = lhs.clone().gcd(rhs.clone()).gcd;
+    lhs.checked_div(&gcd)?.checked_mul(&rhs)
+}
+
+/// The least common multiple of non-negative numbers, which is 1 for no numbers at all, if it fits
+pub fn lcm_of<T: Clone + Zero + One + Gcd + Checked>(values: impl IntoIterator<Item = T>) -> Option<T> {
+    values.into_iter().try_fold(T::one(), lcm)
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::app::math::{BigInt, Modular};
     use super::*;
 
     #[test]
@@ -77,4 +130,119 @@ mod tests {
 
         assert_eq!(result, GcdResult { lhs_inverse: 1, rhs_inverse: 0, gcd: 3 });
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn should_compute_lcm() {
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(lcm_of([2, 3, 4, 5]), Some(60));
+        assert_eq!(lcm_of([]), Some(1));
+        assert_eq!(lcm(usize::MAX, usize::MAX - 1), None);
+        assert_eq!(lcm(0u8, 5), Some(0));
+        assert_eq!(lcm(BigInt::from(usize::MAX), BigInt::from(usize::MAX - 1)), Some(BigInt::from(usize::MAX) * BigInt::from(usize::MAX - 1)));
+    }
+
+    /// Smaller numbers of the same sign to try in place of `value`
+    #[cfg(feature = "ssr")]
+    fn shrink_signed(value: i64) -> Vec<i64> {
+        let mut candidates = vec![0, value / 2, value - value.signum()];
+        candidates.retain(|candidate| candidate.abs() < value.abs());
+        candidates.dedup();
+        candidates
+    }
+
+    #[test]
+    #[cfg(feature = "ssr")]
+    fn should_satisfy_bezout_modulo() {
+        use crate::app::common::{check_property, shrink_number};
+
+        check_property(
+            1000,
+            |random, size| {
+                let bits = 6 * size as u32;
+                (random.below(1 << bits), random.below(1 << bits))
+            },
+            |&(lhs, rhs)| {
+                let smaller_lhs = shrink_number(lhs).into_iter().map(move |lhs| (lhs, rhs));
+                let smaller_rhs = shrink_number(rhs).into_iter().map(move |rhs| (lhs, rhs));
+                smaller_lhs.chain(smaller_rhs).collect()
+            },
+            |&(lhs, rhs)| {
+                let GcdResult { lhs_inverse, rhs_inverse, gcd } = (lhs as u64).gcd(rhs as u64);
+                let (lhs, rhs, gcd) = (lhs as u128, rhs as u128, gcd as u128);
+                let is_congruent = |value: u128, modulo: u128| if modulo == 0 { value == gcd } else { value % modulo == gcd % modulo };
+
+                if gcd == 0 && (lhs, rhs) != (0, 0) || gcd != 0 && (lhs % gcd != 0 || rhs % gcd != 0) {
+                    Err(format!("{} does not divide both", gcd))
+                } else if !is_congruent(lhs_inverse as u128 * lhs, rhs) {
+                    Err(format!("{} * {} is not {} modulo {}", lhs_inverse, lhs, gcd, rhs))
+                } else if !is_congruent(rhs_inverse as u128 * rhs, lhs) {
+                    Err(format!("{} * {} is not {} modulo {}", rhs_inverse, rhs, gcd, lhs))
+                } else {
+                    Ok(())
+                }
+            },
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "ssr")]
+    fn should_satisfy_bezout_identity() {
+        use crate::app::common::check_property;
+
+        check_property(
+            1000,
+            |random, size| {
+                let bound = 8i64.pow(size as u32);
+                (random.between(-bound..=bound), random.between(-bound..=bound))
+            },
+            |&(lhs, rhs)| {
+                let smaller_lhs = shrink_signed(lhs).into_iter().map(move |lhs| (lhs, rhs));
+                let smaller_rhs = shrink_signed(rhs).into_iter().map(move |rhs| (lhs, rhs));
+                smaller_lhs.chain(smaller_rhs).collect()
+            },
+            |&(lhs, rhs)| {
+                let Bezout { lhs_coefficient, rhs_coefficient, gcd } = bezout(lhs, rhs);
+                let expected = (lhs.unsigned_abs() as usize).gcd(rhs.unsigned_abs() as usize).gcd as i64;
+
+                if gcd != expected {
+                    Err(format!("got gcd {}, expected {}", gcd, expected))
+                } else if lhs_coefficient * lhs + rhs_coefficient * rhs != gcd {
+                    Err(format!("{} * {} + {} * {} is not {}", lhs_coefficient, lhs, rhs_coefficient, rhs, gcd))
+                } else {
+                    Ok(())
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn should_find_modular_inverses() {
+        for modulus in 1..=30i32 {
+            for value in -30..=30i32 {
+                let expected = (0..modulus).find(|inverse| (value * inverse).rem_euclid(modulus) == 1 % modulus);
+                assert_eq!(value.mod_inverse(&modulus), expected, "{} modulo {}", value, modulus);
+            }
+        }
+
+        assert_eq!(3u8.mod_inverse(&255), None);
+        assert_eq!(BigInt::from(-3i128).mod_inverse(&BigInt::from(7usize)), Some(BigInt::from(2usize)));
+    }
+
+    #[test]
+    fn should_raise_to_modular_powers() {
+        for modulus in 1..=20u32 {
+            for value in 0..20u32 {
+                for exponent in 0..10u64 {
+                    let expected = (0..exponent).fold(1 % modulus, |power, _| power * value % modulus);
+                    assert_eq!(value.mod_pow(exponent, &modulus), expected, "{} ^ {} modulo {}", value, exponent, modulus);
+                }
+            }
+        }
+
+        // Fermat's little theorem, with a modulus whose square does not fit
+        let prime = u64::MAX - 58;
+        assert_eq!(12345u64.mod_pow(prime - 1, &prime), 1);
+        assert_eq!((-2i64).mod_pow(3, &5), 2);
+        assert_eq!(BigInt::from(2usize).mod_pow(100, &BigInt::from(1_000_000_007usize)), BigInt::from(976_371_285usize));
+    }
+}
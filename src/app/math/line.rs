@@ -0,0 +1,228 @@
+use std::fmt::{Debug, Display, Formatter};
+use super::axis_box::Rectangle;
+use super::rational::{Integer, Rational};
+use super::traits::{one::One, zero::Zero};
+use super::vector::Vector2;
+
+/// How two lines or segments meet: not at all, in a single point, or along a stretch they share
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Intersection<Point, Shared> {
+    None,
+    Point(Point),
+    Coincident(Shared),
+}
+
+/// The point where two lines cross, with the parameter of that point along each of them
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Crossing<T> {
+    pub point: Vector2<Rational<T>>,
+    pub lhs_parameter: Rational<T>,
+    pub rhs_parameter: Rational<T>,
+}
+
+impl<T: Integer + Display> Debug for Crossing<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Crossing")
+            .field("point", &self.point)
+            .field("lhs_parameter", &self.lhs_parameter)
+            .field("rhs_parameter", &self.rhs_parameter)
+            .finish()
+    }
+}
+
+/// The points `origin + t * direction` for every number `t`, the parameter of the point
+///
+/// The direction must not be zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Line2<T> {
+    pub origin: Vector2<T>,
+    pub direction: Vector2<T>,
+}
+
+/// The points from `start` to `end`, which must differ
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Segment2<T> {
+    pub start: Vector2<T>,
+    pub end: Vector2<T>,
+}
+
+impl<T: Integer> Line2<T> {
+    pub fn point_at(&self, parameter: &Rational<T>) -> Vector2<Rational<T>> {
+        self.origin.clone().map(Rational::from_integer) + self.direction.clone().map(|d| Rational::from_integer(d) * parameter.clone())
+    }
+
+    pub fn contains(&self, point: &Vector2<T>) -> bool {
+        (point.clone() - self.origin.clone()).cross(&self.direction).is_zero()
+    }
+
+    /// The parameter of the point on the line closest to `point`, which is the point itself if it is on the line
+    pub fn parameter_of(&self, point: &Vector2<T>) -> Rational<T> {
+        Rational::new((point.clone() - self.origin.clone()).dot(&self.direction), self.direction.dot(&self.direction))
+    }
+
+    /// The lines cross in a point, unless they are parallel, and then they either coincide or never meet
+    pub fn intersection(&self, rhs: &Line2<T>) -> Intersection<Crossing<T>, Line2<T>> {
+        let offset = rhs.origin.clone() - self.origin.clone();
+        let denominator = self.direction.cross(&rhs.direction);
+
+        if denominator.is_zero() {
+            return if offset.cross(&self.direction).is_zero() {
+                Intersection::Coincident(self.clone())
+            } else {
+                Intersection::None
+            };
+        }
+
+        let lhs_parameter = Rational::new(offset.cross(&rhs.direction), denominator.clone());
+        Intersection::Point(Crossing {
+            point: self.point_at(&lhs_parameter),
+            lhs_parameter,
+            rhs_parameter: Rational::new(offset.cross(&self.direction), denominator),
+        })
+    }
+
+    /// The first and last parameter of the points on the line inside `rectangle`, if there are any
+    pub fn parameters_inside(&self, rectangle: &Rectangle<T>) -> Option<(Rational<T>, Rational<T>)> {
+        let axes = [
+            (&self.origin.x, &self.direction.x, &rectangle.min.x, &rectangle.max.x),
+            (&self.origin.y, &self.direction.y, &rectangle.min.y, &rectangle.max.y),
+        ];
+
+        let mut range: Option<(Rational<T>, Rational<T>)> = None;
+        for (origin, direction, min, max) in axes {
+            if direction.is_zero() {
+                if origin < min || max < origin {
+                    return None;
+                }
+                continue;
+            }
+
+            let to_min = Rational::new(min.clone() - origin.clone(), direction.clone());
+            let to_max = Rational::new(max.clone() - origin.clone(), direction.clone());
+            let (first, last) = if to_min < to_max { (to_min, to_max) } else { (to_max, to_min) };
+            range = Some(match range {
+                None => (first, last),
+                Some((start, end)) => (
+                    if start < first { first } else { start },
+                    if last < end { last } else { end },
+                ),
+            });
+        }
+
+        // The direction is not zero, so some axis has limited the range
+        range.filter(|(start, end)| start <= end)
+    }
+}
+
+impl<T: Integer> Segment2<T> {
+    /// The line through the segment, with the start at parameter 0 and the end at parameter 1
+    pub fn as_line(&self) -> Line2<T> {
+        Line2 { origin: self.start.clone(), direction: self.end.clone() - self.start.clone() }
+    }
+
+    /// The segments cross in a point, touch at an end, overlap along a segment, or never meet
+    pub fn intersection(&self, rhs: &Segment2<T>) -> Intersection<Vector2<Rational<T>>, Segment2<T>> {
+        let line = self.as_line();
+        let on_segment = |parameter: &Rational<T>| Rational::zero() <= *parameter && *parameter <= Rational::one();
+
+        match line.intersection(&rhs.as_line()) {
+            Intersection::None => Intersection::None,
+            Intersection::Point(crossing) => {
+                if on_segment(&crossing.lhs_parameter) && on_segment(&crossing.rhs_parameter) {
+                    Intersection::Point(crossing.point)
+                } else {
+                    Intersection::None
+                }
+            },
+            Intersection::Coincident(_) => {
+                let (rhs_start, rhs_end) = (line.parameter_of(&rhs.start), line.parameter_of(&rhs.end));
+                let (rhs_first, rhs_last) = if rhs_start < rhs_end {
+                    ((rhs_start, rhs.start.clone()), (rhs_end, rhs.end.clone()))
+                } else {
+                    ((rhs_end, rhs.end.clone()), (rhs_start, rhs.start.clone()))
+                };
+
+                let (first_parameter, first) = if Rational::zero() < rhs_first.0 { rhs_first } else { (Rational::zero(), self.start.clone()) };
+                let (last_parameter, last) = if rhs_last.0 < Rational::one() { rhs_last } else { (Rational::one(), self.end.clone()) };
+
+                if last_parameter < first_parameter {
+                    Intersection::None
+                } else if first_parameter == last_parameter {
+                    Intersection::Point(first.map(Rational::from_integer))
+                } else {
+                    Intersection::Coincident(Segment2 { start: first, end: last })
+                }
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(x: i64, y: i64) -> Vector2<i64> {
+        Vector2::new(x, y)
+    }
+
+    fn rational_point(x: (i64, i64), y: (i64, i64)) -> Vector2<Rational<i64>> {
+        Vector2::new(Rational::new(x.0, x.1), Rational::new(y.0, y.1))
+    }
+
+    fn segment(start: (i64, i64), end: (i64, i64)) -> Segment2<i64> {
+        Segment2 { start: point(start.0, start.1), end: point(end.0, end.1) }
+    }
+
+    #[test]
+    fn should_cross_lines() {
+        let lhs = Line2 { origin: point(0, 0), direction: point(2, 1) };
+        let rhs = Line2 { origin: point(3, 0), direction: point(0, -3) };
+
+        assert_eq!(lhs.intersection(&rhs), Intersection::Point(Crossing {
+            point: rational_point((3, 1), (3, 2)),
+            lhs_parameter: Rational::new(3, 2),
+            rhs_parameter: Rational::new(-1, 2),
+        }));
+    }
+
+    #[test]
+    fn should_tell_parallel_from_coincident_lines() {
+        let line = Line2 { origin: point(1, 1), direction: point(2, 2) };
+
+        assert_eq!(line.intersection(&Line2 { origin: point(0, 1), direction: point(-1, -1) }), Intersection::None);
+        assert_eq!(line.intersection(&Line2 { origin: point(5, 5), direction: point(-3, -3) }), Intersection::Coincident(line));
+        assert!(line.contains(&point(-4, -4)));
+        assert_eq!(line.parameter_of(&point(4, 4)), Rational::new(3, 2));
+    }
+
+    #[test]
+    fn should_clip_lines_to_rectangles() {
+        let rectangle = Rectangle { min: point(0, 0), max: point(4, 2) };
+
+        let diagonal = Line2 { origin: point(1, 1), direction: point(1, 1) };
+        assert_eq!(diagonal.parameters_inside(&rectangle), Some((Rational::new(-1, 1), Rational::new(1, 1))));
+
+        let vertical = Line2 { origin: point(4, 7), direction: point(0, -2) };
+        assert_eq!(vertical.parameters_inside(&rectangle), Some((Rational::new(5, 2), Rational::new(7, 2))));
+
+        let outside = Line2 { origin: point(5, 0), direction: point(0, 1) };
+        assert_eq!(outside.parameters_inside(&rectangle), None);
+
+        let missing = Line2 { origin: point(0, 3), direction: point(1, 1) };
+        assert_eq!(missing.parameters_inside(&rectangle), None);
+    }
+
+    #[test]
+    fn should_intersect_segments() {
+        let lhs = segment((0, 0), (4, 4));
+
+        assert_eq!(lhs.intersection(&segment((0, 4), (4, 0))), Intersection::Point(rational_point((2, 1), (2, 1))));
+        assert_eq!(lhs.intersection(&segment((0, 4), (1, 3))), Intersection::None);
+        assert_eq!(lhs.intersection(&segment((1, 0), (5, 4))), Intersection::None);
+        assert_eq!(lhs.intersection(&segment((4, 4), (6, 6))), Intersection::Point(rational_point((4, 1), (4, 1))));
+        assert_eq!(lhs.intersection(&segment((5, 5), (6, 6))), Intersection::None);
+        assert_eq!(lhs.intersection(&segment((6, 6), (2, 2))), Intersection::Coincident(segment((2, 2), (4, 4))));
+        assert_eq!(lhs.intersection(&segment((3, 3), (-1, -1))), Intersection::Coincident(segment((0, 0), (3, 3))));
+        assert_eq!(lhs.intersection(&segment((1, 1), (2, 2))), Intersection::Coincident(segment((1, 1), (2, 2))));
+    }
+}
@@ -0,0 +1,80 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use super::traits::{zero::Zero, one::One};
+
+/// The operations Gaussian elimination needs, with exact division like for rationals
+pub trait Field: Clone + PartialEq + Zero + One + Neg<Output = Self> + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> {}
+
+impl<T> Field for T
+where
+    T: Clone + PartialEq + Zero + One + Neg<Output = T> + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T> {}
+
+/// Solves `matrix * x = rhs` for a square matrix by Gaussian elimination, or returns `None` if the matrix is singular
+///
+/// Meant for small dense systems; this takes cubic time in the number of unknowns.
+pub fn solve_linear_system<F: Field>(mut matrix: Vec<Vec<F>>, mut rhs: Vec<F>) -> Option<Vec<F>> {
+    let size = rhs.len();
+    assert!(matrix.len() == size && matrix.iter().all(|row| row.len() == size), "Linear system should be square");
+
+    for column in 0..size {
+        let pivot_row = (column..size).find(|row| !matrix[*row][column].is_zero())?;
+        matrix.swap(column, pivot_row);
+        rhs.swap(column, pivot_row);
+
+        let pivot = matrix[column][column].clone();
+        for row in column + 1..size {
+            if matrix[row][column].is_zero() {
+                continue;
+            }
+
+            let factor = matrix[row][column].clone() / pivot.clone();
+            for entry in column..size {
+                matrix[row][entry] = matrix[row][entry].clone() - factor.clone() * matrix[column][entry].clone();
+            }
+            rhs[row] = rhs[row].clone() - factor * rhs[column].clone();
+        }
+    }
+
+    // Back substitution, from the last unknown up
+    let mut solution: Vec<F> = vec![F::zero(); size];
+    for row in (0..size).rev() {
+        let known = (row + 1..size)
+            .fold(F::zero(), |sum, column| sum + matrix[row][column].clone() * solution[column].clone());
+        solution[row] = (rhs[row].clone() - known) / matrix[row][row].clone();
+    }
+
+    Some(solution)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::app::math::Rational;
+    use super::*;
+
+    fn rationals(values: &[i128]) -> Vec<Rational<i128>> {
+        values.iter().map(|value| Rational::from_integer(*value)).collect()
+    }
+
+    #[test]
+    fn should_solve_system() {
+        // x + 2y = 5 and 3x - y = 1, needing a row swap first
+        let matrix = vec![rationals(&[0, 1, 1]), rationals(&[1, 2, 0]), rationals(&[3, -1, 0])];
+
+        let solution = solve_linear_system(matrix, rationals(&[4, 5, 1])).unwrap();
+
+        assert_eq!(solution, vec![Rational::new(1, 1), Rational::new(2, 1), Rational::new(2, 1)]);
+    }
+
+    #[test]
+    fn should_solve_system_with_fractional_solution() {
+        let matrix = vec![rationals(&[2, 0]), rationals(&[1, 3])];
+
+        assert_eq!(solve_linear_system(matrix, rationals(&[1, 1])), Some(vec![Rational::new(1, 2), Rational::new(1, 6)]));
+    }
+
+    #[test]
+    fn should_not_solve_singular_system() {
+        let matrix = vec![rationals(&[1, 2]), rationals(&[2, 4])];
+
+        assert_eq!(solve_linear_system(matrix, rationals(&[1, 2])), None);
+    }
+}
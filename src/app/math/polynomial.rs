@@ -0,0 +1,106 @@
+use std::ops::{Add, Div, Mul, Sub};
+use super::linear::Field;
+use super::traits::{zero::Zero, one::One};
+
+/// The polynomial of least degree through values at `0, 1, 2, ...`, kept as the first entry of every row of the
+/// difference table
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FiniteDifferences<T> {
+    /// Down to the last row that is not all zeros
+    leading_differences: Vec<T>,
+}
+
+impl<T> FiniteDifferences<T>
+where
+    T: Clone + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>
+{
+    pub fn new(values: &[T]) -> FiniteDifferences<T> {
+        let mut leading_differences = Vec::new();
+        let mut row = values.to_vec();
+        while row.iter().any(|value| !value.is_zero()) {
+            leading_differences.push(row[0].clone());
+            row = row.windows(2).map(|pair| pair[1].clone() - pair[0].clone()).collect();
+        }
+
+        FiniteDifferences { leading_differences }
+    }
+
+    /// The degree of the polynomial, or `None` if it is zero everywhere
+    ///
+    /// If this is one less than the number of values, the values may not come from a polynomial of lower degree.
+    pub fn degree(&self) -> Option<usize> {
+        self.leading_differences.len().checked_sub(1)
+    }
+
+    /// The value at any integer `n`, also before the first value or far after the last one
+    pub fn evaluate(&self, n: T) -> T {
+        // Newton's forward difference formula, with `n choose k` as the coefficient of the k-th difference
+        let mut value = T::zero();
+        let mut binomial = T::one();
+        let mut k = T::zero();
+        for difference in self.leading_differences.iter() {
+            value = value + binomial.clone() * difference.clone();
+            binomial = binomial * (n.clone() - k.clone()) / (k.clone() + T::one());
+            k = k + T::one();
+        }
+
+        value
+    }
+}
+
+/// The value at `x` of the polynomial of least degree through the given points, which should have distinct x
+/// coordinates
+pub fn lagrange_interpolate<F: Field>(points: &[(F, F)], x: F) -> F {
+    points.iter().enumerate()
+        .map(|(index, (point_x, point_y))| {
+            let basis = points.iter().enumerate()
+                .filter(|(other_index, _)| *other_index != index)
+                .fold(F::one(), |basis, (_, (other_x, _))| {
+                    basis * (x.clone() - other_x.clone()) / (point_x.clone() - other_x.clone())
+                });
+            point_y.clone() * basis
+        })
+        .fold(F::zero(), |sum, term| sum + term)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::app::math::Rational;
+    use super::*;
+
+    fn cubic(n: isize) -> isize {
+        2 * n * n * n - 5 * n * n + 7
+    }
+
+    #[test]
+    fn should_extrapolate_polynomial() {
+        let values: Vec<isize> = (0..8).map(cubic).collect();
+        let differences = FiniteDifferences::new(&values);
+
+        assert_eq!(differences.degree(), Some(3));
+        assert_eq!(differences.evaluate(8), cubic(8));
+        assert_eq!(differences.evaluate(1000), cubic(1000));
+        assert_eq!(differences.evaluate(-1), cubic(-1));
+        assert_eq!(differences.evaluate(-25), cubic(-25));
+    }
+
+    #[test]
+    fn should_fit_constant_and_zero_values() {
+        assert_eq!(FiniteDifferences::new(&[3isize, 3, 3]).degree(), Some(0));
+        assert_eq!(FiniteDifferences::new(&[3isize, 3, 3]).evaluate(-7), 3);
+        assert_eq!(FiniteDifferences::new(&[0isize, 0]).degree(), None);
+        assert_eq!(FiniteDifferences::new(&[0isize, 0]).evaluate(5), 0);
+        assert_eq!(FiniteDifferences::<isize>::new(&[]).evaluate(5), 0);
+    }
+
+    #[test]
+    fn should_interpolate_through_points() {
+        let rational = |value: i128| Rational::from_integer(value);
+        // y = x^2 / 2 - 3, sampled at unevenly spaced points
+        let points = [(rational(-2), rational(-1)), (rational(1), Rational::new(-5, 2)), (rational(4), rational(5))];
+
+        assert_eq!(lagrange_interpolate(&points, rational(10)), rational(47));
+        assert_eq!(lagrange_interpolate(&points, rational(3)), Rational::new(3, 2));
+        assert_eq!(lagrange_interpolate(&points, rational(1)), Rational::new(-5, 2));
+    }
+}
@@ -0,0 +1,246 @@
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display, Formatter};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use super::gcd::Gcd;
+use super::traits::{checked::Checked, one::One, signed::Signed, zero::Zero};
+
+/// The integer operations a rational number needs from its numerator and denominator
+pub trait Integer: Clone + PartialOrd + Zero + One + Gcd + Neg<Output = Self> + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> {}
+
+impl<T> Integer for T
+where
+    T: Clone + PartialOrd + Zero + One + Gcd + Neg<Output = T> + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T> {}
+
+/// An exact fraction, always in lowest terms with a positive denominator
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Rational<T> {
+    numerator: T,
+    denominator: T,
+}
+
+impl<T: Integer> Rational<T> {
+    /// Panics if the denominator is zero
+    pub fn new(numerator: T, denominator: T) -> Rational<T> {
+        assert!(!denominator.is_zero(), "Rational with zero denominator");
+
+        let gcd = common_divisor(&numerator, &denominator);
+        let (numerator, denominator) = (numerator / gcd.clone(), denominator / gcd);
+
+        if denominator < T::zero() {
+            Rational { numerator: -numerator, denominator: -denominator }
+        } else {
+            Rational { numerator, denominator }
+        }
+    }
+
+    pub fn from_integer(value: T) -> Rational<T> {
+        Rational { numerator: value, denominator: T::one() }
+    }
+
+    pub fn numerator(&self) -> &T {
+        &self.numerator
+    }
+
+    pub fn denominator(&self) -> &T {
+        &self.denominator
+    }
+
+    /// The value as an integer, if it is one
+    pub fn to_integer(&self) -> Option<T> {
+        if self.denominator.is_one() { Some(self.numerator.clone()) } else { None }
+    }
+}
+
+/// A divisor of both numbers that is never zero, as long as one of them isn't
+fn common_divisor<T: Integer>(lhs: &T, rhs: &T) -> T {
+    let gcd = lhs.clone().gcd(rhs.clone()).gcd;
+    if gcd.is_zero() { T::one() } else { gcd }
+}
+
+impl<T: Integer> Zero for Rational<T> {
+    fn zero() -> Self {
+        Rational::from_integer(T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.numerator.is_zero()
+    }
+}
+
+impl<T: Integer> One for Rational<T> {
+    fn one() -> Self {
+        Rational::from_integer(T::one())
+    }
+
+    fn is_one(&self) -> bool {
+        self.numerator.is_one() && self.denominator.is_one()
+    }
+}
+
+impl<T: Integer> Neg for Rational<T> {
+    type Output = Rational<T>;
+
+    fn neg(self) -> Self::Output {
+        Rational { numerator: -self.numerator, denominator: self.denominator }
+    }
+}
+
+impl<T: Integer> Signed for Rational<T> {
+    fn abs(&self) -> Self {
+        if self.is_negative() { -self.clone() } else { self.clone() }
+    }
+
+    fn is_negative(&self) -> bool {
+        self.numerator < T::zero()
+    }
+}
+
+// The operations divide out common factors before multiplying, to keep intermediate values small
+
+impl<T: Integer> Add for Rational<T> {
+    type Output = Rational<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let gcd = common_divisor(&self.denominator, &rhs.denominator);
+        let lhs_factor = rhs.denominator.clone() / gcd.clone();
+        let rhs_factor = self.denominator.clone() / gcd;
+
+        Rational::new(self.numerator * lhs_factor.clone() + rhs.numerator * rhs_factor, self.denominator * lhs_factor)
+    }
+}
+
+impl<T: Integer> Sub for Rational<T> {
+    type Output = Rational<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + (-rhs)
+    }
+}
+
+impl<T: Integer> Mul for Rational<T> {
+    type Output = Rational<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let lhs_gcd = common_divisor(&self.numerator, &rhs.denominator);
+        let rhs_gcd = common_divisor(&rhs.numerator, &self.denominator);
+
+        Rational::new(
+            (self.numerator / lhs_gcd.clone()) * (rhs.numerator / rhs_gcd.clone()),
+            (self.denominator / rhs_gcd) * (rhs.denominator / lhs_gcd),
+        )
+    }
+}
+
+impl<T: Integer> Div for Rational<T> {
+    type Output = Rational<T>;
+
+    /// Panics when dividing by zero
+    fn div(self, rhs: Self) -> Self::Output {
+        assert!(!rhs.is_zero(), "Division of rational by zero");
+        self * Rational::new(rhs.denominator, rhs.numerator)
+    }
+}
+
+/// Compares by cross-multiplying, which the positive denominators allow
+impl<T: Integer> PartialOrd for Rational<T> {
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        (self.numerator.clone() * rhs.denominator.clone()).partial_cmp(&(rhs.numerator.clone() * self.denominator.clone()))
+    }
+}
+
+impl<T: Integer + Ord> Ord for Rational<T> {
+    fn cmp(&self, rhs: &Self) -> Ordering {
+        (self.numerator.clone() * rhs.denominator.clone()).cmp(&(rhs.numerator.clone() * self.denominator.clone()))
+    }
+}
+
+impl<T: Integer + Checked> Checked for Rational<T> {
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        let gcd = common_divisor(&self.denominator, &rhs.denominator);
+        let lhs_factor = rhs.denominator.clone() / gcd.clone();
+        let rhs_factor = self.denominator.clone() / gcd;
+
+        let numerator = self.numerator.checked_mul(&lhs_factor)?.checked_add(&rhs.numerator.checked_mul(&rhs_factor)?)?;
+        Some(Rational::new(numerator, self.denominator.checked_mul(&lhs_factor)?))
+    }
+
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        self.checked_add(&-rhs.clone())
+    }
+
+    fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        let lhs_gcd = common_divisor(&self.numerator, &rhs.denominator);
+        let rhs_gcd = common_divisor(&rhs.numerator, &self.denominator);
+
+        Some(Rational::new(
+            (self.numerator.clone() / lhs_gcd.clone()).checked_mul(&(rhs.numerator.clone() / rhs_gcd.clone()))?,
+            (self.denominator.clone() / rhs_gcd).checked_mul(&(rhs.denominator.clone() / lhs_gcd))?,
+        ))
+    }
+
+    fn checked_div(&self, rhs: &Self) -> Option<Self> {
+        if rhs.is_zero() {
+            return None;
+        }
+        self.checked_mul(&Rational::new(rhs.denominator.clone(), rhs.numerator.clone()))
+    }
+}
+
+impl<T: Integer + Display> Display for Rational<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.denominator.is_one() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+impl<T: Integer + Display> Debug for Rational<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rational(numerator: i128, denominator: i128) -> Rational<i128> {
+        Rational::new(numerator, denominator)
+    }
+
+    #[test]
+    fn should_normalize() {
+        assert_eq!(rational(6, -4), rational(-3, 2));
+        assert_eq!(rational(0, -7), Rational::zero());
+        assert_eq!(rational(-6, -3).to_integer(), Some(2));
+        assert_eq!(rational(1, 2).to_integer(), None);
+        assert_eq!(rational(-3, 6).to_string(), "-1/2");
+    }
+
+    #[test]
+    fn should_do_arithmetic() {
+        assert_eq!(rational(1, 6) + rational(1, 3), rational(1, 2));
+        assert_eq!(rational(1, 6) - rational(1, 3), rational(-1, 6));
+        assert_eq!(rational(4, 9) * rational(-3, 8), rational(-1, 6));
+        assert_eq!(rational(4, 9) / rational(-2, 3), rational(-2, 3));
+    }
+
+    #[test]
+    fn should_compare() {
+        assert!(rational(1, 3) < rational(1, 2));
+        assert!(rational(-1, 2) < rational(-1, 3));
+        assert!(rational(3, -4) < Rational::zero());
+        assert_eq!(rational(2, 4).cmp(&rational(-3, -6)), Ordering::Equal);
+    }
+
+    #[test]
+    fn should_check_arithmetic() {
+        assert_eq!(rational(1, 6).checked_add(&rational(1, 3)), Some(rational(1, 2)));
+        assert_eq!(rational(4, 9).checked_div(&rational(-2, 3)), Some(rational(-2, 3)));
+        assert_eq!(rational(4, 9).checked_div(&Rational::zero()), None);
+        assert_eq!(rational(i128::MAX, 2).checked_mul(&rational(4, 1)), None);
+        assert_eq!(rational(-3, 4).abs(), rational(3, 4));
+    }
+}
@@ -1,2 +1,5 @@
 pub mod zero;
-pub mod one;
\ No newline at end of file
+pub mod one;
+pub mod signed;
+pub mod checked;
+pub mod modular;
@@ -0,0 +1,33 @@
+/// Arithmetic that gives `None` instead of overflowing or dividing by zero
+pub trait Checked: Sized {
+    fn checked_add(&self, rhs: &Self) -> Option<Self>;
+    fn checked_sub(&self, rhs: &Self) -> Option<Self>;
+    fn checked_mul(&self, rhs: &Self) -> Option<Self>;
+    fn checked_div(&self, rhs: &Self) -> Option<Self>;
+}
+
+macro_rules! impl_checked {
+    ($($type_name:ty),*) => {
+        $(
+            impl Checked for $type_name {
+                fn checked_add(&self, rhs: &Self) -> Option<Self> {
+                    <$type_name>::checked_add(*self, *rhs)
+                }
+
+                fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+                    <$type_name>::checked_sub(*self, *rhs)
+                }
+
+                fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+                    <$type_name>::checked_mul(*self, *rhs)
+                }
+
+                fn checked_div(&self, rhs: &Self) -> Option<Self> {
+                    <$type_name>::checked_div(*self, *rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_checked!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
@@ -0,0 +1,67 @@
+use std::ops::{Add, Div, Rem, Sub};
+use super::super::gcd::{Gcd, GcdResult};
+use super::{one::One, zero::Zero};
+
+/// Arithmetic modulo a positive number
+pub trait Modular: Sized {
+    /// The remainder in `0..modulus`, also for negative numbers
+    fn modulo(&self, modulus: &Self) -> Self;
+    /// The product modulo `modulus`, without overflowing for any modulus that fits
+    fn mod_mul(&self, rhs: &Self, modulus: &Self) -> Self;
+    /// The number to the power `exponent` modulo `modulus`, without overflowing for any modulus that fits
+    fn mod_pow(&self, exponent: u64, modulus: &Self) -> Self;
+    /// The number in `0..modulus` that gives 1 modulo `modulus` when multiplied by this one, if they are coprime
+    fn mod_inverse(&self, modulus: &Self) -> Option<Self>;
+}
+
+impl<T> Modular for T
+where
+    T: Clone + PartialOrd + Zero + One + Gcd + Add<Output = T> + Sub<Output = T> + Div<Output = T> + Rem<Output = T>
+{
+    fn modulo(&self, modulus: &T) -> T {
+        let remainder = self.clone() % modulus.clone();
+        if remainder < T::zero() { remainder + modulus.clone() } else { remainder }
+    }
+
+    fn mod_mul(&self, rhs: &T, modulus: &T) -> T {
+        // Doubling and adding, so no intermediate value gets above the modulus
+        let two = T::one() + T::one();
+        let mut product = T::zero();
+        let mut addend = self.modulo(modulus);
+        let mut factor = rhs.modulo(modulus);
+        while !factor.is_zero() {
+            if !(factor.clone() % two.clone()).is_zero() {
+                product = add_modulo(product, addend.clone(), modulus);
+            }
+            addend = add_modulo(addend.clone(), addend, modulus);
+            factor = factor / two.clone();
+        }
+
+        product
+    }
+
+    fn mod_pow(&self, mut exponent: u64, modulus: &T) -> T {
+        let mut power = T::one().modulo(modulus);
+        let mut base = self.modulo(modulus);
+        while exponent > 0 {
+            if exponent % 2 == 1 {
+                power = power.mod_mul(&base, modulus);
+            }
+            base = base.mod_mul(&base, modulus);
+            exponent /= 2;
+        }
+
+        power
+    }
+
+    fn mod_inverse(&self, modulus: &T) -> Option<T> {
+        let GcdResult { lhs_inverse, gcd, .. } = self.modulo(modulus).gcd(modulus.clone());
+        if gcd.is_one() { Some(lhs_inverse.modulo(modulus)) } else { None }
+    }
+}
+
+/// The sum of two remainders modulo `modulus`, without going above the modulus
+fn add_modulo<T: PartialOrd + Add<Output = T> + Sub<Output = T> + Clone>(lhs: T, rhs: T, modulus: &T) -> T {
+    let gap = modulus.clone() - rhs.clone();
+    if lhs >= gap { lhs - gap } else { lhs + rhs }
+}
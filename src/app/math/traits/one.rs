@@ -3,22 +3,20 @@ pub trait One {
     fn is_one(&self) -> bool;
 }
 
-impl One for usize {
-    fn one() -> Self {
-        1
-    }
+macro_rules! impl_one {
+    ($($type_name:ty),*) => {
+        $(
+            impl One for $type_name {
+                fn one() -> Self {
+                    1
+                }
 
-    fn is_one(&self) -> bool {
-        *self == 1
-    }
+                fn is_one(&self) -> bool {
+                    *self == 1
+                }
+            }
+        )*
+    };
 }
 
-impl One for i128 {
-    fn one() -> Self {
-        1
-    }
-
-    fn is_one(&self) -> bool {
-        *self == 1
-    }
-}
+impl_one!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
@@ -0,0 +1,27 @@
+use std::ops::Neg;
+use super::zero::Zero;
+
+/// Numbers that can be negative
+pub trait Signed: Zero + Neg<Output = Self> {
+    fn abs(&self) -> Self;
+    fn is_negative(&self) -> bool;
+}
+
+macro_rules! impl_signed {
+    ($($type_name:ty),*) => {
+        $(
+            impl Signed for $type_name {
+                /// Panics or wraps around on the minimum value, like the primitive `abs`
+                fn abs(&self) -> Self {
+                    <$type_name>::abs(*self)
+                }
+
+                fn is_negative(&self) -> bool {
+                    *self < 0
+                }
+            }
+        )*
+    };
+}
+
+impl_signed!(i8, i16, i32, i64, i128, isize);
@@ -3,22 +3,20 @@ pub trait Zero {
     fn is_zero(&self) -> bool;
 }
 
-impl Zero for usize {
-    fn zero() -> Self {
-        0
-    }
+macro_rules! impl_zero {
+    ($($type_name:ty),*) => {
+        $(
+            impl Zero for $type_name {
+                fn zero() -> Self {
+                    0
+                }
 
-    fn is_zero(&self) -> bool {
-        *self == 0
-    }
+                fn is_zero(&self) -> bool {
+                    *self == 0
+                }
+            }
+        )*
+    };
 }
 
-impl Zero for i128 {
-    fn zero() -> Self {
-        0
-    }
-
-    fn is_zero(&self) -> bool {
-        *self == 0
-    }
-}
+impl_zero!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
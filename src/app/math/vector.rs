@@ -0,0 +1,142 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// A point or direction in the plane
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Vector2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// A point or direction in space
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Vector3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Vector2<T> {
+    pub const fn new(x: T, y: T) -> Vector2<T> {
+        Vector2 { x, y }
+    }
+}
+
+impl<T> Vector3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Vector3<T> {
+        Vector3 { x, y, z }
+    }
+
+    /// The projection onto the plane of the x and y axes
+    pub fn xy(self) -> Vector2<T> {
+        Vector2 { x: self.x, y: self.y }
+    }
+}
+
+impl<T: Clone + Sub<Output = T> + Mul<Output = T>> Vector2<T> {
+    /// The z coordinate of the cross product in space, positive when `rhs` points counterclockwise from `self`
+    pub fn cross(&self, rhs: &Vector2<T>) -> T {
+        self.x.clone() * rhs.y.clone() - self.y.clone() * rhs.x.clone()
+    }
+}
+
+impl<T: Clone + Sub<Output = T> + Mul<Output = T>> Vector3<T> {
+    pub fn cross(&self, rhs: &Vector3<T>) -> Vector3<T> {
+        Vector3 {
+            x: self.y.clone() * rhs.z.clone() - self.z.clone() * rhs.y.clone(),
+            y: self.z.clone() * rhs.x.clone() - self.x.clone() * rhs.z.clone(),
+            z: self.x.clone() * rhs.y.clone() - self.y.clone() * rhs.x.clone(),
+        }
+    }
+}
+
+macro_rules! impl_vector {
+    ($vector:ident, $len:literal, $($axis:ident),*) => {
+        impl<T> $vector<T> {
+            pub fn map<U>(self, f: impl Fn(T) -> U) -> $vector<U> {
+                $vector { $($axis: f(self.$axis)),* }
+            }
+        }
+
+        impl<T: Clone + Add<Output = T> + Mul<Output = T>> $vector<T> {
+            pub fn dot(&self, rhs: &$vector<T>) -> T {
+                let products = [$(self.$axis.clone() * rhs.$axis.clone()),*];
+                let [first, rest @ ..] = products;
+                rest.into_iter().fold(first, |sum, product| sum + product)
+            }
+        }
+
+        impl<T> From<[T; $len]> for $vector<T> {
+            fn from(value: [T; $len]) -> Self {
+                let [$($axis),*] = value;
+                $vector { $($axis),* }
+            }
+        }
+
+        impl<T: Add<Output = T>> Add for $vector<T> {
+            type Output = $vector<T>;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                $vector { $($axis: self.$axis + rhs.$axis),* }
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $vector<T> {
+            type Output = $vector<T>;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                $vector { $($axis: self.$axis - rhs.$axis),* }
+            }
+        }
+
+        impl<T: Neg<Output = T>> Neg for $vector<T> {
+            type Output = $vector<T>;
+
+            fn neg(self) -> Self::Output {
+                $vector { $($axis: -self.$axis),* }
+            }
+        }
+
+        /// Scaling by a number
+        impl<T: Clone + Mul<Output = T>> Mul<T> for $vector<T> {
+            type Output = $vector<T>;
+
+            fn mul(self, rhs: T) -> Self::Output {
+                $vector { $($axis: self.$axis * rhs.clone()),* }
+            }
+        }
+    };
+}
+
+impl_vector!(Vector2, 2, x, y);
+impl_vector!(Vector3, 3, x, y, z);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_do_arithmetic() {
+        let lhs = Vector3::new(1i64, -2, 3);
+        let rhs = Vector3::new(4i64, 5, -6);
+
+        assert_eq!(lhs + rhs, Vector3::new(5, 3, -3));
+        assert_eq!(lhs - rhs, Vector3::new(-3, -7, 9));
+        assert_eq!(-lhs * 2, Vector3::new(-2, 4, -6));
+        assert_eq!(lhs.dot(&rhs), -24);
+        assert_eq!(Vector3::from([1, 2, 3]).xy(), Vector2::new(1, 2));
+    }
+
+    #[test]
+    fn should_take_cross_products() {
+        let x = Vector3::new(1i64, 0, 0);
+        let y = Vector3::new(0i64, 1, 0);
+        assert_eq!(x.cross(&y), Vector3::new(0, 0, 1));
+        assert_eq!(y.cross(&x), Vector3::new(0, 0, -1));
+
+        let lhs = Vector3::new(1i64, -2, 3);
+        let rhs = Vector3::new(4i64, 5, -6);
+        let cross = lhs.cross(&rhs);
+        assert_eq!((cross.dot(&lhs), cross.dot(&rhs)), (0, 0));
+        assert_eq!(lhs.xy().cross(&rhs.xy()), cross.z);
+    }
+}
@@ -1,34 +1,14 @@
-mod haunted_wasteland;
-mod pipe_maze;
-mod cosmic_expansion;
-mod hot_springs;
-mod point_of_incidence;
-mod parabolic_reflector_dish;
-mod lens_library;
-mod the_floor_will_be_lava;
-mod clumsy_crucible;
-mod lavaduct_lagoon;
-mod aplenty;
-mod pulse_propagation;
-mod step_counter;
-mod sand_slabs;
+pub mod year2023;
+#[cfg(feature = "ssr")]
+mod generation;
+mod graph_export;
+mod plugin;
 mod timing;
-mod long_walk;
-mod mirage_maintenance;
-mod camel_cards;
-mod wait_for_it;
-mod if_you_give_seed_fertilizer;
-mod scratchcards;
-mod gear_ratios;
-mod cube_conundrum;
-mod trebuchet;
-mod never_tell_me_the_odds;
-mod snowverload;
 
 use leptos::*;
 use leptos_router::*;
 use serde::{Deserialize, Serialize};
-use self::timing::TimedSolutionResponse;
+use self::{graph_export::GraphExportForm, plugin::PluginSolutionResponse, timing::TimedSolutionResponse};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 enum ProblemPart {
@@ -36,16 +16,96 @@ enum ProblemPart {
     Part2
 }
 
+/// A single problem as listed in the registry
+#[derive(Debug, Clone, Copy)]
+pub struct ProblemInfo {
+    pub slug: &'static str,
+    pub title: &'static str,
+    /// The path the `Solve` server function of the problem is served at, like `/api/solve_with_timing123`
+    pub solve_path: fn() -> String,
+    /// The path the `Generate` server function of the problem is served at
+    pub generate_path: fn() -> String,
+    /// The path the `ExportGraph` server function of the problem is served at, if its input can be exported as a graph
+    pub export_graph_path: Option<fn() -> String>,
+    /// Generates a random input for the problem from a seed and a size
+    #[cfg(feature = "ssr")]
+    pub generate: fn(u64, usize) -> Result<String, String>,
+}
+
+/// All problems of a single year, in the order they are displayed
+#[derive(Debug, Clone, Copy)]
+pub struct ProblemYear {
+    pub year: u16,
+    pub problems: &'static [ProblemInfo],
+    /// The routes of the problem pages of the year, nested in the page layout
+    pub routes: fn() -> View,
+}
+
+/// Every year that has problems, in the order they are displayed
+pub const YEARS: &[ProblemYear] = &[year2023::YEAR];
+
+/// The path a server function is served at, as posted to by an `ActionForm`
+fn server_fn_path<T: ServerFn>() -> String {
+    let prefix = T::prefix();
+    if prefix.is_empty() {
+        T::url().to_string()
+    } else {
+        format!("{}/{}", prefix, T::url())
+    }
+}
+
+/// Defines the registry entry and the routes for the problems of a single year.
+///
+/// Expects to be invoked in a module that declares every listed problem module; the routes are
+/// served under `/{year}/problems/{module}`. Problems listed `with graph` also get a form to
+/// export their input as a graph.
 macro_rules! problem_routes_def {
-    ($( $title:literal at $module:ident ),*) => {
+    (@export_graph_path $module:ident) => { None };
+    (@export_graph_path $module:ident graph) => { Some(server_fn_path::<$module::ExportGraph>) };
+    (@graph_export_form $module:ident $input:ident) => { () };
+    (@graph_export_form $module:ident $input:ident graph) => {
+        view! { <GraphExportForm action={ create_server_action::<$module::ExportGraph>() } input=$input/> }
+    };
+    ($year:literal; $( $title:literal at $module:ident $(with $graph:ident)? ),*) => {
+        use leptos::*;
+        use leptos_meta::*;
+        use leptos_router::*;
+        use super::{server_fn_path, GraphExportForm, NoProblem, ProblemInfo, ProblemYear, TimedProblemForm};
+
+        pub const YEAR: ProblemYear = ProblemYear {
+            year: $year,
+            problems: &[
+                $(
+                    ProblemInfo {
+                        slug: stringify!($module),
+                        title: $title,
+                        solve_path: server_fn_path::<$module::Solve>,
+                        generate_path: server_fn_path::<$module::Generate>,
+                        export_graph_path: problem_routes_def!(@export_graph_path $module $($graph)?),
+                        #[cfg(feature = "ssr")]
+                        generate: $module::generate,
+                    },
+                )*
+            ],
+            routes: || Routes().into_view(),
+        };
+
         #[component(transparent)]
         pub fn Routes() -> impl IntoView {
             view! {
-                <Route path="problems" view=Problems>
+                <Route path=concat!(stringify!($year), "/problems") view=Problems>
                     $(
                         <Route path=stringify!($module)
-                            view=|| view! {
-                                <TimedProblemForm action={ create_server_action::<$module::Solve>() }>$title</TimedProblemForm>
+                            view=|| {
+                                let input = create_rw_signal(String::new());
+                                view! {
+                                    <TimedProblemForm
+                                        action={ create_server_action::<$module::Solve>() }
+                                        generate_action={ create_server_action::<$module::Generate>() }
+                                        input
+                                    >$title</TimedProblemForm>
+                                    { problem_routes_def!(@graph_export_form $module input $($graph)?) }
+                                }
                             }
                         />
                     )*
@@ -57,6 +117,7 @@ macro_rules! problem_routes_def {
         #[component]
         pub fn Problems() -> impl IntoView {
             view! {
+                <Title text=concat!("Advent of Code ", stringify!($year))/>
                 <nav>
                     <ul>
                         $(
@@ -70,33 +131,7 @@ macro_rules! problem_routes_def {
     };
 }
 
-problem_routes_def! {
-    "Day 1: Trebuchet?!" at trebuchet,
-    "Day 2: Cube Conundrum" at cube_conundrum,
-    "Day 3: Gear Ratios" at gear_ratios,
-    "Day 4: Scratchcards" at scratchcards,
-    "Day 5: If You Give A Seed A Fertilizer" at if_you_give_seed_fertilizer,
-    "Day 6: Wait For It" at wait_for_it,
-    "Day 7: Camel Cards" at camel_cards,
-    "Day 8: Haunted Wasteland" at haunted_wasteland,
-    "Day 9: Mirage Maintenance" at mirage_maintenance,
-    "Day 10: Pipe Maze" at pipe_maze,
-    "Day 11: Cosmic Expansion" at cosmic_expansion,
-    "Day 12: Hot Springs" at hot_springs,
-    "Day 13: Point of Incidence" at point_of_incidence,
-    "Day 14: Parabolic Reflector Dish" at parabolic_reflector_dish,
-    "Day 15: Lens Library" at lens_library,
-    "Day 16: The Floor Will Be Lava" at the_floor_will_be_lava,
-    "Day 17: Clumsy Crucible" at clumsy_crucible,
-    "Day 18: Lavaduct Lagoon" at lavaduct_lagoon,
-    "Day 19: Aplenty" at aplenty,
-    "Day 20: Pulse Propagation" at pulse_propagation,
-    "Day 21: Step Counter" at step_counter,
-    "Day 22: Sand Slabs" at sand_slabs,
-    "Day 23: A Long Walk" at long_walk,
-    "Day 24: Never Tell Me The Odds" at never_tell_me_the_odds,
-    "Day 25: Snowverload" at snowverload
-}
+pub(crate) use problem_routes_def;
 
 #[component]
 fn NoProblem() -> impl IntoView {
@@ -106,14 +141,41 @@ fn NoProblem() -> impl IntoView {
 }
 
 #[component]
-fn TimedProblemForm<T>(action: Action<T, Result<TimedSolutionResponse, ServerFnError>>, children: Children) -> impl IntoView
+fn TimedProblemForm<T, G>(
+    action: Action<T, Result<TimedSolutionResponse, ServerFnError>>,
+    generate_action: Action<G, Result<String, ServerFnError>>,
+    /// The problem input, shared with the other forms on the page
+    input: RwSignal<String>,
+    children: Children
+) -> impl IntoView
 where
-    T: Clone + ServerFn
+    T: Clone + ServerFn,
+    G: Clone + ServerFn
 {
     let value = action.value();
+    let generated = generate_action.value();
+
+    create_effect(move |_| {
+        if let Some(Ok(generated)) = generated() {
+            input.set(generated);
+        }
+    });
 
     view! {
         <h2>{ children() }</h2>
+        <ActionForm action=generate_action>
+            <div>
+                <label for="generator-seed">Seed</label>
+                <input type="number" name="seed" id="generator-seed" min="0" value="1"/>
+                <label for="generator-size">Size</label>
+                <input type="number" name="size" id="generator-size" min="1" value="10"/>
+                <input type="submit" value="Generate input"/>
+            </div>
+        </ActionForm>
+        { move || match generated() {
+            Some(Err(error)) => Some(view! { <p class="warning">"Could not generate input: " { error.to_string() }</p> }),
+            _ => None
+        } }
         <ActionForm action=action>
             <div>
                 <label for="problem-part">Problem part</label>
@@ -124,13 +186,50 @@ where
             </div>
             <div>
                 <label for="problem-input">Problem input</label>
-                <textarea name="input" id="problem-input" />
+                <textarea name="input" id="problem-input"
+                    prop:value=input
+                    on:input=move |event| input.set(event_target_value(&event))
+                />
+            </div>
+            <div>
+                <label for="problem-threads">Threads (0 for one per core)</label>
+                <input type="number" name="threads" id="problem-threads" min="0" value="0" required/>
             </div>
             <input type="submit" value="Solve!"/>
         </ActionForm>
         <Show when=move || if let Some(Ok(_)) = value() { true } else { false }>
             <p>Solution: <input readonly class="numeric" prop:value=move || if let Some(Ok(value)) = value() { value.solution } else { "".to_string() }/></p>
-            <p>"Computed in " { value().map(|value| value.map(|value| value.duration_description)) }</p>
+            <p>"Computed in " { value().map(|value| value.map(|value| {
+                format!("{}, with {} of busy time on {} threads", value.duration_description, value.busy_duration_description, value.thread_count)
+            })) }</p>
+            { move || value()
+                .and_then(|value| value.ok())
+                .and_then(|value| value.statistics)
+                .map(|statistics| view! { <p>{ statistics }</p> }) }
+            { move || value()
+                .and_then(|value| value.ok())
+                .and_then(|value| value.plugin)
+                .map(|plugin| view! { <PluginSolution plugin/> }) }
         </Show>
     }
 }
+
+#[component]
+fn PluginSolution(plugin: PluginSolutionResponse) -> impl IntoView {
+    let agrees = plugin.agrees;
+
+    match plugin.solution {
+        Ok(solution) => view! {
+            <p>"Plugin " <code>{ plugin.executable }</code> ": "
+                <input readonly class="numeric" class:disagreeing=!agrees prop:value=solution/>
+            </p>
+            <p>"Computed in " { plugin.duration_description }</p>
+            <Show when=move || !agrees>
+                <p class="warning">"The plugin disagrees with the built-in solution!"</p>
+            </Show>
+        }.into_view(),
+        Err(message) => view! {
+            <p class="warning">"Plugin " <code>{ plugin.executable }</code> " failed: " { message }</p>
+        }.into_view()
+    }
+}
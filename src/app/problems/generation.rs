@@ -0,0 +1,10 @@
+use crate::app::common::Random;
+
+/// Generates an input with the generator of a problem, refusing sizes it cannot generate in reasonable time
+pub fn generate_with_limit(inner: &dyn Fn(&mut Random, usize) -> String, max_size: usize, seed: u64, size: usize) -> Result<String, String> {
+    if size > max_size {
+        return Err(format!("size {} is larger than the maximum of {}", size, max_size));
+    }
+
+    Ok(inner(&mut Random::new(seed), size))
+}
@@ -0,0 +1,102 @@
+use leptos::*;
+use leptos_router::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GraphFormat {
+    Dot,
+    Json
+}
+
+impl GraphFormat {
+    fn file_name(&self) -> &'static str {
+        match self {
+            GraphFormat::Dot => "graph.dot",
+            GraphFormat::Json => "graph.json",
+        }
+    }
+
+    fn media_type(&self) -> &'static str {
+        match self {
+            GraphFormat::Dot => "text/vnd.graphviz",
+            GraphFormat::Json => "application/json",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GraphExportResponse {
+    pub format: GraphFormat,
+    pub content: String,
+}
+
+impl GraphExportResponse {
+    /// A `data:` URL with the exported graph, to be downloaded through a link
+    fn data_url(&self) -> String {
+        let mut url = format!("data:{};charset=utf-8,", self.format.media_type());
+        for byte in self.content.bytes() {
+            if byte.is_ascii_alphanumeric() || b"-_.~".contains(&byte) {
+                url.push(byte as char);
+            } else {
+                url.push_str(&format!("%{:02X}", byte));
+            }
+        }
+        url
+    }
+}
+
+#[macro_export]
+macro_rules! as_graph_export_server_fn {
+    ($exporter:item) => {
+        use crate::app::problems::graph_export::{GraphExportResponse, GraphFormat};
+        #[cfg(feature = "ssr")]
+        use crate::app::common::GraphExport;
+
+        #[cfg(feature = "ssr")]
+        $exporter
+
+        #[server(ExportGraph)]
+        pub async fn export_graph_with_format(input: String, format: GraphFormat) -> Result<GraphExportResponse, ServerFnError> {
+            let graph = graph(&input).map_err(|error| ServerFnError::ServerError(error.to_string()))?;
+            let content = match format {
+                GraphFormat::Dot => graph.to_dot(),
+                GraphFormat::Json => graph.to_json(),
+            };
+
+            Ok(GraphExportResponse { format, content })
+        }
+    }
+}
+
+/// Exports the graph of the input in the problem form, and offers it as a download
+#[component]
+pub fn GraphExportForm<E>(
+    action: Action<E, Result<GraphExportResponse, ServerFnError>>,
+    input: RwSignal<String>
+) -> impl IntoView
+where
+    E: Clone + ServerFn
+{
+    let value = action.value();
+
+    view! {
+        <ActionForm action=action>
+            <input type="hidden" name="input" prop:value=input/>
+            <div>
+                <label for="graph-format">Graph format</label>
+                <select name="format" id="graph-format">
+                    <option value="Dot">DOT</option>
+                    <option value="Json">JSON</option>
+                </select>
+                <input type="submit" value="Download graph"/>
+            </div>
+        </ActionForm>
+        { move || match value() {
+            Some(Ok(response)) => Some(view! {
+                <p><a href=response.data_url() download=response.format.file_name()>"Save " { response.format.file_name() }</a></p>
+            }.into_view()),
+            Some(Err(error)) => Some(view! { <p class="warning">"Could not export graph: " { error.to_string() }</p> }.into_view()),
+            None => None
+        } }
+    }
+}
@@ -1,15 +0,0 @@
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub enum Direction {
-    Left,
-    Right
-}
-
-impl From<char> for Direction {
-    fn from(value: char) -> Self {
-        match value {
-            'L' => Direction::Left,
-            'R' => Direction::Right,
-            _ => panic!("Invalid direction.")
-        }
-    }
-}
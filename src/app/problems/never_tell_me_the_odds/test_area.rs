@@ -1,9 +0,0 @@
-pub struct AreaRange {
-    pub start: i128,
-    pub end: i128,
-}
-
-pub struct FlatArea {
-    pub x: AreaRange,
-    pub y: AreaRange
-}
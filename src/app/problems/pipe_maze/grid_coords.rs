@@ -1,5 +0,0 @@
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct GridCoords {
-    pub x: usize,
-    pub y: usize,
-}
@@ -0,0 +1,184 @@
+#[cfg(feature = "ssr")]
+use std::io::{Read, Write};
+#[cfg(feature = "ssr")]
+use std::process::{Command, Stdio};
+#[cfg(feature = "ssr")]
+use std::sync::mpsc::{self, Receiver};
+#[cfg(feature = "ssr")]
+use std::time::{Duration, Instant};
+#[cfg(feature = "ssr")]
+use super::ProblemPart;
+
+use serde::{Deserialize, Serialize};
+
+/// The answer of an external solver, reported next to the built-in solution
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PluginSolutionResponse {
+    pub executable: String,
+    /// The trimmed output of the solver, or a description of why it failed
+    pub solution: Result<String, String>,
+    pub duration_description: String,
+    /// Whether the solver gave the same answer as the built-in solution
+    pub agrees: bool,
+}
+
+#[cfg(feature = "ssr")]
+const PLUGIN_VARIABLE_PREFIX: &str = "AOC_PLUGIN";
+
+/// How long an external solver may run before it is stopped
+#[cfg(feature = "ssr")]
+pub const PLUGIN_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Identifies the problem an external solver is configured for
+#[cfg(feature = "ssr")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PluginKey {
+    year: String,
+    slug: String,
+}
+
+#[cfg(feature = "ssr")]
+impl PluginKey {
+    /// Reads the key off the module path of a problem, like `aoc2023::app::problems::year2023::trebuchet`
+    pub fn from_module_path(module_path: &str) -> Option<PluginKey> {
+        let mut segments = module_path.rsplit("::");
+        let slug = segments.next()?;
+        let year = segments.next()?.strip_prefix("year")?;
+
+        Some(PluginKey {
+            year: year.to_string(),
+            slug: slug.to_string(),
+        })
+    }
+
+    /// The environment variable configuring the solver, like `AOC_PLUGIN_2023_TREBUCHET`
+    pub fn variable_name(&self) -> String {
+        format!("{}_{}_{}", PLUGIN_VARIABLE_PREFIX, self.year, self.slug.to_uppercase())
+    }
+
+    pub fn executable(&self) -> Option<String> {
+        std::env::var(self.variable_name()).ok()
+            .filter(|executable| !executable.trim().is_empty())
+    }
+}
+
+/// Reads the pipe to its end on a separate thread, so the solver never blocks on a full pipe
+#[cfg(feature = "ssr")]
+fn read_to_end_on_thread(mut pipe: impl Read + Send + 'static) -> Receiver<Vec<u8>> {
+    let (sender, receiver) = mpsc::channel();
+    std::thread::spawn(move || {
+        let mut bytes = Vec::new();
+        let _ = pipe.read_to_end(&mut bytes);
+        let _ = sender.send(bytes);
+    });
+
+    receiver
+}
+
+/// Runs an external solver, and stops it if it takes longer than `timeout`
+///
+/// The solver receives the input on stdin and the part (`1` or `2`) as its only argument,
+/// and is expected to print the answer on stdout.
+#[cfg(feature = "ssr")]
+pub fn run_plugin(executable: &str, part: ProblemPart, input: &str, timeout: Duration) -> Result<String, String> {
+    let deadline = Instant::now() + timeout;
+    let timed_out = || format!("{} timed out after {:?}", executable, timeout);
+
+    let part_argument = match part {
+        ProblemPart::Part1 => "1",
+        ProblemPart::Part2 => "2",
+    };
+
+    let mut child = Command::new(executable)
+        .arg(part_argument)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|error| format!("could not start {}: {}", executable, error))?;
+
+    // Write on a separate thread, so a solver filling up its stdout before reading all input cannot deadlock
+    let mut stdin = child.stdin.take().unwrap();
+    let input = input.to_string();
+    let writer = std::thread::spawn(move || stdin.write_all(input.as_bytes()));
+
+    let stdout = read_to_end_on_thread(child.stdout.take().unwrap());
+    let stderr = read_to_end_on_thread(child.stderr.take().unwrap());
+
+    let status = loop {
+        let status = child.try_wait()
+            .map_err(|error| format!("could not wait for {}: {}", executable, error))?;
+        match status {
+            Some(status) => break status,
+            None if Instant::now() >= deadline => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(timed_out());
+            },
+            None => std::thread::sleep(Duration::from_millis(10)),
+        }
+    };
+    // A solver is free to stop reading its input early, so a broken pipe is not an error
+    let _ = writer.join();
+
+    // Processes the solver started may still hold on to its output after it exits
+    let read_output = |output: Receiver<Vec<u8>>| {
+        output.recv_timeout(deadline.saturating_duration_since(Instant::now())).map_err(|_| timed_out())
+    };
+    let (stdout, stderr) = (read_output(stdout)?, read_output(stderr)?);
+
+    if status.success() {
+        Ok(String::from_utf8_lossy(&stdout).trim().to_string())
+    } else {
+        Err(format!("{} exited with {}: {}", executable, status, String::from_utf8_lossy(&stderr).trim()))
+    }
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_read_key_from_module_path() {
+        let key = PluginKey::from_module_path("aoc2023::app::problems::year2023::trebuchet").unwrap();
+
+        assert_eq!(key.variable_name(), "AOC_PLUGIN_2023_TREBUCHET");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn should_pass_input_and_part() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let path = std::env::temp_dir().join("aoc_plugin_should_pass_input_and_part.sh");
+        std::fs::write(&path, "#!/bin/sh\nread line\necho \"$1: $line\"\n").unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let result = run_plugin(path.to_str().unwrap(), ProblemPart::Part2, "input\n", PLUGIN_TIMEOUT);
+
+        assert_eq!(result, Ok("2: input".to_string()));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn should_stop_solver_after_timeout() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let path = std::env::temp_dir().join("aoc_plugin_should_stop_solver_after_timeout.sh");
+        std::fs::write(&path, "#!/bin/sh\nexec sleep 30\n").unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let instant = Instant::now();
+        let result = run_plugin(path.to_str().unwrap(), ProblemPart::Part1, "", Duration::from_millis(200));
+
+        assert!(result.as_ref().is_err_and(|error| error.ends_with("timed out after 200ms")), "{:?}", result);
+        assert!(instant.elapsed() < Duration::from_secs(10));
+    }
+
+    #[test]
+    fn should_report_missing_executable() {
+        let result = run_plugin("/nonexistent/plugin", ProblemPart::Part1, "", PLUGIN_TIMEOUT);
+
+        assert!(result.is_err());
+    }
+}
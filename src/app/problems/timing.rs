@@ -3,46 +3,137 @@ use std::fmt::Display;
 #[cfg(feature = "ssr")]
 use std::time::{Duration, Instant};
 #[cfg(feature = "ssr")]
-use super::ProblemPart;
+use super::{ProblemPart, plugin::{PluginKey, PLUGIN_TIMEOUT, run_plugin}};
 #[cfg(feature = "ssr")]
 use leptos::*;
+#[cfg(feature = "ssr")]
+use crate::app::{common::{thread_count, with_thread_count}, math::BigInt};
 
 use serde::{Deserialize, Serialize};
+use super::plugin::PluginSolutionResponse;
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TimedSolutionResponse {
     pub solution: String,
     pub duration_description: String,
+    /// The time the threads of the solution were busy, added up, which is more than the duration if parts of it ran
+    /// in parallel
+    pub busy_duration_description: String,
+    /// The number of threads the parallel parts of the solution could use, which is 1 without the `parallel` feature
+    pub thread_count: usize,
+    /// Notes on the work that went into the solution, if the problem keeps any
+    pub statistics: Option<String>,
+    /// The answer of the external solver configured for this problem, if any
+    pub plugin: Option<PluginSolutionResponse>,
+}
+
+/// The answer to a problem, with any notes on the work that went into it
+#[cfg(feature = "ssr")]
+pub trait Solution: Display {
+    fn statistics(&self) -> Option<String> {
+        None
+    }
 }
 
+#[cfg(feature = "ssr")]
+impl Solution for usize { }
+
+#[cfg(feature = "ssr")]
+impl Solution for isize { }
+
+#[cfg(feature = "ssr")]
+impl Solution for String { }
+
+#[cfg(feature = "ssr")]
+impl Solution for BigInt { }
+
+/// An answer shown as is, with statistics shown next to it
 #[cfg(feature = "ssr")]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-struct TimedSolution<T: Display> {
+pub struct WithStatistics<T: Display, S: Display> {
+    pub solution: T,
+    pub statistics: S,
+}
+
+#[cfg(feature = "ssr")]
+impl<T: Display, S: Display> Display for WithStatistics<T, S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.solution)
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl<T: Display, S: Display> Solution for WithStatistics<T, S> {
+    fn statistics(&self) -> Option<String> {
+        Some(self.statistics.to_string())
+    }
+}
+
+#[cfg(feature = "ssr")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct TimedSolution<T: Solution> {
     solution: T,
     duration: Duration,
+    busy_duration: Duration,
+    thread_count: usize,
 }
 
 #[cfg(feature = "ssr")]
-impl<T: Display> From<TimedSolution<T>> for TimedSolutionResponse {
+impl<T: Solution> From<TimedSolution<T>> for TimedSolutionResponse {
     fn from(value: TimedSolution<T>) -> Self {
         Self {
             solution: value.solution.to_string(),
             duration_description: get_description(value.duration),
+            busy_duration_description: get_description(value.busy_duration),
+            thread_count: value.thread_count,
+            statistics: value.solution.statistics(),
+            plugin: None,
         }
     }
 }
 
+/// Solves the problem with its parallel parts on `threads` threads, at most one per core and one per core if not
+/// given or zero, and runs the external solver configured for the problem at `module_path` if there is one
+///
+/// An input the problem cannot parse or solve is reported as a server error, with the line and column where parsing
+/// failed if it did.
 #[cfg(feature = "ssr")]
-pub fn with_timing<T: Display>(inner: &dyn Fn(ProblemPart, String) -> T, part: ProblemPart, input: String) -> Result<TimedSolutionResponse, ServerFnError> {
+pub fn with_timing<T: Solution, E: Display>(inner: &dyn Fn(ProblemPart, String) -> Result<T, E>, module_path: &str, part: ProblemPart, input: String, threads: Option<usize>) -> Result<TimedSolutionResponse, ServerFnError> {
+    let executable = PluginKey::from_module_path(module_path).and_then(|key| key.executable());
+    let plugin_input = executable.as_ref().map(|_| input.clone());
+    // The count comes from the request, so it must not start more threads than the cores can run
+    let core_count = std::thread::available_parallelism().map_or(1, |count| count.get());
+    let allowed_thread_count = threads
+        .filter(|count| *count > 0)
+        .map_or(core_count, |count| count.min(core_count));
+
     let instant = Instant::now();
-    let solution = inner(part, input);
+    let ((solution, thread_count), parallel_time) = with_thread_count(allowed_thread_count, || (inner(part, input), thread_count()));
     let duration = instant.elapsed();
     let timed_solution = TimedSolution {
-        solution,
-        duration
+        solution: solution.map_err(|error| ServerFnError::ServerError(error.to_string()))?,
+        duration,
+        // The current thread waits while the parallel parts run, and the threads of those parts are busy instead
+        busy_duration: duration.saturating_sub(parallel_time.wall) + parallel_time.busy,
+        thread_count,
     };
 
-    Ok(timed_solution.into())
+    let mut response: TimedSolutionResponse = timed_solution.into();
+
+    if let (Some(executable), Some(input)) = (executable, plugin_input) {
+        let instant = Instant::now();
+        let solution = run_plugin(&executable, part, &input, PLUGIN_TIMEOUT);
+        let duration = instant.elapsed();
+
+        response.plugin = Some(PluginSolutionResponse {
+            agrees: solution.as_ref().is_ok_and(|solution| *solution == response.solution),
+            executable,
+            solution,
+            duration_description: get_description(duration),
+        });
+    }
+
+    Ok(response)
 }
 
 #[cfg(feature = "ssr")]
@@ -75,14 +166,25 @@ macro_rules! as_server_fn_with_timing {
         use leptos::*;
         use crate::app::problems::{ProblemPart, timing::TimedSolutionResponse};
         #[cfg(feature = "ssr")]
-        use crate::app::problems::timing::with_timing;
+        use crate::app::problems::{generation::generate_with_limit, timing::with_timing};
 
         #[cfg(feature = "ssr")]
         $solver
 
         #[server(Solve)]
-        pub async fn solve_with_timing(part: ProblemPart, input: String) -> Result<TimedSolutionResponse, ServerFnError> {
-            with_timing(&solve, part, input)
+        pub async fn solve_with_timing(part: ProblemPart, input: String, threads: Option<usize>) -> Result<TimedSolutionResponse, ServerFnError> {
+            with_timing(&solve, module_path!(), part, input, threads)
+        }
+
+        /// Generates a random input of the given size, the same one for the same seed
+        #[cfg(feature = "ssr")]
+        pub fn generate(seed: u64, size: usize) -> Result<String, String> {
+            generate_with_limit(&generator::generate, generator::MAX_SIZE, seed, size)
+        }
+
+        #[server(Generate)]
+        pub async fn generate_with_seed(seed: u64, size: usize) -> Result<String, ServerFnError> {
+            generate(seed, size).map_err(ServerFnError::ServerError)
         }
     }
 }
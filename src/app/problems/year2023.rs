@@ -0,0 +1,56 @@
+mod haunted_wasteland;
+mod pipe_maze;
+mod cosmic_expansion;
+mod hot_springs;
+mod point_of_incidence;
+mod parabolic_reflector_dish;
+mod lens_library;
+mod the_floor_will_be_lava;
+mod clumsy_crucible;
+mod lavaduct_lagoon;
+mod aplenty;
+mod pulse_propagation;
+mod step_counter;
+mod sand_slabs;
+mod long_walk;
+mod mirage_maintenance;
+mod camel_cards;
+mod wait_for_it;
+mod if_you_give_seed_fertilizer;
+mod scratchcards;
+mod gear_ratios;
+mod cube_conundrum;
+mod trebuchet;
+mod never_tell_me_the_odds;
+mod snowverload;
+
+use super::problem_routes_def;
+
+problem_routes_def! {
+    2023;
+    "Day 1: Trebuchet?!" at trebuchet,
+    "Day 2: Cube Conundrum" at cube_conundrum,
+    "Day 3: Gear Ratios" at gear_ratios,
+    "Day 4: Scratchcards" at scratchcards,
+    "Day 5: If You Give A Seed A Fertilizer" at if_you_give_seed_fertilizer,
+    "Day 6: Wait For It" at wait_for_it,
+    "Day 7: Camel Cards" at camel_cards,
+    "Day 8: Haunted Wasteland" at haunted_wasteland with graph,
+    "Day 9: Mirage Maintenance" at mirage_maintenance,
+    "Day 10: Pipe Maze" at pipe_maze,
+    "Day 11: Cosmic Expansion" at cosmic_expansion,
+    "Day 12: Hot Springs" at hot_springs,
+    "Day 13: Point of Incidence" at point_of_incidence,
+    "Day 14: Parabolic Reflector Dish" at parabolic_reflector_dish,
+    "Day 15: Lens Library" at lens_library,
+    "Day 16: The Floor Will Be Lava" at the_floor_will_be_lava,
+    "Day 17: Clumsy Crucible" at clumsy_crucible,
+    "Day 18: Lavaduct Lagoon" at lavaduct_lagoon,
+    "Day 19: Aplenty" at aplenty with graph,
+    "Day 20: Pulse Propagation" at pulse_propagation with graph,
+    "Day 21: Step Counter" at step_counter,
+    "Day 22: Sand Slabs" at sand_slabs,
+    "Day 23: A Long Walk" at long_walk with graph,
+    "Day 24: Never Tell Me The Odds" at never_tell_me_the_odds,
+    "Day 25: Snowverload" at snowverload with graph
+}
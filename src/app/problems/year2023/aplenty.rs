@@ -6,27 +6,39 @@ mod influx;
 mod influx_range;
 #[cfg(feature = "ssr")]
 mod processor;
+#[cfg(feature = "ssr")]
+mod generator;
 
+#[cfg(feature = "ssr")]
+use crate::app::common::{ParseError, Parser};
 #[cfg(feature = "ssr")]
 use self::{influx::Influx, workflow::Workflow, processor::{Processor, ProcessorResult}};
 
-use crate::as_server_fn_with_timing;
+use crate::{as_graph_export_server_fn, as_server_fn_with_timing};
 
 as_server_fn_with_timing! {
-    fn solve(part: ProblemPart, input: String) -> usize {
-        let (workflow, influx) = input.split_once("\n\n").unwrap();
-        let workflow = Workflow::from_str(workflow);
-        let influx = Influx::from_str(influx);
+    fn solve(part: ProblemPart, input: String) -> Result<usize, ParseError> {
+        let (workflow, influx) = Parser::parse_all(&input, |parser| {
+            let workflow = Workflow::parse(parser)?;
+            parser.line_end()?;
+            Ok((workflow, Influx::parse(parser)?))
+        })?;
         let processor = Processor::from(workflow);
 
-        match part {
+        Ok(match part {
             ProblemPart::Part1 =>
                 influx.iter()
                     .filter(move |influx_item| processor.process(influx_item, "in") == ProcessorResult::Accept)
                     .map(|influx_item| influx_item.value())
                     .sum(),
             ProblemPart::Part2 =>
-                processor.process_range().into_iter().map(|r| r.size()).sum()
-        }
+                processor.process_range().into_iter().map(|r| r.volume()).sum()
+        })
+    }
+}
+
+as_graph_export_server_fn! {
+    fn graph(input: &str) -> Result<Workflow<'_>, ParseError> {
+        Workflow::parse(&mut Parser::new(input))
     }
 }
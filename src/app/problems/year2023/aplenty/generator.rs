@@ -0,0 +1,85 @@
+use std::collections::HashSet;
+use crate::app::common::Random;
+
+pub const MAX_SIZE: usize = 1000;
+
+const PROPERTIES: [char; 4] = ['x', 'm', 'a', 's'];
+
+/// Generates workflows nested `size` deep starting from `in`, followed by `5 * size` parts
+pub fn generate(random: &mut Random, size: usize) -> String {
+    let size = size.max(1);
+
+    let mut generator = WorkflowGenerator {
+        random,
+        used_names: HashSet::from(["in".to_string()]),
+        workflows: Vec::new(),
+        max_workflow_count: 4 * size + 10,
+    };
+    generator.generate_workflow("in".to_string(), size);
+
+    let mut workflows = generator.workflows;
+    random.shuffle(&mut workflows);
+
+    let parts = (0..5 * size)
+        .map(|_| {
+            let ratings = PROPERTIES.iter()
+                .map(|property| format!("{}={}", property, 1 + random.below(4000)))
+                .collect::<Vec<_>>()
+                .join(",");
+            format!("{{{}}}", ratings)
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!("{}\n\n{}", workflows.join("\n"), parts)
+}
+
+struct WorkflowGenerator<'a> {
+    random: &'a mut Random,
+    used_names: HashSet<String>,
+    workflows: Vec<String>,
+    max_workflow_count: usize,
+}
+
+impl<'a> WorkflowGenerator<'a> {
+    /// Generates a workflow of which exactly one rule continues until the given depth is reached
+    fn generate_workflow(&mut self, name: String, depth: usize) {
+        let rule_count = 1 + self.random.below(3);
+        let deepest_rule = self.random.below(rule_count + 1);
+
+        let mut rules: Vec<String> = Vec::with_capacity(rule_count + 1);
+        for index in 0..=rule_count {
+            let target = if depth > 1 && (index == deepest_rule || (self.random.chance(1, 4) && self.has_room())) {
+                let target = self.new_name();
+                self.generate_workflow(target.clone(), depth - 1);
+                target
+            } else {
+                self.random.choose(&["A", "R"]).to_string()
+            };
+
+            if index == rule_count {
+                rules.push(target);
+            } else {
+                let property = self.random.choose(&PROPERTIES);
+                let operator = self.random.choose(&['<', '>']);
+                rules.push(format!("{}{}{}:{}", property, operator, 1 + self.random.below(4000), target));
+            }
+        }
+
+        self.workflows.push(format!("{}{{{}}}", name, rules.join(",")));
+    }
+
+    fn has_room(&self) -> bool {
+        self.used_names.len() < self.max_workflow_count
+    }
+
+    fn new_name(&mut self) -> String {
+        loop {
+            let length = 2 + self.random.below(2);
+            let name = self.random.lowercase_word(length);
+            if self.used_names.insert(name.clone()) {
+                return name;
+            }
+        }
+    }
+}
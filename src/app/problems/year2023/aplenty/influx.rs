@@ -0,0 +1,52 @@
+use std::ops::Deref;
+use crate::app::common::{ParseError, Parser};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InfluxItem {
+    pub xtreme: usize,
+    pub musical: usize,
+    pub aerodynamic: usize,
+    pub shiny: usize
+}
+
+impl InfluxItem {
+    fn parse(parser: &mut Parser) -> Result<InfluxItem, ParseError> {
+        parser.literal("{x=")?;
+        let xtreme = parser.unsigned::<usize>()?;
+        parser.literal(",m=")?;
+        let musical = parser.unsigned::<usize>()?;
+        parser.literal(",a=")?;
+        let aerodynamic = parser.unsigned::<usize>()?;
+        parser.literal(",s=")?;
+        let shiny = parser.unsigned::<usize>()?;
+        parser.literal("}")?;
+
+        Ok(InfluxItem {
+            xtreme,
+            musical,
+            aerodynamic,
+            shiny
+        })
+    }
+
+    pub fn value(&self) -> usize {
+        self.xtreme + self.musical + self.aerodynamic + self.shiny
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Influx(Vec<InfluxItem>);
+
+impl Influx {
+    pub fn parse(parser: &mut Parser) -> Result<Influx, ParseError> {
+        Ok(Influx(parser.lines(InfluxItem::parse)?))
+    }
+}
+
+impl Deref for Influx {
+    type Target = Vec<InfluxItem>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
@@ -0,0 +1,9 @@
+use crate::app::common::{Interval, IntervalBox};
+
+/// The ratings of a part of the influx, along one axis for each property in the order x, m, a, s
+pub type InfluxRange = IntervalBox<usize, 4>;
+
+/// Every item, with ratings from 1 to 4000
+pub fn whole_influx_range() -> InfluxRange {
+    IntervalBox([Interval::new(1, 4001); 4])
+}
@@ -0,0 +1,171 @@
+use super::{influx::InfluxItem, influx_range::{whole_influx_range, InfluxRange}, workflow::{InstructionResult, SplitResult, Workflow}};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProcessorResult {
+    Accept,
+    Reject
+}
+
+#[derive(Debug)]
+pub struct Processor<'a> {
+    workflow: Workflow<'a>
+}
+
+impl<'a> Processor<'a> {
+    pub fn from(workflow: Workflow<'a>) -> Processor<'a> {
+        Processor {
+            workflow
+        }
+    }
+
+    pub fn process(&self, influx_item: &InfluxItem, name: &'a str) -> ProcessorResult {
+        let workflow_item = self.workflow.get(name).unwrap();
+        let result = workflow_item.instructions.iter()
+            .find(|instruction| instruction.condition.is_satisfied_by(influx_item))
+            .map_or(workflow_item.final_instruction, |instruction| instruction.then);
+
+        match result {
+            InstructionResult::Accept => ProcessorResult::Accept,
+            InstructionResult::Reject => ProcessorResult::Reject,
+            InstructionResult::Goto(name) => self.process(influx_item, name),
+        }
+    }
+
+    pub fn process_range(&self) -> Vec<InfluxRange> {
+        let initial_name: &'a str = "in";
+
+        let mut item_stack = vec![(initial_name, whole_influx_range())];
+        let mut result: Vec<InfluxRange> = Vec::new();
+
+        while let Some((name, influx_range)) = item_stack.pop() {
+            let mut influx_range = Some(influx_range);
+
+            let workflow_item = self.workflow.get(name).unwrap();
+
+            for instruction in workflow_item.instructions.iter() {
+                if let Some(range) = influx_range {
+                    match instruction.condition.split_range(&range) {
+                        SplitResult::Then(then) => {
+                            influx_range = None;
+                            match instruction.then {
+                                InstructionResult::Accept => result.push(then),
+                                InstructionResult::Reject => (),
+                                InstructionResult::Goto(name) => item_stack.push((name, then))
+                            };
+                        },
+                        SplitResult::Next(next) => {
+                            influx_range = Some(next);
+                        },
+                        SplitResult::Split { then, next } => {
+                            influx_range = Some(next);
+                            match instruction.then {
+                                InstructionResult::Accept => result.push(then),
+                                InstructionResult::Reject => (),
+                                InstructionResult::Goto(name) => item_stack.push((name, then))
+                            }
+                        }
+                    }
+                }
+            }
+
+            if let Some(range) = influx_range {
+                match workflow_item.final_instruction {
+                    InstructionResult::Accept => result.push(range),
+                    InstructionResult::Reject => (),
+                    InstructionResult::Goto(name) => item_stack.push((name, range))
+                };
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+    use crate::app::common::{check_property, Parser};
+    use super::super::generator;
+    use super::*;
+
+    fn conditions(workflow: &str) -> impl Iterator<Item = (char, char, usize)> + '_ {
+        workflow.lines()
+            .flat_map(|line| line.split(['{', ',', '}']))
+            .filter_map(|rule| rule.split_once(':'))
+            .map(|(condition, _)| {
+                let mut chars = condition.chars();
+                (chars.next().unwrap(), chars.next().unwrap(), condition[2..].parse::<usize>().unwrap())
+            })
+    }
+
+    /// Splits every rating at every threshold in the workflows, and processes one item for each resulting box
+    fn reference_accepted_count(workflow: &str) -> usize {
+        let starts = ['x', 'm', 'a', 's'].map(|property| {
+            let mut starts = BTreeSet::from([1usize, 4001]);
+            for (_, operator, value) in conditions(workflow).filter(|(rule_property, _, _)| *rule_property == property) {
+                starts.insert(if operator == '<' { value } else { value + 1 }.clamp(1, 4001));
+            }
+            starts.into_iter().collect::<Vec<_>>()
+        });
+        let cells = starts.clone().map(|starts| {
+            starts.windows(2).map(|window| (window[0], window[1] - window[0])).collect::<Vec<_>>()
+        });
+
+        let processor = Processor::from(Parser::parse_all(workflow, Workflow::parse).unwrap());
+        let mut count = 0usize;
+        for &(xtreme, xtreme_size) in cells[0].iter() {
+            for &(musical, musical_size) in cells[1].iter() {
+                for &(aerodynamic, aerodynamic_size) in cells[2].iter() {
+                    for &(shiny, shiny_size) in cells[3].iter() {
+                        let item = InfluxItem { xtreme, musical, aerodynamic, shiny };
+                        if processor.process(&item, "in") == ProcessorResult::Accept {
+                            count += xtreme_size * musical_size * aerodynamic_size * shiny_size;
+                        }
+                    }
+                }
+            }
+        }
+
+        count
+    }
+
+    /// The workflows with a single conditional rule left out
+    fn shrink(workflow: &str) -> Vec<String> {
+        let lines: Vec<&str> = workflow.lines().collect();
+
+        (0..lines.len())
+            .flat_map(|index| {
+                let (name, rules) = lines[index].strip_suffix('}').unwrap().split_once('{').unwrap();
+                let rules: Vec<&str> = rules.split(',').collect();
+                let lines = &lines;
+
+                (0..rules.len() - 1).map(move |removed| {
+                    let remaining: Vec<&str> = rules.iter().enumerate()
+                        .filter_map(|(rule_index, rule)| if rule_index == removed { None } else { Some(*rule) })
+                        .collect();
+                    let mut candidate = lines.clone();
+                    let line = format!("{}{{{}}}", name, remaining.join(","));
+                    candidate[index] = &line;
+                    candidate.join("\n")
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn should_match_reference_accepted_count() {
+        check_property(
+            100,
+            |random, size| {
+                let input = generator::generate(random, size / 2 + 1);
+                input.split_once("\n\n").unwrap().0.to_string()
+            },
+            |workflow| shrink(workflow),
+            |workflow| {
+                let actual: usize = Processor::from(Parser::parse_all(workflow, Workflow::parse).unwrap()).process_range().iter().map(|range| range.volume()).sum();
+                let expected = reference_accepted_count(workflow);
+                if actual == expected { Ok(()) } else { Err(format!("got {}, expected {}", actual, expected)) }
+            },
+        );
+    }
+}
@@ -0,0 +1,259 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::ops::Deref;
+use crate::app::common::{ExportEdge, ExportNode, GraphExport, ParseError, Parser};
+use super::{influx::InfluxItem, influx_range::InfluxRange};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SplitResult<T> {
+    Then(T),
+    Split { then: T, next: T },
+    Next(T)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InstructionProperty {
+    Xtreme,
+    Musical,
+    Aerodynamic,
+    Shiny
+}
+
+impl InstructionProperty {
+    /// The axis of the property in an `InfluxRange`
+    fn axis(&self) -> usize {
+        match self {
+            InstructionProperty::Xtreme => 0,
+            InstructionProperty::Musical => 1,
+            InstructionProperty::Aerodynamic => 2,
+            InstructionProperty::Shiny => 3,
+        }
+    }
+
+    fn parse(parser: &mut Parser) -> Result<InstructionProperty, ParseError> {
+        parser.character("property", |c| match c {
+            'x' => Some(InstructionProperty::Xtreme),
+            'm' => Some(InstructionProperty::Musical),
+            'a' => Some(InstructionProperty::Aerodynamic),
+            's' => Some(InstructionProperty::Shiny),
+            _ => None
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InstructionOperator {
+    Less,
+    Greater
+}
+
+impl InstructionOperator {
+    fn parse(parser: &mut Parser) -> Result<InstructionOperator, ParseError> {
+        parser.character("operator", |c| match c {
+            '<' => Some(InstructionOperator::Less),
+            '>' => Some(InstructionOperator::Greater),
+            _ => None
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InstructionCondition {
+    pub property: InstructionProperty,
+    pub operator: InstructionOperator,
+    pub value: usize
+}
+
+impl Display for InstructionCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let property = match self.property {
+            InstructionProperty::Xtreme => 'x',
+            InstructionProperty::Musical => 'm',
+            InstructionProperty::Aerodynamic => 'a',
+            InstructionProperty::Shiny => 's',
+        };
+        let operator = match self.operator {
+            InstructionOperator::Less => '<',
+            InstructionOperator::Greater => '>',
+        };
+        write!(f, "{}{}{}", property, operator, self.value)
+    }
+}
+
+impl InstructionCondition {
+    fn parse(parser: &mut Parser) -> Result<InstructionCondition, ParseError> {
+        let property = InstructionProperty::parse(parser)?;
+        let operator = InstructionOperator::parse(parser)?;
+        let value = parser.unsigned::<usize>()?;
+
+        Ok(InstructionCondition {
+            property,
+            operator,
+            value
+        })
+    }
+
+    pub fn is_satisfied_by(&self, influx_item: &InfluxItem) -> bool {
+        match self.property {
+            InstructionProperty::Xtreme =>
+                match self.operator {
+                    InstructionOperator::Less => influx_item.xtreme < self.value,
+                    InstructionOperator::Greater => influx_item.xtreme > self.value,
+                },
+            InstructionProperty::Musical =>
+                match self.operator {
+                    InstructionOperator::Less => influx_item.musical < self.value,
+                    InstructionOperator::Greater => influx_item.musical > self.value,
+                },
+            InstructionProperty::Aerodynamic =>
+                match self.operator {
+                    InstructionOperator::Less => influx_item.aerodynamic < self.value,
+                    InstructionOperator::Greater => influx_item.aerodynamic > self.value,
+                },
+            InstructionProperty::Shiny =>
+                match self.operator {
+                    InstructionOperator::Less => influx_item.shiny < self.value,
+                    InstructionOperator::Greater => influx_item.shiny > self.value,
+                },
+        }
+    }
+
+    pub fn split_range(&self, influx_range: &InfluxRange) -> SplitResult<InfluxRange> {
+        let (then, next) = match self.operator {
+            InstructionOperator::Less => influx_range.split_at(self.property.axis(), self.value),
+            InstructionOperator::Greater => {
+                let (next, then) = influx_range.split_at(self.property.axis(), self.value + 1);
+                (then, next)
+            },
+        };
+
+        if then.is_empty() {
+            SplitResult::Next(next)
+        } else if next.is_empty() {
+            SplitResult::Then(then)
+        } else {
+            SplitResult::Split { then, next }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InstructionResult<'a> {
+    Accept,
+    Reject,
+    Goto(&'a str)
+}
+
+impl<'a> InstructionResult<'a> {
+    /// The name of the workflow the result leads to, with `A` and `R` for accepting and rejecting
+    fn target(&self) -> &'a str {
+        match self {
+            InstructionResult::Accept => "A",
+            InstructionResult::Reject => "R",
+            InstructionResult::Goto(name) => name,
+        }
+    }
+
+    fn parse(parser: &mut Parser<'a>) -> Result<InstructionResult<'a>, ParseError> {
+        Ok(match parser.word()? {
+            "A" => InstructionResult::Accept,
+            "R" => InstructionResult::Reject,
+            name => InstructionResult::Goto(name)
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Instruction<'a> {
+    pub condition: InstructionCondition,
+    pub then: InstructionResult<'a>
+}
+
+impl<'a> Instruction<'a> {
+    fn parse(parser: &mut Parser<'a>) -> Result<Instruction<'a>, ParseError> {
+        let condition = InstructionCondition::parse(parser)?;
+        parser.literal(":")?;
+        let then = InstructionResult::parse(parser)?;
+
+        Ok(Instruction {
+            condition,
+            then
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WorkflowItem<'a> {
+    pub instructions: Vec<Instruction<'a>>,
+    pub final_instruction: InstructionResult<'a>,
+}
+
+impl<'a> WorkflowItem<'a> {
+    /// The rules between braces, where only the last one has no condition
+    fn parse(parser: &mut Parser<'a>) -> Result<WorkflowItem<'a>, ParseError> {
+        parser.literal("{")?;
+        let mut instructions: Vec<Instruction<'a>> = Vec::new();
+        loop {
+            let before_instruction = *parser;
+            match Instruction::parse(parser) {
+                Ok(instruction) => {
+                    instructions.push(instruction);
+                    parser.literal(",")?;
+                },
+                Err(_) => {
+                    *parser = before_instruction;
+                    break;
+                }
+            }
+        }
+        let final_instruction = InstructionResult::parse(parser)?;
+        parser.literal("}")?;
+
+        Ok(WorkflowItem {
+            instructions,
+            final_instruction
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Workflow<'a>(HashMap<&'a str, WorkflowItem<'a>>);
+
+impl<'a> Workflow<'a> {
+    /// A named workflow on each line, up to a blank line
+    pub fn parse(parser: &mut Parser<'a>) -> Result<Workflow<'a>, ParseError> {
+        let items = parser.lines(|parser| Ok((parser.word()?, WorkflowItem::parse(parser)?)))?;
+        Ok(Workflow(items.into_iter().collect()))
+    }
+}
+
+impl<'a> Deref for Workflow<'a> {
+    type Target = HashMap<&'a str, WorkflowItem<'a>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<'a> GraphExport for Workflow<'a> {
+    /// Workflows are drawn as boxes, next to an accepting and a rejecting node
+    fn nodes(&self) -> Vec<ExportNode> {
+        self.0.keys()
+            .map(|name| ExportNode { id: name.to_string(), shape: Some("box") })
+            .chain([
+                ExportNode { id: "A".to_string(), shape: Some("doublecircle") },
+                ExportNode { id: "R".to_string(), shape: Some("circle") },
+            ])
+            .collect()
+    }
+
+    /// Edges are labelled with the condition that leads to them, the final instruction has no label
+    fn edges(&self) -> Vec<ExportEdge> {
+        self.0.iter()
+            .flat_map(|(name, item)| item.instructions.iter()
+                .map(|instruction| (instruction.then, Some(instruction.condition.to_string())))
+                .chain([(item.final_instruction, None)])
+                .map(|(then, label)| ExportEdge { from: name.to_string(), to: then.target().to_string(), label }))
+            .collect()
+    }
+}
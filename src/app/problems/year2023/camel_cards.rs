@@ -0,0 +1,36 @@
+#[cfg(feature = "ssr")]
+mod hands;
+#[cfg(feature = "ssr")]
+mod generator;
+
+#[cfg(feature = "ssr")]
+use crate::app::common::{ParseError, Parser};
+#[cfg(feature = "ssr")]
+use self::hands::{parse_bid, CamelBid};
+
+use crate::as_server_fn_with_timing;
+
+#[cfg(feature = "ssr")]
+fn solve_instance<T>(input: &str, bid: fn([T; 5], usize) -> CamelBid<T>) -> Result<usize, ParseError>
+where
+    T: Ord + TryFrom<char>
+{
+    let mut bids = Parser::parse_all(input, |parser| parser.lines(|parser| {
+        let (hand_cards, bid_size) = parse_bid(parser)?;
+        Ok(bid(hand_cards, bid_size))
+    }))?;
+    bids.sort_by(|x, y| x.hand.cmp(&y.hand));
+
+    Ok(bids.into_iter().enumerate()
+        .map(|(index, bid)| (index + 1) * bid.bid_size)
+        .sum())
+}
+
+as_server_fn_with_timing! {
+    fn solve(part: ProblemPart, input: String) -> Result<usize, ParseError> {
+        match part {
+            ProblemPart::Part1 => solve_instance(&input, CamelBid::without_jokers),
+            ProblemPart::Part2 => solve_instance(&input, CamelBid::with_jokers)
+        }
+    }
+}
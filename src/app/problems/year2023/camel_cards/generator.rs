@@ -0,0 +1,19 @@
+use crate::app::common::Random;
+
+pub const MAX_SIZE: usize = 100_000;
+
+const CARDS: [char; 13] = ['2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A'];
+
+/// Generates `size` hands with their bids
+pub fn generate(random: &mut Random, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            // Draw from a few card kinds at a time, so pairs and full houses are as likely as in a real game
+            let kind_count = 1 + random.below(5);
+            let kinds: Vec<char> = (0..kind_count).map(|_| *random.choose(&CARDS)).collect();
+            let hand: String = (0..5).map(|_| *random.choose(&kinds)).collect();
+            format!("{} {}", hand, 1 + random.below(1000))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use crate::app::common::{ParseError, Parser};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum CamelCard {
@@ -34,44 +35,48 @@ pub enum CamelJokerCard {
     Ace
 }
 
-impl From<char> for CamelCard {
-    fn from(value: char) -> Self {
+impl TryFrom<char> for CamelCard {
+    type Error = char;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
         match value {
-            '2' => CamelCard::Two,
-            '3' => CamelCard::Three,
-            '4' => CamelCard::Four,
-            '5' => CamelCard::Five,
-            '6' => CamelCard::Six,
-            '7' => CamelCard::Seven,
-            '8' => CamelCard::Eight,
-            '9' => CamelCard::Nine,
-            'T' => CamelCard::Ten,
-            'J' => CamelCard::Jack,
-            'Q' => CamelCard::Queen,
-            'K' => CamelCard::King,
-            'A' => CamelCard::Ace,
-            _ => panic!("Invalid char for a card")
+            '2' => Ok(CamelCard::Two),
+            '3' => Ok(CamelCard::Three),
+            '4' => Ok(CamelCard::Four),
+            '5' => Ok(CamelCard::Five),
+            '6' => Ok(CamelCard::Six),
+            '7' => Ok(CamelCard::Seven),
+            '8' => Ok(CamelCard::Eight),
+            '9' => Ok(CamelCard::Nine),
+            'T' => Ok(CamelCard::Ten),
+            'J' => Ok(CamelCard::Jack),
+            'Q' => Ok(CamelCard::Queen),
+            'K' => Ok(CamelCard::King),
+            'A' => Ok(CamelCard::Ace),
+            _ => Err(value)
         }
     }
 }
 
-impl From<char> for CamelJokerCard {
-    fn from(value: char) -> Self {
+impl TryFrom<char> for CamelJokerCard {
+    type Error = char;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
         match value {
-            'J' => CamelJokerCard::Joker,
-            '2' => CamelJokerCard::Two,
-            '3' => CamelJokerCard::Three,
-            '4' => CamelJokerCard::Four,
-            '5' => CamelJokerCard::Five,
-            '6' => CamelJokerCard::Six,
-            '7' => CamelJokerCard::Seven,
-            '8' => CamelJokerCard::Eight,
-            '9' => CamelJokerCard::Nine,
-            'T' => CamelJokerCard::Ten,
-            'Q' => CamelJokerCard::Queen,
-            'K' => CamelJokerCard::King,
-            'A' => CamelJokerCard::Ace,
-            _ => panic!("Invalid char for a card")
+            'J' => Ok(CamelJokerCard::Joker),
+            '2' => Ok(CamelJokerCard::Two),
+            '3' => Ok(CamelJokerCard::Three),
+            '4' => Ok(CamelJokerCard::Four),
+            '5' => Ok(CamelJokerCard::Five),
+            '6' => Ok(CamelJokerCard::Six),
+            '7' => Ok(CamelJokerCard::Seven),
+            '8' => Ok(CamelJokerCard::Eight),
+            '9' => Ok(CamelJokerCard::Nine),
+            'T' => Ok(CamelJokerCard::Ten),
+            'Q' => Ok(CamelJokerCard::Queen),
+            'K' => Ok(CamelJokerCard::King),
+            'A' => Ok(CamelJokerCard::Ace),
+            _ => Err(value)
         }
     }
 }
@@ -100,7 +105,7 @@ pub struct CamelBid<T> {
 }
 
 impl CamelBid<CamelCard> {
-    fn without_jokers(hand_cards: [CamelCard; 5], bid_size: usize) -> Self {
+    pub fn without_jokers(hand_cards: [CamelCard; 5], bid_size: usize) -> Self {
         let mut cards_map: HashMap<CamelCard, usize> = HashMap::new();
 
         for hand_card in hand_cards {
@@ -126,19 +131,8 @@ impl CamelBid<CamelCard> {
     }
 }
 
-impl From<&str> for CamelBid<CamelCard> {
-    fn from(value: &str) -> Self {
-        let (hand_string, bid_string) = value.split_once(' ').unwrap();
-        let hand = hand_string.chars().map(|x| -> CamelCard { x.into() }).collect::<Vec<CamelCard>>();
-        let hand = [hand[0], hand[1], hand[2], hand[3], hand[4]];
-        let bid_size = bid_string.parse::<usize>().unwrap();
-
-        CamelBid::without_jokers(hand, bid_size)
-    }
-}
-
 impl CamelBid<CamelJokerCard> {
-    fn with_jokers(hand_cards: [CamelJokerCard; 5], bid_size: usize) -> CamelBid<CamelJokerCard> {
+    pub fn with_jokers(hand_cards: [CamelJokerCard; 5], bid_size: usize) -> CamelBid<CamelJokerCard> {
         let mut cards_map: HashMap<CamelJokerCard, usize> = HashMap::new();
         let mut jokers: usize = 0;
 
@@ -172,15 +166,16 @@ impl CamelBid<CamelJokerCard> {
     }
 }
 
-impl From<&str> for CamelBid<CamelJokerCard> {
-    fn from(value: &str) -> Self {
-        let (hand_string, bid_string) = value.split_once(' ').unwrap();
-        let hand = hand_string.chars().map(|x| -> CamelJokerCard { x.into() }).collect::<Vec<CamelJokerCard>>();
-        let hand = [hand[0], hand[1], hand[2], hand[3], hand[4]];
-        let bid_size = bid_string.parse::<usize>().unwrap();
-
-        CamelBid::with_jokers(hand, bid_size)
+/// A hand of five cards and the bid on it, separated by a space
+pub fn parse_bid<T: TryFrom<char>>(parser: &mut Parser) -> Result<([T; 5], usize), ParseError> {
+    let mut hand_cards: Vec<T> = Vec::with_capacity(5);
+    for _ in 0..5 {
+        hand_cards.push(parser.character("card", |c| T::try_from(c).ok())?);
     }
+    parser.literal(" ")?;
+    let bid_size = parser.unsigned::<usize>()?;
+
+    Ok((hand_cards.try_into().unwrap_or_else(|_| unreachable!("five cards were read")), bid_size))
 }
 
 fn get_hand_type_by_partition(partition: Vec<usize>) -> CamelHandType {
@@ -0,0 +1,23 @@
+#[cfg(feature = "ssr")]
+mod crucible_planner;
+#[cfg(feature = "ssr")]
+mod generator;
+
+#[cfg(feature = "ssr")]
+use crate::app::common::{ParseError, Parser};
+#[cfg(feature = "ssr")]
+use self::crucible_planner::{CrucibleConfig, CruciblePlanner};
+
+use crate::as_server_fn_with_timing;
+
+as_server_fn_with_timing! {
+    fn solve(part: ProblemPart, input: String) -> Result<usize, ParseError> {
+        let grid = Parser::parse_all(&input, |parser| parser.grid(|c| c.to_digit(10).map(|heat_loss| heat_loss as usize)))?;
+        let config = match part {
+            ProblemPart::Part1 => CrucibleConfig::legacy(),
+            ProblemPart::Part2 => CrucibleConfig::ultra()
+        };
+
+        Ok(CruciblePlanner::from(&grid, config).minimum_heat_loss())
+    }
+}
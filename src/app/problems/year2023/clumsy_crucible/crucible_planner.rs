@@ -0,0 +1,198 @@
+use crate::app::common::{shortest_path_in, DenseIndex, DenseMap, Direction, Grid, ImplicitGraph};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CrucibleConfig {
+    minimum: usize,
+    maximum: usize,
+}
+
+impl CrucibleConfig {
+    pub fn legacy() -> CrucibleConfig {
+        CrucibleConfig {
+            minimum: 0,
+            maximum: 3,
+        }
+    }
+
+    pub fn ultra() -> CrucibleConfig {
+        CrucibleConfig {
+            minimum: 4,
+            maximum: 10,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CrucibleHeading {
+    direction: Direction,
+    time: usize
+}
+
+impl CrucibleHeading {
+    fn from(direction: Direction) -> CrucibleHeading {
+        CrucibleHeading {
+            direction,
+            time: 1
+        }
+    }
+
+    fn with(self, direction: Direction) -> CrucibleHeading {
+        if self.direction == direction {
+            CrucibleHeading {
+                direction,
+                time: self.time + 1
+            }
+        } else {
+            CrucibleHeading::from(direction)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CrucibleLocation {
+    coords: (usize, usize),
+    heading: Option<CrucibleHeading>
+}
+
+impl CrucibleLocation {
+    fn new() -> CrucibleLocation {
+        CrucibleLocation {
+            coords: (0, 0),
+            heading: None
+        }
+    }
+}
+
+/// Numbers the locations block by block, with the locations on each block numbered by heading
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct LocationIndex {
+    width: usize,
+    height: usize,
+    maximum: usize,
+}
+
+impl LocationIndex {
+    /// No heading, or one of the four directions for up to the maximum time
+    fn headings_per_block(&self) -> usize {
+        1 + 4 * self.maximum
+    }
+}
+
+impl DenseIndex<CrucibleLocation> for LocationIndex {
+    fn size(&self) -> usize {
+        self.width * self.height * self.headings_per_block()
+    }
+
+    fn index(&self, location: &CrucibleLocation) -> Option<usize> {
+        let (x, y) = location.coords;
+        let heading = match location.heading {
+            None => 0,
+            Some(CrucibleHeading { direction, time }) if (1..=self.maximum).contains(&time) => {
+                1 + direction.index() * self.maximum + (time - 1)
+            },
+            Some(_) => return None,
+        };
+
+        (x < self.width && y < self.height)
+            .then_some((y * self.width + x) * self.headings_per_block() + heading)
+    }
+}
+
+#[derive(Debug)]
+pub struct CruciblePlanner<'a> {
+    grid: &'a Grid<usize>,
+    config: CrucibleConfig,
+}
+
+impl<'a> CruciblePlanner<'a> {
+    pub fn from(grid: &'a Grid<usize>, config: CrucibleConfig) -> CruciblePlanner<'a> {
+        CruciblePlanner {
+            grid,
+            config,
+        }
+    }
+
+    pub fn minimum_heat_loss(self) -> usize {
+        let endpoint = (self.grid.width() - 1, self.grid.height() - 1);
+        let is_target = |location: &CrucibleLocation| {
+            location.coords == endpoint && location.heading.is_some_and(|heading| heading.time >= self.config.minimum)
+        };
+
+        let index = LocationIndex { width: self.grid.width(), height: self.grid.height(), maximum: self.config.maximum };
+        shortest_path_in(&self, CrucibleLocation::new(), is_target, DenseMap::new(index), DenseMap::new(index)).unwrap().distance
+    }
+
+    /// The directions the crucible may move in next, which are never back where it came from
+    fn available_directions(&self, heading: Option<CrucibleHeading>) -> Vec<Direction> {
+        match heading {
+            None => Direction::all().collect(),
+            Some(heading) => {
+                let mut directions = Vec::with_capacity(3);
+                if heading.time < self.config.maximum {
+                    directions.push(heading.direction);
+                }
+                if heading.time >= self.config.minimum {
+                    directions.push(heading.direction.turn_left());
+                    directions.push(heading.direction.turn_right());
+                }
+                directions
+            }
+        }
+    }
+}
+
+impl<'a> ImplicitGraph for CruciblePlanner<'a> {
+    type Vertex = CrucibleLocation;
+
+    fn neighbors(&self, location: &CrucibleLocation) -> Vec<(usize, CrucibleLocation)> {
+        self.available_directions(location.heading).into_iter()
+            .filter_map(|direction| {
+                let coords = self.grid.step(location.coords, direction)?;
+                let heading = location.heading.map_or(CrucibleHeading::from(direction), |h| h.with(direction));
+
+                Some((self.grid[coords], CrucibleLocation { coords, heading: Some(heading) }))
+            })
+            .collect()
+    }
+
+    /// Every block loses at least 1 heat, so the crucible loses at least as much as the blocks it has left to cross
+    fn heuristic(&self, location: &CrucibleLocation) -> usize {
+        let (x, y) = location.coords;
+        (self.grid.width() - 1 - x) + (self.grid.height() - 1 - y)
+    }
+}
+
+#[cfg(test)]
+mod benches {
+    extern crate test;
+
+    use test::Bencher;
+    use crate::app::common::{shortest_path, Parser, Random};
+    use super::{super::generator, *};
+
+    /// A map of 60 by 60 blocks, smaller than the puzzle input so that a search can run many times
+    fn heat_loss_grid() -> Grid<usize> {
+        let input = generator::generate(&mut Random::new(17), 60);
+        Parser::parse_all(&input, |parser| parser.grid(|c| c.to_digit(10).map(|heat_loss| heat_loss as usize))).unwrap()
+    }
+
+    #[bench]
+    fn bench_hash_map_search(bencher: &mut Bencher) {
+        let grid = heat_loss_grid();
+        let planner = CruciblePlanner::from(&grid, CrucibleConfig::ultra());
+        let endpoint = (grid.width() - 1, grid.height() - 1);
+
+        bencher.iter(|| {
+            shortest_path(&planner, CrucibleLocation::new(), |location| {
+                location.coords == endpoint && location.heading.is_some_and(|heading| heading.time >= 4)
+            }).unwrap().distance
+        });
+    }
+
+    #[bench]
+    fn bench_dense_map_search(bencher: &mut Bencher) {
+        let grid = heat_loss_grid();
+
+        bencher.iter(|| CruciblePlanner::from(&grid, CrucibleConfig::ultra()).minimum_heat_loss());
+    }
+}
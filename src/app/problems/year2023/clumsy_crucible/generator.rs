@@ -0,0 +1,17 @@
+use crate::app::common::Random;
+
+pub const MAX_SIZE: usize = 500;
+
+/// Generates a `size` by `size` map of heat loss, at least large enough for an ultra crucible to cross
+pub fn generate(random: &mut Random, size: usize) -> String {
+    let size = size.max(5);
+
+    (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| char::from(b'1' + random.below(9) as u8))
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -4,20 +4,24 @@ mod cosmic_coords;
 mod cosmic_grid;
 #[cfg(feature = "ssr")]
 mod cosmic_interval;
+#[cfg(feature = "ssr")]
+mod generator;
 
+#[cfg(feature = "ssr")]
+use crate::app::{common::ParseError, math::BigInt};
 #[cfg(feature = "ssr")]
 use self::cosmic_grid::CosmicGrid;
 
 use crate::as_server_fn_with_timing;
 
 as_server_fn_with_timing! {
-    fn solve(part: ProblemPart, input: String) -> usize {
-        let cosmic_grid: CosmicGrid = input.as_str().into();
+    fn solve(part: ProblemPart, input: String) -> Result<BigInt, ParseError> {
+        let cosmic_grid = input.parse::<CosmicGrid>()?;
         let expansion_factor = match part {
             ProblemPart::Part1 => 2usize,
             ProblemPart::Part2 => 1000000usize
         };
 
-        cosmic_grid.weight(expansion_factor)
+        Ok(cosmic_grid.weight(expansion_factor))
     }
 }
@@ -0,0 +1,47 @@
+use std::str::FromStr;
+use crate::app::{common::{ParseError, Parser}, math::BigInt};
+use super::{cosmic_coords::CosmicCoords, cosmic_interval::CosmicIntervals};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CosmicGrid {
+    galaxies: Vec<CosmicCoords>,
+    with_x_count: Vec<usize>,
+    with_y_count: Vec<usize>,
+}
+
+impl CosmicGrid {
+    pub fn weight(&self, expansion_factor: usize) -> BigInt {
+        let x_weight = CosmicIntervals::from_counts(&self.with_x_count).weight(expansion_factor);
+        let y_weight = CosmicIntervals::from_counts(&self.with_y_count).weight(expansion_factor);
+        x_weight + y_weight
+    }
+}
+
+impl FromStr for CosmicGrid {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let is_galaxy = Parser::parse_all(s, |parser| parser.grid(|c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None
+        }))?;
+        let galaxies: Vec<CosmicCoords> = is_galaxy.coords()
+            .filter(|coords| is_galaxy[*coords])
+            .map(|(x, y)| CosmicCoords { x, y })
+            .collect();
+
+        let mut with_x_count: Vec<usize> = vec![0; is_galaxy.width()];
+        let mut with_y_count: Vec<usize> = vec![0; is_galaxy.height()];
+        for galaxy in galaxies.iter() {
+            with_x_count[galaxy.x] += 1;
+            with_y_count[galaxy.y] += 1;
+        }
+
+        Ok(CosmicGrid {
+            galaxies,
+            with_x_count,
+            with_y_count
+        })
+    }
+}
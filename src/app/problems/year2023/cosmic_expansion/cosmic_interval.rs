@@ -0,0 +1,103 @@
+use crate::app::common::Interval;
+use crate::app::math::{sum_of_products, BigInt};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CosmicInterval {
+    /// The empty rows or columns between two with galaxies
+    empty: Interval<usize>,
+    before_count: usize,
+    after_count: usize
+}
+
+impl CosmicInterval {
+    /// The distance across the interval times the pairs of galaxies on either side, as a sum of products
+    fn weight_terms(&self, expansion_factor: usize) -> [Vec<usize>; 2] {
+        [
+            vec![self.before_count, self.after_count],
+            vec![expansion_factor, self.empty.length(), self.before_count, self.after_count],
+        ]
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CosmicIntervals(Vec<CosmicInterval>);
+
+impl CosmicIntervals {
+    pub fn from_counts(galaxy_counts: &Vec<usize>) -> CosmicIntervals {
+        let count = galaxy_counts.iter().sum();
+
+        let mut start = 0usize;
+        let mut end = 0usize;
+        let mut before_count = 0usize;
+        let mut after_count = count;
+
+        let mut intervals: Vec<CosmicInterval> = Vec::new();
+
+        for count in galaxy_counts.iter() {
+            if *count == 0 {
+                end += 1;
+            } else {
+                if before_count != 0 && after_count != 0 {
+                    intervals.push(CosmicInterval { empty: Interval::new(start + 1, end), before_count, after_count });
+                }
+                start = end;
+                end += 1;
+                before_count += count;
+                after_count -= count;
+            }
+        }
+
+        CosmicIntervals(intervals)
+    }
+
+    pub fn weight(&self, expansion_factor: usize) -> BigInt {
+        sum_of_products(self.0.iter().flat_map(|interval| interval.weight_terms(expansion_factor)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::app::common::{check_property, shrink_number, shrink_vec};
+    use super::*;
+
+    /// Expands every empty column and adds up the distances between every pair of galaxies
+    fn reference_weight(galaxy_counts: &[usize], expansion_factor: usize) -> usize {
+        let mut expanded = 0usize;
+        let positions: Vec<usize> = galaxy_counts.iter()
+            .flat_map(|count| {
+                let position = expanded;
+                expanded += if *count == 0 { expansion_factor } else { 1 };
+                std::iter::repeat(position).take(*count)
+            })
+            .collect();
+
+        positions.iter().enumerate()
+            .flat_map(|(index, lhs)| positions[index + 1..].iter().map(move |rhs| rhs - lhs))
+            .sum()
+    }
+
+    #[test]
+    fn should_match_reference_weight() {
+        check_property(
+            500,
+            |random, size| {
+                let counts: Vec<usize> = (0..2 * size).map(|_| if random.chance(1, 2) { 0 } else { random.below(4) }).collect();
+                (counts, 1 + random.below(10))
+            },
+            |(counts, expansion_factor)| {
+                let fewer_counts = shrink_vec(counts, |count| shrink_number(*count)).into_iter()
+                    .map(|counts| (counts, *expansion_factor));
+                let smaller_factor = shrink_number(*expansion_factor).into_iter()
+                    .filter(|expansion_factor| *expansion_factor > 0)
+                    .map(|expansion_factor| (counts.clone(), expansion_factor));
+
+                fewer_counts.chain(smaller_factor).collect()
+            },
+            |(counts, expansion_factor)| {
+                let actual = CosmicIntervals::from_counts(counts).weight(*expansion_factor);
+                let expected = reference_weight(counts, *expansion_factor);
+                if actual == BigInt::from(expected) { Ok(()) } else { Err(format!("got {}, expected {}", actual, expected)) }
+            },
+        );
+    }
+}
@@ -0,0 +1,19 @@
+use crate::app::common::Random;
+
+pub const MAX_SIZE: usize = 1000;
+
+/// Generates a `size` by `size` image with scattered galaxies, leaving some rows and columns empty
+pub fn generate(random: &mut Random, size: usize) -> String {
+    let size = size.max(2);
+    let empty_rows: Vec<bool> = (0..size).map(|_| random.chance(1, 8)).collect();
+    let empty_columns: Vec<bool> = (0..size).map(|_| random.chance(1, 8)).collect();
+
+    (0..size)
+        .map(|y| {
+            (0..size)
+                .map(|x| if !empty_rows[y] && !empty_columns[x] && random.chance(1, 16) { '#' } else { '.' })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
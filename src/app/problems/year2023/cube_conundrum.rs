@@ -0,0 +1,42 @@
+#[cfg(feature = "ssr")]
+mod box_set;
+#[cfg(feature = "ssr")]
+mod generator;
+
+#[cfg(feature = "ssr")]
+use crate::app::common::{ParseError, Parser};
+#[cfg(feature = "ssr")]
+use self::box_set::BoxSet;
+
+use crate::as_server_fn_with_timing;
+
+as_server_fn_with_timing! {
+    fn solve(part: ProblemPart, input: String) -> Result<usize, ParseError> {
+        let games = Parser::parse_all(&input, |parser| parser.lines(|parser| parser.key_value(
+            |parser| {
+                parser.literal("Game ")?;
+                parser.unsigned::<usize>()
+            },
+            |parser| parser.separated(";", BoxSet::parse),
+        )))?;
+
+        Ok(match part {
+            ProblemPart::Part1 => {
+                let comparison = BoxSet {
+                    red: 12,
+                    green: 13,
+                    blue: 14,
+                };
+                games.into_iter()
+                    .filter(|(_, box_sets)| box_sets.iter().all(|box_set| *box_set <= comparison))
+                    .map(|(game_id, _)| game_id)
+                    .sum()
+            },
+            ProblemPart::Part2 => {
+                games.into_iter()
+                    .map(|(_, box_sets)| box_sets.into_iter().sum::<BoxSet>().power())
+                    .sum()
+            }
+        })
+    }
+}
@@ -1,6 +1,7 @@
 use std::cmp::{max, Ordering};
 use std::iter::Sum;
 use std::ops::Add;
+use crate::app::common::{ParseError, Parser};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct BoxSet {
@@ -13,6 +14,31 @@ impl BoxSet {
     pub fn power(&self) -> usize {
         self.red * self.blue * self.green
     }
+
+    /// Counts of cubes by color, such as `3 blue, 4 red`
+    pub fn parse(parser: &mut Parser) -> Result<BoxSet, ParseError> {
+        let mut box_set = BoxSet {
+            red: 0,
+            green: 0,
+            blue: 0,
+        };
+        parser.separated(",", |parser| {
+            let count = parser.unsigned::<usize>()?;
+            parser.literal(" ")?;
+            if parser.try_literal("red") {
+                box_set.red = count;
+            } else if parser.try_literal("green") {
+                box_set.green = count;
+            } else if parser.try_literal("blue") {
+                box_set.blue = count;
+            } else {
+                return Err(parser.error("color"));
+            }
+            Ok(())
+        })?;
+
+        Ok(box_set)
+    }
 }
 
 impl PartialOrd for BoxSet {
@@ -51,26 +77,3 @@ impl Sum for BoxSet {
         box_set
     }
 }
-
-impl From<&str> for BoxSet {
-    fn from(value: &str) -> Self {
-        let items = value.split(", ");
-        let mut box_set = BoxSet {
-            red: 0,
-            green: 0,
-            blue: 0,
-        };
-        for item in items {
-            if item.ends_with("green") {
-                box_set.green = item.strip_suffix(" green").unwrap().parse::<usize>().unwrap();
-            }
-            if item.ends_with("red") {
-                box_set.red = item.strip_suffix(" red").unwrap().parse::<usize>().unwrap();
-            }
-            if item.ends_with("blue") {
-                box_set.blue = item.strip_suffix(" blue").unwrap().parse::<usize>().unwrap();
-            }
-        }
-        box_set
-    }
-}
@@ -0,0 +1,28 @@
+use crate::app::common::Random;
+
+pub const MAX_SIZE: usize = 10_000;
+
+/// Generates `size` games of up to six draws each
+pub fn generate(random: &mut Random, size: usize) -> String {
+    (1..=size.max(1))
+        .map(|game_id| {
+            let draws = (0..1 + random.below(6))
+                .map(|_| generate_draw(random))
+                .collect::<Vec<_>>()
+                .join("; ");
+            format!("Game {}: {}", game_id, draws)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn generate_draw(random: &mut Random) -> String {
+    let mut colors = vec!["red", "green", "blue"];
+    random.shuffle(&mut colors);
+    colors.truncate(1 + random.below(3));
+
+    colors.into_iter()
+        .map(|color| format!("{} {}", 1 + random.below(20), color))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -0,0 +1,24 @@
+#[cfg(feature = "ssr")]
+mod gears;
+#[cfg(feature = "ssr")]
+mod generator;
+
+#[cfg(feature = "ssr")]
+use crate::app::common::ParseError;
+#[cfg(feature = "ssr")]
+use self::gears::Schematic;
+
+use crate::as_server_fn_with_timing;
+
+as_server_fn_with_timing! {
+    fn solve(part: ProblemPart, input: String) -> Result<usize, ParseError> {
+        let schematic = input.parse::<Schematic>()?;
+        Ok(match part {
+            ProblemPart::Part1 => schematic
+                .get_parts_adjacent_to_symbol().into_iter()
+                .map(|item| item.value)
+                .sum(),
+            ProblemPart::Part2 => schematic.get_gears()
+        })
+    }
+}
@@ -0,0 +1,96 @@
+use std::collections::HashSet;
+use std::str::FromStr;
+use crate::app::common::{Grid, ParseError, Parser};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GearLocation {
+    pub value: usize,
+    pub line: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct SymbolLocation {
+    symbol: char,
+    coords: (usize, usize),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Schematic {
+    parts: Vec<GearLocation>,
+    symbols: Vec<SymbolLocation>,
+    /// For each tile, the index in `parts` of the part it is a digit of
+    part_tiles: Grid<Option<usize>>,
+}
+
+impl Schematic {
+    /// The indices in `parts` of the parts with a digit next to the symbol, also diagonally
+    fn adjacent_parts(&self, symbol: &SymbolLocation) -> HashSet<usize> {
+        self.part_tiles.neighbors_with_diagonals(symbol.coords)
+            .filter_map(|coords| self.part_tiles[coords])
+            .collect()
+    }
+
+    pub fn get_parts_adjacent_to_symbol(&self) -> HashSet<GearLocation> {
+        self.symbols.iter()
+            .flat_map(|symbol| self.adjacent_parts(symbol))
+            .map(|index| self.parts[index])
+            .collect()
+    }
+
+    pub fn get_gears(&self) -> usize {
+        self.symbols.iter()
+            .filter(|symbol| symbol.symbol == '*')
+            .map(|symbol| self.adjacent_parts(symbol))
+            .filter(|parts| parts.len() == 2)
+            .map(|parts| parts.into_iter().map(|index| self.parts[index].value).product::<usize>())
+            .sum()
+    }
+}
+
+impl FromStr for Schematic {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Parser::parse_all(s, |parser| {
+            // The grid makes sure the rows are even, and then the numbers are read again from the start
+            let mut reader = *parser;
+            let tiles = parser.grid(Some)?;
+            let mut parts: Vec<GearLocation> = Vec::new();
+            let mut symbols: Vec<SymbolLocation> = Vec::new();
+            let mut part_tiles = tiles.map(|_| None);
+
+            for (line, row) in tiles.rows().enumerate() {
+                if line > 0 {
+                    reader.line_end()?;
+                }
+                let mut position = 0usize;
+                while position < row.len() {
+                    let c = row[position];
+                    if c.is_ascii_digit() {
+                        let length = row[position..].iter().take_while(|c| c.is_ascii_digit()).count();
+                        let value = reader.unsigned::<usize>()?;
+                        for x in position..position + length {
+                            part_tiles[(x, line)] = Some(parts.len());
+                        }
+                        parts.push(GearLocation { value, line, start: position, end: position + length - 1 });
+                        position += length;
+                    } else {
+                        reader.character("tile", Some)?;
+                        if c != '.' {
+                            symbols.push(SymbolLocation { symbol: c, coords: (position, line) });
+                        }
+                        position += 1;
+                    }
+                }
+            }
+
+            Ok(Schematic {
+                parts,
+                symbols,
+                part_tiles,
+            })
+        })
+    }
+}
@@ -0,0 +1,35 @@
+use crate::app::common::Random;
+
+pub const MAX_SIZE: usize = 1000;
+
+const SYMBOLS: [char; 10] = ['*', '*', '*', '#', '+', '$', '/', '@', '%', '='];
+
+/// Generates a `size` by `size` engine schematic of part numbers and symbols
+pub fn generate(random: &mut Random, size: usize) -> String {
+    let size = size.max(3);
+
+    (0..size)
+        .map(|_| {
+            let mut row = String::with_capacity(size);
+            while row.len() < size {
+                let remaining = size - row.len();
+                if random.chance(1, 5) {
+                    let digit_count = (1 + random.below(3)).min(remaining);
+                    row.push(char::from(b'1' + random.below(9) as u8));
+                    for _ in 1..digit_count {
+                        row.push(char::from(b'0' + random.below(10) as u8));
+                    }
+                } else if random.chance(1, 8) {
+                    row.push(*random.choose(&SYMBOLS));
+                    continue;
+                }
+
+                if row.len() < size {
+                    row.push('.');
+                }
+            }
+            row
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
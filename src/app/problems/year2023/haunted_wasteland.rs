@@ -10,11 +10,30 @@ mod network_path;
 mod crossroads;
 #[cfg(feature="ssr")]
 mod network;
+#[cfg(feature="ssr")]
+mod generator;
 
+#[cfg(feature="ssr")]
+use crate::app::common::{ParseError, Parser};
 #[cfg(feature="ssr")]
 use self::{direction::Direction, node::Node, network::Network};
 
-use crate::as_server_fn_with_timing;
+use crate::{as_graph_export_server_fn, as_server_fn_with_timing};
+
+/// The directions on the first line, and the network after a blank line
+#[cfg(feature="ssr")]
+fn parse_document(input: &str) -> Result<(Vec<Direction>, Network), ParseError> {
+    Parser::parse_all(input, |parser| {
+        let mut directions: Vec<Direction> = Vec::new();
+        while !parser.is_at_line_end() {
+            directions.push(parser.character("direction", |c| Direction::try_from(c).ok())?);
+        }
+        parser.line_end()?;
+        parser.line_end()?;
+
+        Ok((directions, Network::parse(parser)?))
+    })
+}
 
 #[cfg(feature="ssr")]
 fn solve_1(network: Network, directions: Vec<Direction>) -> Option<usize> {
@@ -41,13 +60,10 @@ fn solve_2(network: Network, directions: Vec<Direction>) -> Option<usize> {
 }
 
 as_server_fn_with_timing! {
-    fn solve(part: ProblemPart, input: String) -> String {
-        let (directions, network) = input.split_once("\n\n").unwrap();
-        let directions = directions.chars().map(|x| -> Direction { x.into() }).collect::<Vec<Direction>>();
-
-        let network: Network = network.into();
+    fn solve(part: ProblemPart, input: String) -> Result<String, ParseError> {
+        let (directions, network) = parse_document(&input)?;
 
-        match part {
+        Ok(match part {
             ProblemPart::Part1 => {
                 if let Some(solution) = solve_1(network, directions) {
                     solution.to_string()
@@ -62,6 +78,13 @@ as_server_fn_with_timing! {
                     "Never".to_string()
                 }
             }
-        }
+        })
+    }
+}
+
+as_graph_export_server_fn! {
+    fn graph(input: &str) -> Result<Network, ParseError> {
+        let (_, network) = parse_document(input)?;
+        Ok(network)
     }
 }
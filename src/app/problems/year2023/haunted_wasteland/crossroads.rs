@@ -0,0 +1,24 @@
+use crate::app::common::{ParseError, Parser};
+use super::node::Node;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Crossroads {
+    pub left: Node,
+    pub right: Node
+}
+
+impl Crossroads {
+    pub fn parse(parser: &mut Parser) -> Result<Crossroads, ParseError> {
+        parser.literal("(")?;
+        let left = Node::parse(parser)?;
+        parser.literal(", ")?;
+        let right = Node::parse(parser)?;
+        parser.literal(")")?;
+
+        Ok(Crossroads {
+            left,
+            right
+        })
+    }
+}
+
@@ -0,0 +1,153 @@
+use std::collections::HashSet;
+use crate::app::math::{crt, lcm, Mod};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventuallyPeriodic {
+    pub initial_part: Vec<usize>,
+    pub initial_length: usize,
+    pub repeating_part: Vec<usize>,
+    pub repeating_length: usize,
+}
+
+impl EventuallyPeriodic {
+    pub fn get_first_index(&self) -> Option<usize> {
+        if let Some(index) = self.initial_part.first() {
+            Some(*index)
+        } else if let Some(index) = self.repeating_part.first() {
+            Some(*index + self.initial_length)
+        } else {
+            None
+        }
+    }
+
+    fn shift_repeating(&mut self, by: usize) {
+        let copies = by / self.repeating_length;
+        for i in 0..copies {
+            let mut to_append: Vec<_> = self.repeating_part.iter()
+                .map(|x| *x + self.initial_length + i * self.repeating_length)
+                .collect();
+            self.initial_part.append(&mut to_append);
+        }
+
+        let mut to_append: Vec<_> = self.repeating_part.iter()
+            .filter_map(|x| if *x < (by % self.repeating_length) { Some(*x + self.initial_length + copies * self.repeating_length)} else { None })
+            .collect();
+        self.initial_part.append(&mut to_append);
+
+        self.initial_length += by;
+
+        let mut repeating_part: Vec<_> = self.repeating_part.iter()
+            .filter_map(|x| if *x >= (by % self.repeating_length) { Some(*x - (by % self.repeating_length)) } else { None })
+            .collect();
+        let mut shifted_repeated: Vec<_> =  self.repeating_part.iter()
+            .filter_map(|x| if *x < (by % self.repeating_length) { Some(*x + self.repeating_length - (by % self.repeating_length)) } else { None })
+            .collect();
+        repeating_part.append(&mut shifted_repeated);
+
+        self.repeating_part = repeating_part;
+    }
+
+    pub fn mul(mut self, mut rhs: Self) -> Self {
+        if self.initial_length < rhs.initial_length {
+            self.shift_repeating(rhs.initial_length - self.initial_length);
+        } else if self.initial_length > rhs.initial_length {
+            rhs.shift_repeating(self.initial_length - rhs.initial_length);
+        }
+        let initial_length = self.initial_length; // now that we made those equal
+
+        let initial_lhs: HashSet<_> = HashSet::from_iter(self.initial_part.iter().map(|x| *x));
+        let initial_rhs: HashSet<_> = HashSet::from_iter(rhs.initial_part.iter().map(|x| *x));
+        let mut initial_part: Vec<_> = initial_lhs.intersection(&initial_rhs).map(|x| *x).collect();
+        initial_part.sort();
+
+        let repeating_length = lcm(self.repeating_length, rhs.repeating_length)
+            .expect("The combined period should fit in a usize");
+
+        // Every pair of congruences has a solution below the combined period, or none at all
+        let mut repeating_part: Vec<_> = self.repeating_part.iter()
+            .flat_map(|lhs| {
+                rhs.repeating_part.iter()
+                    .filter_map(|rhs_value| crt([
+                        Mod { value: *lhs, modulo: self.repeating_length },
+                        Mod { value: *rhs_value, modulo: rhs.repeating_length },
+                    ]).ok())
+            })
+            .map(|x| x.value)
+            .collect();
+        repeating_part.sort();
+
+        EventuallyPeriodic {
+            initial_length,
+            initial_part,
+            repeating_length,
+            repeating_part
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::app::common::{check_property, Random};
+    use super::*;
+
+    /// Whether the index is in the set, read directly from the definition
+    fn contains(periodic: &EventuallyPeriodic, index: usize) -> bool {
+        if index < periodic.initial_length {
+            periodic.initial_part.contains(&index)
+        } else {
+            periodic.repeating_part.contains(&((index - periodic.initial_length) % periodic.repeating_length))
+        }
+    }
+
+    fn random_periodic(random: &mut Random, size: usize) -> EventuallyPeriodic {
+        let initial_length = random.below(2 * size + 1);
+        let repeating_length = 1 + random.below(2 * size);
+
+        EventuallyPeriodic {
+            initial_part: (0..initial_length).filter(|_| random.chance(1, 3)).collect(),
+            initial_length,
+            repeating_part: (0..repeating_length).filter(|_| random.chance(1, 3)).collect(),
+            repeating_length,
+        }
+    }
+
+    /// The same set with a single index left out of the initial or repeating part
+    fn shrink_periodic(periodic: &EventuallyPeriodic) -> Vec<EventuallyPeriodic> {
+        let fewer_initial = (0..periodic.initial_part.len()).map(|index| {
+            let mut candidate = periodic.clone();
+            candidate.initial_part.remove(index);
+            candidate
+        });
+        let fewer_repeating = (0..periodic.repeating_part.len()).map(|index| {
+            let mut candidate = periodic.clone();
+            candidate.repeating_part.remove(index);
+            candidate
+        });
+
+        fewer_initial.chain(fewer_repeating).collect()
+    }
+
+    #[test]
+    fn should_match_reference_intersection() {
+        check_property(
+            1000,
+            |random, size| (random_periodic(random, size), random_periodic(random, size)),
+            |(lhs, rhs)| {
+                let smaller_lhs = shrink_periodic(lhs).into_iter().map(|lhs| (lhs, rhs.clone()));
+                let smaller_rhs = shrink_periodic(rhs).into_iter().map(|rhs| (lhs.clone(), rhs));
+                smaller_lhs.chain(smaller_rhs).collect()
+            },
+            |(lhs, rhs)| {
+                let product = lhs.clone().mul(rhs.clone());
+                let bound = lhs.initial_length.max(rhs.initial_length) + 2 * lhs.repeating_length * rhs.repeating_length;
+
+                match (0..bound).find(|index| contains(&product, *index) != (contains(lhs, *index) && contains(rhs, *index))) {
+                    Some(index) => Err(format!("product is wrong at index {}", index)),
+                    None if product.get_first_index() != (0..bound).find(|index| contains(lhs, *index) && contains(rhs, *index)) =>
+                        Err(format!("first index {:?} is wrong", product.get_first_index())),
+                    None => Ok(()),
+                }
+            },
+        );
+    }
+}
@@ -0,0 +1,38 @@
+use std::collections::HashSet;
+use crate::app::common::Random;
+
+pub const MAX_SIZE: usize = 10_000;
+
+/// Generates a network of `size` nodes, including `AAA`, `ZZZ` and up to two more ghost starts and ends
+pub fn generate(random: &mut Random, size: usize) -> String {
+    let size = size.max(2);
+
+    let direction_count = 2 + random.below(size);
+    let directions: String = (0..direction_count).map(|_| *random.choose(&['L', 'R'])).collect();
+
+    let ghost_count = random.below(3).min((size - 2) / 2);
+    let mut names = vec!["AAA".to_string(), "ZZZ".to_string()];
+    let mut used_names: HashSet<String> = names.iter().cloned().collect();
+    while names.len() < size {
+        let ending = if names.len() < 2 + ghost_count {
+            'A'
+        } else if names.len() < 2 + 2 * ghost_count {
+            'Z'
+        } else {
+            char::from(b'B' + random.below(24) as u8)
+        };
+        let name = format!("{}{}", random.lowercase_word(2).to_uppercase(), ending);
+
+        if used_names.insert(name.clone()) {
+            names.push(name);
+        }
+    }
+
+    random.shuffle(&mut names);
+    let nodes = names.iter()
+        .map(|name| format!("{} = ({}, {})", name, random.choose(&names), random.choose(&names)))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!("{}\n\n{}", directions, nodes)
+}
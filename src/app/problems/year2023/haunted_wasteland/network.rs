@@ -0,0 +1,65 @@
+use std::collections::HashMap;
+use crate::app::common::{find_orbit, ExportEdge, ExportNode, GraphExport, ParseError, Parser};
+use super::{crossroads::Crossroads, direction::Direction, network_path::NetworkPath, node::Node};
+
+pub struct Network(HashMap<Node, Crossroads>);
+
+impl Network {
+    pub fn traverse(&self, node: Node, dir: Direction) -> Node {
+        let Network(map) = self;
+        let crossroads = map.get(&node).unwrap();
+        match dir {
+            Direction::Left => crossroads.left,
+            Direction::Right => crossroads.right,
+        }
+    }
+
+    pub fn get_starting_nodes(&self) -> Vec<Node> {
+        self.0.keys().filter_map(|key| if key.is_start() { Some(*key) } else { None }).collect()
+    }
+
+    pub fn get_path(&self, node: Node, dirs: &Vec<Direction>) -> NetworkPath {
+        // The walk repeats from the first time it is back at a node at the same point in the directions
+        let orbit = find_orbit((node, 0usize), |(from, index)| {
+            *from = self.traverse(*from, dirs[*index]);
+            *index = (*index + 1) % dirs.len();
+        });
+        let visited: Vec<Node> = orbit.states().iter().map(|(node, _)| *node).collect();
+        let (initial, repeating) = visited.split_at(orbit.cycle().tail_length);
+
+        NetworkPath::new(initial.to_vec(), repeating.to_vec())
+    }
+
+    /// The crossroads at a node on each line, such as `AAA = (BBB, CCC)`
+    pub fn parse(parser: &mut Parser) -> Result<Network, ParseError> {
+        let crossroads = parser.lines(|parser| {
+            let node = Node::parse(parser)?;
+            parser.literal(" = ")?;
+            Ok((node, Crossroads::parse(parser)?))
+        })?;
+
+        Ok(Network(crossroads.into_iter().collect()))
+    }
+}
+
+impl GraphExport for Network {
+    /// Starting nodes are drawn as boxes, and ending nodes as double circles
+    fn nodes(&self) -> Vec<ExportNode> {
+        self.0.keys()
+            .map(|node| ExportNode {
+                id: node.to_string(),
+                shape: if node.is_start() { Some("box") } else if node.is_end() { Some("doublecircle") } else { None },
+            })
+            .collect()
+    }
+
+    fn edges(&self) -> Vec<ExportEdge> {
+        self.0.iter()
+            .flat_map(|(node, crossroads)| [("L", crossroads.left), ("R", crossroads.right)].map(|(label, to)| ExportEdge {
+                from: node.to_string(),
+                to: to.to_string(),
+                label: Some(label.to_string()),
+            }))
+            .collect()
+    }
+}
@@ -0,0 +1,37 @@
+use std::fmt::Display;
+use crate::app::common::{ParseError, Parser};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Node([char; 3]);
+
+impl Node {
+    pub fn new(name: &str) -> Node {
+        let chars = name.chars().collect::<Vec<char>>();
+        Node([chars[0], chars[1], chars[2]])
+    }
+
+    /// A name of three letters or digits
+    pub fn parse(parser: &mut Parser) -> Result<Node, ParseError> {
+        let start = *parser;
+        let name = parser.word()?;
+        if name.chars().count() != 3 {
+            return Err(start.error("name of three characters"));
+        }
+
+        Ok(Node::new(name))
+    }
+
+    pub fn is_start(&self) -> bool {
+        self.0[2] == 'A'
+    }
+
+    pub fn is_end(&self) -> bool {
+        self.0[2] == 'Z'
+    }
+}
+
+impl Display for Node {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}{}", self.0[0], self.0[1], self.0[2])
+    }
+}
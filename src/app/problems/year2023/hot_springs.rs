@@ -0,0 +1,57 @@
+#[cfg(feature = "ssr")]
+mod arrangement_counter;
+#[cfg(feature = "ssr")]
+mod spring_condition;
+#[cfg(feature = "ssr")]
+mod generator;
+
+#[cfg(feature = "ssr")]
+use crate::app::{common::{parallel_map, MemoStats, ParseError, Parser}, problems::timing::WithStatistics};
+#[cfg(feature = "ssr")]
+use self::{arrangement_counter::ArrangementCounter, spring_condition::parse_record};
+
+use crate::as_server_fn_with_timing;
+
+as_server_fn_with_timing! {
+    fn solve(part: ProblemPart, input: String) -> Result<WithStatistics<usize, MemoStats>, ParseError> {
+        let records = Parser::parse_all(&input, |parser| parser.lines(parse_record))?;
+        let (counts, stats): (Vec<usize>, Vec<MemoStats>) = parallel_map(&records, |(spring_conditions, damaged_group_sizes)| {
+            let (mut spring_conditions, mut damaged_group_sizes) = (spring_conditions.clone(), damaged_group_sizes.clone());
+
+            if part == ProblemPart::Part2 {
+                spring_conditions = vec![
+                    spring_conditions.clone(),
+                    vec![None],
+                    spring_conditions.clone(),
+                    vec![None],
+                    spring_conditions.clone(),
+                    vec![None],
+                    spring_conditions.clone(),
+                    vec![None],
+                    spring_conditions.clone(),
+                ]
+                    .into_iter()
+                    .flatten()
+                    .collect();
+                damaged_group_sizes = vec![
+                    damaged_group_sizes.clone(),
+                    damaged_group_sizes.clone(),
+                    damaged_group_sizes.clone(),
+                    damaged_group_sizes.clone(),
+                    damaged_group_sizes.clone(),
+                ]
+                    .into_iter()
+                    .flatten()
+                    .collect();
+            }
+
+            let arrangement_counter = ArrangementCounter::new(spring_conditions, damaged_group_sizes);
+            (arrangement_counter.count_arrangements(), arrangement_counter.stats())
+        }).into_iter().unzip();
+
+        Ok(WithStatistics {
+            solution: counts.into_iter().sum(),
+            statistics: stats.into_iter().sum(),
+        })
+    }
+}
@@ -0,0 +1,76 @@
+use crate::app::common::{Memo, MemoStats};
+use super::spring_condition::SpringCondition;
+
+/// Counts the arrangements of a record, remembering the count for each point in the record it gets to
+pub struct ArrangementCounter {
+    spring_conditions: Vec<Option<SpringCondition>>,
+    damaged_group_sizes: Vec<usize>,
+    /// The counts by the offsets into the spring conditions and the damaged group sizes
+    counts: Memo<(usize, usize), usize>,
+}
+
+impl ArrangementCounter {
+    pub fn new(spring_conditions: Vec<Option<SpringCondition>>, damaged_group_sizes: Vec<usize>) -> Self {
+        Self {
+            spring_conditions,
+            damaged_group_sizes,
+            counts: Memo::new(),
+        }
+    }
+
+    pub fn count_arrangements(&self) -> usize {
+        self.count_from(0, 0)
+    }
+
+    pub fn stats(&self) -> MemoStats {
+        self.counts.stats()
+    }
+
+    /// The arrangements of the springs from `spring_offset` on, with the damaged groups from `group_offset` on
+    fn count_from(&self, spring_offset: usize, group_offset: usize) -> usize {
+        self.counts.get_or_compute((spring_offset, group_offset), || {
+            let spring_conditions = &self.spring_conditions[spring_offset..];
+            let damaged_group_sizes = &self.damaged_group_sizes[group_offset..];
+
+            if spring_conditions.is_empty() {
+                if damaged_group_sizes.is_empty() {
+                    1
+                } else {
+                    0
+                }
+            } else {
+                match spring_conditions[0] {
+                    Some(SpringCondition::Operational) => self.count_from(spring_offset + 1, group_offset),
+                    spring_condition => {
+                        let none_result = if spring_condition.is_none() {
+                            self.count_from(spring_offset + 1, group_offset)
+                        } else {
+                            0
+                        };
+
+                        let damaged_result = if damaged_group_sizes.is_empty() {
+                            0
+                        } else {
+                            let damaged_group_size = damaged_group_sizes[0];
+                            let checked_length = if spring_conditions.len() > damaged_group_size {
+                                damaged_group_size + 1
+                            } else {
+                                damaged_group_size
+                            };
+
+                            if spring_conditions.len() >= damaged_group_size &&
+                                (1..damaged_group_size).all(|i| spring_conditions[i] != Some(SpringCondition::Operational)) &&
+                                (spring_conditions.len() == damaged_group_size || spring_conditions[damaged_group_size] != Some(SpringCondition::Damaged)) {
+                                self.count_from(spring_offset + checked_length, group_offset + 1)
+                            } else {
+                                0
+                            }
+                        };
+
+                        none_result + damaged_result
+                    }
+                }
+            }
+        })
+    }
+}
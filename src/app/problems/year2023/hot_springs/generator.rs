@@ -0,0 +1,28 @@
+use crate::app::common::Random;
+
+pub const MAX_SIZE: usize = 10_000;
+
+/// Generates `size` condition records of up to 20 springs, with about a third of the conditions unknown
+pub fn generate(random: &mut Random, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            let length = 3 + random.below(18);
+            let mut damaged: Vec<bool> = (0..length).map(|_| random.chance(1, 2)).collect();
+            if !damaged.contains(&true) {
+                damaged[random.below(length)] = true;
+            }
+
+            let group_sizes = damaged.split(|is_damaged| !is_damaged)
+                .filter(|group| !group.is_empty())
+                .map(|group| group.len().to_string())
+                .collect::<Vec<_>>()
+                .join(",");
+            let conditions: String = damaged.into_iter()
+                .map(|is_damaged| if random.chance(1, 3) { '?' } else if is_damaged { '#' } else { '.' })
+                .collect();
+
+            format!("{} {}", conditions, group_sizes)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
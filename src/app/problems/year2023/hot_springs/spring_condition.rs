@@ -0,0 +1,31 @@
+use crate::app::common::{ParseError, Parser};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SpringCondition {
+    Damaged,
+    Operational
+}
+
+impl SpringCondition {
+    /// The condition of a spring, which is `None` if unknown
+    fn read(c: char) -> Option<Option<SpringCondition>> {
+        match c {
+            '#' => Some(Some(SpringCondition::Damaged)),
+            '.' => Some(Some(SpringCondition::Operational)),
+            '?' => Some(None),
+            _ => None
+        }
+    }
+}
+
+/// The conditions of a row of springs and the sizes of its groups of damaged springs, such as `?#?.. 2,1`
+pub fn parse_record(parser: &mut Parser) -> Result<(Vec<Option<SpringCondition>>, Vec<usize>), ParseError> {
+    let mut spring_conditions: Vec<Option<SpringCondition>> = Vec::new();
+    while !parser.is_at_line_end() && parser.peek() != Some(' ') {
+        spring_conditions.push(parser.character("spring condition", SpringCondition::read)?);
+    }
+    parser.literal(" ")?;
+    let damaged_group_sizes = parser.separated(",", |parser| parser.unsigned::<usize>())?;
+
+    Ok((spring_conditions, damaged_group_sizes))
+}
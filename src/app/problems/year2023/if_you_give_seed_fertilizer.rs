@@ -0,0 +1,77 @@
+#[cfg(feature = "ssr")]
+mod almanac;
+#[cfg(feature = "ssr")]
+mod generator;
+
+#[cfg(feature = "ssr")]
+use crate::app::common::{Interval, IntervalSet, ParseError, Parser};
+#[cfg(feature = "ssr")]
+use self::almanac::{ Almanac, Resource, ResourceRange };
+
+use crate::as_server_fn_with_timing;
+
+/// The seeds on the first line, and the almanac after a blank line
+#[cfg(feature = "ssr")]
+fn parse_document(input: &str) -> Result<(Vec<usize>, Almanac), ParseError> {
+    Parser::parse_all(input, |parser| {
+        let seeds = parser.labelled("seeds", |parser| parser.spaced(|parser| parser.unsigned::<usize>()))?;
+        parser.line_end()?;
+        parser.line_end()?;
+
+        Ok((seeds, Almanac::parse(parser)?))
+    })
+}
+
+#[cfg(feature = "ssr")]
+pub fn solve_1(input: &str) -> Result<usize, ParseError> {
+    let (seeds, almanac) = parse_document(input)?;
+    let seeds = seeds.into_iter()
+        .map(|x| Resource { name: "seed".to_string(), number: x })
+        .collect::<Vec<Resource>>();
+
+    let mut locations: Vec<usize> = Vec::new();
+    for resource in seeds.iter() {
+        let mut resource = resource.clone();
+        while &resource.name != "location" {
+            resource = almanac.map(&resource)
+        }
+
+        locations.push(resource.number);
+    }
+
+    Ok(locations.into_iter().min().unwrap())
+}
+
+#[cfg(feature = "ssr")]
+pub fn solve_2(input: &str) -> Result<usize, ParseError> {
+    let (seeds, almanac) = parse_document(input)?;
+    let mut resource_range = ResourceRange {
+        name: "seed".to_string(),
+        numbers: read_ranges(&seeds)
+    };
+    while &resource_range.name != "location" {
+        resource_range = almanac.map_range(&resource_range);
+    }
+
+    Ok(resource_range.numbers[0].start as usize)
+}
+
+#[cfg(feature = "ssr")]
+fn read_ranges(seeds: &[usize]) -> IntervalSet<isize> {
+    let mut ranges = IntervalSet::new();
+
+    for range in seeds.chunks(2) {
+        ranges.insert(Interval::with_length(range[0] as isize, range[1] as isize))
+    }
+
+    ranges
+}
+
+as_server_fn_with_timing! {
+    fn solve(part: ProblemPart, input: String) -> Result<usize, ParseError> {
+        match part {
+            ProblemPart::Part1 => solve_1(&input),
+            ProblemPart::Part2 => solve_2(&input)
+        }
+    }
+}
@@ -0,0 +1,152 @@
+use std::collections::HashMap;
+use crate::app::common::{Interval, IntervalSet, ParseError, Parser};
+
+trait ResourceTryMap {
+    fn try_map(&self, input: usize) -> Option<usize>;
+}
+
+trait ResourceMap {
+    fn map(&self, input: usize) -> usize;
+    fn map_range(&self, numbers: &IntervalSet<isize>) -> IntervalSet<isize>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct AlmanacMapRange {
+    destination_range_start: usize,
+    source_range_start: usize,
+    range_length: usize,
+}
+
+impl AlmanacMapRange {
+    fn source(&self) -> Interval<usize> {
+        Interval::with_length(self.source_range_start, self.range_length)
+    }
+}
+
+impl ResourceTryMap for AlmanacMapRange {
+    fn try_map(&self, input: usize) -> Option<usize> {
+        if self.source().contains(input) {
+            Some(self.destination_range_start + (input - self.source_range_start))
+        } else {
+            None
+        }
+    }
+}
+
+impl AlmanacMapRange {
+    fn parse(parser: &mut Parser) -> Result<AlmanacMapRange, ParseError> {
+        let destination_range_start = parser.unsigned::<usize>()?;
+        parser.literal(" ")?;
+        let source_range_start = parser.unsigned::<usize>()?;
+        parser.literal(" ")?;
+        let range_length = parser.unsigned::<usize>()?;
+
+        Ok(Self {
+            destination_range_start,
+            source_range_start,
+            range_length
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct AlmanacMap(Vec<AlmanacMapRange>);
+
+impl ResourceMap for AlmanacMap {
+    fn map(&self, input: usize) -> usize {
+        for map_range in self.0.iter() {
+            if let Some(output) = map_range.try_map(input) {
+                return output;
+            }
+        }
+
+        input
+    }
+
+    /// Maps every number, where the first range that contains a number maps it and numbers in none stay the same
+    fn map_range(&self, numbers: &IntervalSet<isize>) -> IntervalSet<isize> {
+        let mut unmapped = numbers.clone();
+        let mut mapped = IntervalSet::new();
+
+        for map_range in self.0.iter() {
+            let source = IntervalSet::from(Interval::with_length(map_range.source_range_start as isize, map_range.range_length as isize));
+            let offset = map_range.destination_range_start as isize - map_range.source_range_start as isize;
+
+            mapped = mapped.union(&unmapped.intersection(&source).shift(offset));
+            unmapped = unmapped.difference(&source);
+        }
+
+        mapped.union(&unmapped)
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct AlmanacSection {
+    name: String,
+    map: AlmanacMap,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Resource {
+    pub name: String,
+    pub number: usize
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ResourceRange {
+    pub name: String,
+    pub numbers: IntervalSet<isize>
+}
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct Almanac {
+    converters: HashMap<String, AlmanacSection>,
+}
+
+impl Almanac {
+    pub fn map(&self, resource: &Resource) -> Resource {
+        let map = self.converters.get(&resource.name).unwrap();
+        let name = map.name.clone();
+        let number = map.map.map(resource.number);
+
+        Resource {
+            name,
+            number
+        }
+    }
+
+    pub fn map_range(&self, resource_range: &ResourceRange) -> ResourceRange {
+        let map = self.converters.get(&resource_range.name).unwrap();
+        ResourceRange {
+            name: map.name.clone(),
+            numbers: map.map.map_range(&resource_range.numbers)
+        }
+    }
+}
+
+impl Almanac {
+    /// Sections separated by blank lines, each a line such as `seed-to-soil map:` followed by a range on each line
+    pub fn parse(parser: &mut Parser) -> Result<Almanac, ParseError> {
+        let sections = parser.sections(|parser| {
+            let ((from, to), map) = parser.key_value(
+                |parser| {
+                    let from = parser.word()?;
+                    parser.literal("-to-")?;
+                    let to = parser.word()?;
+                    parser.literal(" map")?;
+                    Ok((from, to))
+                },
+                |parser| {
+                    parser.line_end()?;
+                    parser.lines(AlmanacMapRange::parse)
+                },
+            )?;
+
+            Ok((from.to_string(), AlmanacSection { name: to.to_string(), map: AlmanacMap(map) }))
+        })?;
+
+        Ok(Almanac {
+            converters: sections.into_iter().collect()
+        })
+    }
+}
@@ -0,0 +1,53 @@
+use crate::app::common::Random;
+
+pub const MAX_SIZE: usize = 1000;
+
+const RESOURCES: [&str; 8] = ["seed", "soil", "fertilizer", "water", "light", "temperature", "humidity", "location"];
+const UNIVERSE: usize = 4_000_000_000;
+const SEED_RANGE_COUNT: usize = 10;
+
+/// Generates an almanac whose maps each shuffle `size` ranges of numbers around
+pub fn generate(random: &mut Random, size: usize) -> String {
+    let size = size.max(1);
+
+    let seeds = (0..SEED_RANGE_COUNT)
+        .map(|_| {
+            let start = random.below(UNIVERSE);
+            let length = 1 + random.below((UNIVERSE - start).min(UNIVERSE / 20));
+            format!("{} {}", start, length)
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let maps = RESOURCES.windows(2)
+        .map(|names| format!("{}-to-{} map:\n{}", names[0], names[1], generate_map(random, size)))
+        .collect::<Vec<_>>()
+        .join("\n\n");
+
+    format!("seeds: {}\n\n{}", seeds, maps)
+}
+
+fn generate_map(random: &mut Random, size: usize) -> String {
+    let mut cuts: Vec<usize> = (1..size).map(|_| random.below(UNIVERSE)).collect();
+    cuts.push(0);
+    cuts.push(UNIVERSE);
+    cuts.sort();
+    cuts.dedup();
+
+    let sources: Vec<(usize, usize)> = cuts.windows(2)
+        .map(|cut| (cut[0], cut[1] - cut[0]))
+        .collect();
+    let mut destination_order: Vec<usize> = (0..sources.len()).collect();
+    random.shuffle(&mut destination_order);
+
+    let mut destination_start = 0usize;
+    let mut lines = Vec::with_capacity(sources.len());
+    for index in destination_order {
+        let (source_start, length) = sources[index];
+        lines.push(format!("{} {} {}", destination_start, source_start, length));
+        destination_start += length;
+    }
+
+    random.shuffle(&mut lines);
+    lines.join("\n")
+}
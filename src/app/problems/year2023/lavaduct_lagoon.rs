@@ -0,0 +1,25 @@
+#[cfg(feature = "ssr")]
+mod dig_plan;
+#[cfg(feature = "ssr")]
+mod generator;
+
+#[cfg(feature = "ssr")]
+use crate::app::common::ParseError;
+#[cfg(feature = "ssr")]
+use self::dig_plan::{trench_loop, DigPlan};
+
+use crate::as_server_fn_with_timing;
+
+as_server_fn_with_timing! {
+    fn solve(part: ProblemPart, input: String) -> Result<isize, ParseError> {
+        let dig_plan = input.parse::<DigPlan>()?;
+        let trench = match part {
+            ProblemPart::Part1 => trench_loop(dig_plan.original_steps()),
+            ProblemPart::Part2 => trench_loop(&dig_plan.new_steps())
+        };
+        assert!(trench.find_self_intersection().is_none(), "The trench should not cross itself");
+
+        // The lagoon holds the cubes of the trench itself and the ones it encloses
+        Ok(trench.boundary_count() + trench.interior_count())
+    }
+}
@@ -0,0 +1,117 @@
+use std::ops::Deref;
+use std::str::FromStr;
+use crate::app::common::{Direction, LatticePolygon, ParseError, Parser};
+
+/// Reads the direction of a step, with up as north
+fn read_direction(c: char) -> Option<Direction> {
+    match c {
+        'R' => Some(Direction::East),
+        'D' => Some(Direction::South),
+        'L' => Some(Direction::West),
+        'U' => Some(Direction::North),
+        _ => None
+    }
+}
+
+/// """"Color""""
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Color {
+    direction: Direction,
+    length: isize,
+}
+
+impl Color {
+    /// A length of five hexadecimal digits followed by a direction digit, such as `(#70c710)`
+    fn parse(parser: &mut Parser) -> Result<Color, ParseError> {
+        parser.literal("(#")?;
+        let mut length = 0isize;
+        for _ in 0..5 {
+            length = 16 * length + parser.character("hexadecimal digit", |c| c.to_digit(16))? as isize;
+        }
+        let direction = parser.character("direction digit", |c| match c {
+            '0' => Some(Direction::East),
+            '1' => Some(Direction::South),
+            '2' => Some(Direction::West),
+            '3' => Some(Direction::North),
+            _ => None,
+        })?;
+        parser.literal(")")?;
+
+        Ok(Self {
+            direction,
+            length,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DigPlanStep {
+    pub direction: Direction,
+    pub length: isize,
+    pub color: Color
+}
+
+impl DigPlanStep {
+    fn parse(parser: &mut Parser) -> Result<DigPlanStep, ParseError> {
+        let direction = parser.character("direction", read_direction)?;
+        parser.literal(" ")?;
+        let length = parser.unsigned::<isize>()?;
+        parser.literal(" ")?;
+        let color = Color::parse(parser)?;
+
+        Ok(Self {
+            direction,
+            length,
+            color
+        })
+    }
+}
+
+/// The loop through the middle of the trench dug by the steps, with a lattice point per dug cube
+pub fn trench_loop(steps: &[DigPlanStep]) -> LatticePolygon {
+    let mut corner = (0isize, 0isize);
+    let mut corners: Vec<(isize, isize)> = Vec::new();
+    for step in steps {
+        corners.push(corner);
+        let (dx, dy) = step.direction.offset();
+        corner = (corner.0 + dx * step.length, corner.1 + dy * step.length);
+    }
+    assert_eq!(corner, (0, 0), "The trench should end where it started");
+
+    LatticePolygon::new(corners)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DigPlan(Vec<DigPlanStep>);
+
+impl DigPlan {
+    pub fn original_steps(&self) -> &Vec<DigPlanStep> {
+        &self.0
+    }
+
+    pub fn new_steps(&self) -> Vec<DigPlanStep> {
+        self.0.iter()
+            .map(|x| DigPlanStep {
+                direction: x.color.direction,
+                length: x.color.length,
+                color: x.color
+            })
+            .collect()
+    }
+}
+
+impl Deref for DigPlan {
+    type Target = Vec<DigPlanStep>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl FromStr for DigPlan {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(Parser::parse_all(s, |parser| parser.lines(DigPlanStep::parse))?))
+    }
+}
@@ -0,0 +1,59 @@
+use crate::app::common::{Polyomino, Random};
+
+pub const MAX_SIZE: usize = 200;
+
+/// Generates a dig plan tracing a random lagoon in a `size` by `size` grid of blocks; the colors trace the
+/// same outline with far larger blocks
+pub fn generate(random: &mut Random, size: usize) -> String {
+    let size = size.max(2);
+    let cell_count = 1 + random.below(size * size / 2 + 1);
+    let corners = compress(&Polyomino::random(random, size, size, cell_count).boundary());
+
+    let small_offsets = offsets(random, size + 1, 10);
+    let large_offsets = offsets(random, size + 1, 0xFFFFF / size);
+
+    (0..corners.len())
+        .map(|index| {
+            let (from, to) = (corners[index], corners[(index + 1) % corners.len()]);
+            let (direction, direction_digit) = if to.0 > from.0 {
+                ("R", 0)
+            } else if to.1 > from.1 {
+                ("D", 1)
+            } else if to.0 < from.0 {
+                ("L", 2)
+            } else {
+                ("U", 3)
+            };
+            let length = |offsets: &[usize]| {
+                offsets[from.0].abs_diff(offsets[to.0]) + offsets[from.1].abs_diff(offsets[to.1])
+            };
+
+            format!("{} {} (#{:05x}{})", direction, length(&small_offsets), length(&large_offsets), direction_digit)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Leaves out the corners in the middle of a straight line
+fn compress(boundary: &[(usize, usize)]) -> Vec<(usize, usize)> {
+    (0..boundary.len())
+        .filter(|&index| {
+            let previous = boundary[(index + boundary.len() - 1) % boundary.len()];
+            let next = boundary[(index + 1) % boundary.len()];
+            previous.0 != next.0 && previous.1 != next.1
+        })
+        .map(|index| boundary[index])
+        .collect()
+}
+
+/// Positions of the grid lines, with random distances between them
+fn offsets(random: &mut Random, count: usize, max_distance: usize) -> Vec<usize> {
+    let mut offset = 0usize;
+    (0..count)
+        .map(|_| {
+            let current = offset;
+            offset += 1 + random.below(max_distance);
+            current
+        })
+        .collect()
+}
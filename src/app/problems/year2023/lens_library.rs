@@ -6,17 +6,21 @@ mod lava_instruction;
 mod lens_box;
 #[cfg(feature = "ssr")]
 mod focusing_power;
+#[cfg(feature = "ssr")]
+mod generator;
 
+#[cfg(feature = "ssr")]
+use crate::app::common::{ParseError, Parser};
 #[cfg(feature = "ssr")]
 use self::{focusing_power::FocusingPower, lava_hash::LavaHash, lava_instruction::LavaInstruction, lens_box::LensArray};
 
 use crate::as_server_fn_with_timing;
 
 as_server_fn_with_timing! {
-    fn solve(part: ProblemPart, input: String) -> usize {
-        let instructions = input.split(',')
-            .map(|part| -> LavaInstruction { part.into() });
-        match part {
+    fn solve(part: ProblemPart, input: String) -> Result<usize, ParseError> {
+        let instructions = Parser::parse_all(&input, |parser| parser.separated(",", LavaInstruction::parse))?
+            .into_iter();
+        Ok(match part {
             ProblemPart::Part1 => {
                 instructions
                     .map(|instruction| instruction.get_lava_value())
@@ -25,6 +29,6 @@ as_server_fn_with_timing! {
             ProblemPart::Part2 => {
                 instructions.collect::<LensArray>().get_focusing_power()
             }
-        }
+        })
     }
 }
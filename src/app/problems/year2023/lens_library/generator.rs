@@ -0,0 +1,26 @@
+use crate::app::common::Random;
+
+pub const MAX_SIZE: usize = 100_000;
+
+/// Generates an initialization sequence of `size` steps, reusing labels so lenses get replaced and removed
+pub fn generate(random: &mut Random, size: usize) -> String {
+    let size = size.max(1);
+    let labels: Vec<String> = (0..1 + size / 4)
+        .map(|_| {
+            let length = 2 + random.below(5);
+            random.lowercase_word(length)
+        })
+        .collect();
+
+    (0..size)
+        .map(|_| {
+            let label = random.choose(&labels);
+            if random.chance(1, 3) {
+                format!("{}-", label)
+            } else {
+                format!("{}={}", label, 1 + random.below(9))
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
@@ -1,3 +1,4 @@
+use crate::app::common::{ParseError, Parser};
 use super::lava_hash::{LavaHash, LavaHasher};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -26,24 +27,21 @@ pub struct LavaInstruction<'a> {
     pub action: LavaAction
 }
 
-impl<'a> From<&'a str> for LavaInstruction<'a> {
-    fn from(value: &'a str) -> Self {
-        if value.ends_with('-') {
-            let label = value.strip_suffix("-").unwrap().as_bytes();
-            Self {
-                label,
-                action: LavaAction::Decrement
-            }
+impl<'a> LavaInstruction<'a> {
+    /// A label followed by either `-` or `=` and a focal length
+    pub fn parse(parser: &mut Parser<'a>) -> Result<LavaInstruction<'a>, ParseError> {
+        let label = parser.word()?.as_bytes();
+        let action = if parser.try_literal("-") {
+            LavaAction::Decrement
         } else {
-            let (label, value) = value.split_once('=').unwrap();
-            let label = label.as_bytes();
-            let value = value.parse::<u8>().unwrap();
+            parser.literal("=")?;
+            LavaAction::Set(parser.unsigned::<u8>()?)
+        };
 
-            Self {
-                label,
-                action: LavaAction::Set(value)
-            }
-        }
+        Ok(Self {
+            label,
+            action
+        })
     }
 }
 
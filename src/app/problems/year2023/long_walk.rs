@@ -0,0 +1,32 @@
+#[cfg(feature = "ssr")]
+mod forest_trails;
+#[cfg(feature = "ssr")]
+mod generator;
+
+#[cfg(feature = "ssr")]
+use self::forest_trails::ForestTrails;
+#[cfg(feature = "ssr")]
+use crate::app::common::{DistanceHashGraph, ParseError};
+
+use crate::{as_graph_export_server_fn, as_server_fn_with_timing};
+
+// Format notes:
+// * Vertices are the squares surrounded by slopes, and equivalently the "branch points" in the path
+//   (including start and end points)
+// * There is a unique path between each pair of directly connected vertices, if any
+
+as_server_fn_with_timing! {
+    fn solve(part: ProblemPart, input: String) -> Result<usize, ParseError> {
+        let trails = input.parse::<ForestTrails>()?;
+        let graph = trails.as_graph(part == ProblemPart::Part2);
+
+        Ok(graph.longest_path_between(&trails.starting_vertex(), &trails.ending_vertex()).unwrap().distance)
+    }
+}
+
+as_graph_export_server_fn! {
+    /// The trails as in part 1, where slopes can only be walked down
+    fn graph(input: &str) -> Result<DistanceHashGraph<(usize, usize)>, ParseError> {
+        Ok(input.parse::<ForestTrails>()?.as_graph(false))
+    }
+}
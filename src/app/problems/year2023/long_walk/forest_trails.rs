@@ -0,0 +1,131 @@
+use std::fmt::{Debug, Formatter};
+use std::str::FromStr;
+use crate::app::common::{contract_corridors, CorridorGrid, DistanceHashGraph, Grid, ParseError, Parser};
+use crate::create_formatted_flat_enum;
+
+create_formatted_flat_enum! {
+    ForestTileType error ParseForestTileTypeError with
+    [
+        Forest => "#",
+        Path => ".",
+        SlopeNorth => "^",
+        SlopeEast => ">",
+        SlopeSouth => "v",
+        SlopeWest => "<"
+    ]
+}
+
+impl ForestTileType {
+    fn is_slope(&self) -> bool {
+        *self != ForestTileType::Forest && *self != ForestTileType::Path
+    }
+
+    fn is_passable_to_north(&self, can_climb: bool) -> bool {
+        *self == ForestTileType::Path || if can_climb { self.is_slope() } else {*self == ForestTileType::SlopeNorth }
+    }
+
+    fn is_passable_to_east(&self, can_climb: bool) -> bool {
+        *self == ForestTileType::Path || if can_climb { self.is_slope() } else {*self == ForestTileType::SlopeEast }
+    }
+
+    fn is_passable_to_south(&self, can_climb: bool) -> bool {
+        *self == ForestTileType::Path || if can_climb { self.is_slope() } else { *self == ForestTileType::SlopeSouth }
+    }
+
+    fn is_passable_to_west(&self, can_climb: bool) -> bool {
+        *self == ForestTileType::Path || if can_climb { self.is_slope() } else { *self == ForestTileType::SlopeWest }
+    }
+}
+
+pub struct ForestTrails {
+    tiles: Grid<ForestTileType>,
+    /// The path tile in the first row
+    start: (usize, usize),
+    /// The path tile in the last row
+    end: (usize, usize),
+}
+
+impl ForestTrails {
+    pub fn starting_vertex(&self) -> (usize, usize) {
+        self.start
+    }
+
+    pub fn ending_vertex(&self) -> (usize, usize) {
+        self.end
+    }
+
+    pub fn as_graph(&self, can_climb: bool) -> DistanceHashGraph<(usize, usize)> {
+        contract_corridors(&ForestHike { trails: self, can_climb }, self.starting_vertex())
+    }
+
+    fn tile_type(&self, vertex: (usize, usize)) -> ForestTileType {
+        self.tiles[vertex]
+    }
+}
+
+/// The trails as walked by a hiker who may or may not climb up slopes
+struct ForestHike<'a> {
+    trails: &'a ForestTrails,
+    can_climb: bool,
+}
+
+impl<'a> CorridorGrid for ForestHike<'a> {
+    fn width(&self) -> usize {
+        self.trails.tiles.width()
+    }
+
+    fn height(&self) -> usize {
+        self.trails.tiles.height()
+    }
+
+    fn is_passable(&self, tile: (usize, usize)) -> bool {
+        self.trails.tile_type(tile) != ForestTileType::Forest
+    }
+
+    fn can_step(&self, from: (usize, usize), to: (usize, usize)) -> bool {
+        let tile_type = self.trails.tile_type(to);
+        if to.0 < from.0 {
+            tile_type.is_passable_to_west(self.can_climb)
+        } else if to.1 < from.1 {
+            tile_type.is_passable_to_north(self.can_climb)
+        } else if to.0 > from.0 {
+            tile_type.is_passable_to_east(self.can_climb)
+        } else {
+            tile_type.is_passable_to_south(self.can_climb)
+        }
+    }
+}
+
+impl FromStr for ForestTrails {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Parser::parse_all(s, |parser| {
+            let grid_start = *parser;
+            let tiles = parser.grid(|c| c.to_string().parse::<ForestTileType>().ok())?;
+            let path_in_row = |y: usize, expected: &str| (0..tiles.width())
+                .map(|x| (x, y))
+                .find(|coords| tiles[*coords] == ForestTileType::Path)
+                .ok_or_else(|| grid_start.grid_error((0, y), expected));
+
+            let start = path_in_row(0, "path tile in the first row")?;
+            let end = path_in_row(tiles.height() - 1, "path tile in the last row")?;
+            Ok(ForestTrails { tiles, start, end })
+        })
+    }
+}
+
+impl Debug for ForestTrails {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ForestTrails ({}x{})", self.tiles.width(), self.tiles.height())?;
+
+        for row in self.tiles.rows() {
+            write!(f, "\n")?;
+            for tile in row.iter() {
+                write!(f, "{:?}", tile)?;
+            }
+        }
+
+        Ok(())
+    }
+}
\ No newline at end of file
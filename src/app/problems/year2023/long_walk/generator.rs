@@ -0,0 +1,110 @@
+use std::collections::BTreeSet;
+use crate::app::common::Random;
+
+pub const MAX_SIZE: usize = 100;
+
+/// Generates a map of `size` by `size` junctions, shaped like the puzzle input: slopes surround every junction and
+/// only lead right or down, and at least one path leads from the start to the end
+pub fn generate(random: &mut Random, size: usize) -> String {
+    let size = size.max(2);
+
+    let column_offsets = offsets(random, size, 1);
+    let row_offsets = offsets(random, size, 3);
+    let width = column_offsets[size - 1] + 2;
+    let height = row_offsets[size - 1] + 4;
+
+    // Like in the puzzle input, the start and end corridors pass a slope right next to their junction
+    let mut tiles = vec![vec!['#'; width]; height];
+    tiles[0][1] = '.';
+    tiles[1][1] = '.';
+    tiles[2][1] = 'v';
+    tiles[height - 3][width - 2] = 'v';
+    tiles[height - 2][width - 2] = '.';
+    tiles[height - 1][width - 2] = '.';
+
+    for ((column, row), horizontal) in trails(random, size) {
+        let (x, y) = (column_offsets[column], row_offsets[row]);
+        tiles[y][x] = '.';
+
+        if horizontal {
+            let next_x = column_offsets[column + 1];
+            for corridor_x in x + 1..next_x {
+                tiles[y][corridor_x] = if corridor_x == x + 1 || corridor_x == next_x - 1 { '>' } else { '.' };
+            }
+            tiles[y][next_x] = '.';
+        } else {
+            let next_y = row_offsets[row + 1];
+            for corridor_y in y + 1..next_y {
+                tiles[corridor_y][x] = if corridor_y == y + 1 || corridor_y == next_y - 1 { 'v' } else { '.' };
+            }
+            tiles[next_y][x] = '.';
+        }
+    }
+
+    tiles.into_iter()
+        .map(|row| row.into_iter().collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Positions of the junctions along one axis, at random distances from each other
+fn offsets(random: &mut Random, count: usize, first: usize) -> Vec<usize> {
+    let mut offset = first;
+    (0..count)
+        .map(|_| {
+            let current = offset;
+            offset += 3 + random.below(6);
+            current
+        })
+        .collect()
+}
+
+/// The trails between junctions, as the junction they leave from and whether they lead right rather than down
+fn trails(random: &mut Random, size: usize) -> BTreeSet<((usize, usize), bool)> {
+    let mut trails = BTreeSet::new();
+
+    // A path from the first to the last junction
+    let mut junction = (0usize, 0usize);
+    while junction != (size - 1, size - 1) {
+        let horizontal = junction.1 == size - 1 || (junction.0 < size - 1 && random.chance(1, 2));
+        trails.insert((junction, horizontal));
+        junction = if horizontal { (junction.0 + 1, junction.1) } else { (junction.0, junction.1 + 1) };
+    }
+
+    // A spanning tree so every junction is reachable, then some extra trails
+    let mut candidates: Vec<((usize, usize), bool)> = (0..size)
+        .flat_map(|column| (0..size).map(move |row| (column, row)))
+        .flat_map(|junction| [(junction, true), (junction, false)])
+        .filter(|&((column, row), horizontal)| if horizontal { column < size - 1 } else { row < size - 1 })
+        .collect();
+    random.shuffle(&mut candidates);
+
+    let mut components: Vec<usize> = (0..size * size).collect();
+    let find = |components: &mut Vec<usize>, mut index: usize| {
+        while components[index] != index {
+            components[index] = components[components[index]];
+            index = components[index];
+        }
+        index
+    };
+    for &trail in trails.iter() {
+        let ((column, row), horizontal) = trail;
+        let (next_column, next_row) = if horizontal { (column + 1, row) } else { (column, row + 1) };
+        let (from, to) = (find(&mut components, row * size + column), find(&mut components, next_row * size + next_column));
+        components[from] = to;
+    }
+    for trail in candidates {
+        let ((column, row), horizontal) = trail;
+        let (next_column, next_row) = if horizontal { (column + 1, row) } else { (column, row + 1) };
+        let (from, to) = (find(&mut components, row * size + column), find(&mut components, next_row * size + next_column));
+
+        if from != to {
+            components[from] = to;
+            trails.insert(trail);
+        } else if random.chance(1, 4) {
+            trails.insert(trail);
+        }
+    }
+
+    trails
+}
@@ -1,20 +1,24 @@
 #[cfg(feature = "ssr")]
 mod timeline;
+#[cfg(feature = "ssr")]
+mod generator;
 
+#[cfg(feature = "ssr")]
+use crate::app::common::{ParseError, Parser};
 #[cfg(feature = "ssr")]
 use self::timeline::{InstabilityTimeline, Timeline};
 
 use crate::as_server_fn_with_timing;
 
 as_server_fn_with_timing! {
-    fn solve(part: ProblemPart, input: String) -> isize {
-        let timelines = input.lines()
-            .map(|line| -> Timeline { line.into() })
+    fn solve(part: ProblemPart, input: String) -> Result<isize, ParseError> {
+        let timelines = Parser::parse_all(&input, |parser| parser.lines(Timeline::parse))?
+            .into_iter()
             .map(|timeline| InstabilityTimeline::new(timeline));
 
-        match part {
+        Ok(match part {
             ProblemPart::Part1 => timelines.map(|x| x.extrapolate()).sum(),
             ProblemPart::Part2 => timelines.map(|x| x.extrapolate_backwards()).sum()
-        }
+        })
     }
 }
\ No newline at end of file
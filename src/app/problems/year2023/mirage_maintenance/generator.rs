@@ -0,0 +1,35 @@
+use crate::app::common::Random;
+
+pub const MAX_SIZE: usize = 10_000;
+
+const VALUE_COUNT: i64 = 21;
+const MAX_DEGREE: usize = 5;
+
+/// Generates `size` histories, each the values of a random polynomial
+pub fn generate(random: &mut Random, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            let degree = random.below(MAX_DEGREE + 1);
+            let coefficients: Vec<i64> = (0..=degree).map(|_| random.between(-9..=9)).collect();
+
+            (0..VALUE_COUNT)
+                .map(|x| evaluate(&coefficients, x).to_string())
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Evaluates the polynomial with the given coefficients in the basis of binomial coefficients `x choose k`
+fn evaluate(coefficients: &[i64], x: i64) -> i64 {
+    let mut binomial = 1i64;
+    let mut value = 0i64;
+
+    for (k, coefficient) in coefficients.iter().enumerate() {
+        value += coefficient * binomial;
+        binomial = binomial * (x - k as i64) / (k as i64 + 1);
+    }
+
+    value
+}
@@ -0,0 +1,34 @@
+use crate::app::{common::{ParseError, Parser}, math::FiniteDifferences};
+
+#[derive(Debug, Clone)]
+pub struct Timeline(Vec<isize>);
+
+impl Timeline {
+    pub fn parse(parser: &mut Parser) -> Result<Timeline, ParseError> {
+        Ok(Self(parser.integers::<isize>()?))
+    }
+}
+
+/// A timeline as the polynomial through its values, to extrapolate it in either direction
+#[derive(Debug, Clone)]
+pub struct InstabilityTimeline {
+    length: usize,
+    polynomial: FiniteDifferences<isize>,
+}
+
+impl InstabilityTimeline {
+    pub fn new(timeline: Timeline) -> Self {
+        InstabilityTimeline {
+            length: timeline.0.len(),
+            polynomial: FiniteDifferences::new(&timeline.0),
+        }
+    }
+
+    pub fn extrapolate(&self) -> isize {
+        self.polynomial.evaluate(self.length as isize)
+    }
+
+    pub fn extrapolate_backwards(&self) -> isize {
+        self.polynomial.evaluate(-1)
+    }
+}
@@ -0,0 +1,30 @@
+#[cfg(feature = "ssr")]
+mod hailstone;
+#[cfg(feature = "ssr")]
+mod generator;
+
+#[cfg(feature = "ssr")]
+use crate::app::{common::SolveError, math::{BigInt, Rectangle, Vector2}};
+#[cfg(feature = "ssr")]
+use self::hailstone::HailstoneCollection;
+
+use crate::as_server_fn_with_timing;
+
+as_server_fn_with_timing! {
+    fn solve(part: ProblemPart, input: String) -> Result<BigInt, SolveError> {
+        let hailstones = input.parse::<HailstoneCollection>()?;
+        Ok(match part {
+            ProblemPart::Part1 => {
+                let range_start = 200_000_000_000_000i128;
+                let range_end = 400_000_000_000_000i128;
+                let area = Rectangle {
+                    min: Vector2::new(range_start, range_start),
+                    max: Vector2::new(range_end, range_end),
+                };
+
+                BigInt::from(hailstones.intersection_count_inside(&area))
+            },
+            ProblemPart::Part2 => hailstones.rock_position_sum()?
+        })
+    }
+}
\ No newline at end of file
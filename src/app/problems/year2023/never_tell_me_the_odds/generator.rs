@@ -0,0 +1,43 @@
+use std::collections::HashSet;
+use crate::app::common::Random;
+
+pub const MAX_SIZE: usize = 10_000;
+
+/// Generates `size` hailstones in the test area of part 1, all of which a single rock thrown from an integer
+/// position at an integer velocity hits at distinct integer times, and at least the 5 that part 2 needs to find it
+pub fn generate(random: &mut Random, size: usize) -> String {
+    let size = size.max(5);
+
+    let rock_position: [i64; 3] = [0; 3].map(|_| random.between(250_000_000_000_000..=350_000_000_000_000));
+    let rock_velocity: [i64; 3] = [0; 3].map(|_| random.between(-200..=200));
+
+    let mut times: HashSet<i64> = HashSet::new();
+    (0..size)
+        .map(|_| {
+            let time = loop {
+                let time = random.between(100_000_000_000..=500_000_000_000);
+                if times.insert(time) {
+                    break time;
+                }
+            };
+
+            // Hailstones never stand still in the projection of part 1
+            let relative_velocity: [i64; 3] = [0; 3].map(|_| loop {
+                let velocity = random.between(-100..=100);
+                if velocity != 0 {
+                    break velocity;
+                }
+            });
+
+            let position: Vec<String> = (0..3)
+                .map(|axis| (rock_position[axis] - relative_velocity[axis] * time).to_string())
+                .collect();
+            let velocity: Vec<String> = (0..3)
+                .map(|axis| (rock_velocity[axis] + relative_velocity[axis]).to_string())
+                .collect();
+
+            format!("{} @ {}", position.join(", "), velocity.join(", "))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
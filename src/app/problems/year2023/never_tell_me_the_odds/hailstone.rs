@@ -0,0 +1,215 @@
+use std::str::FromStr;
+use crate::app::{common::{ParseError, Parser, SolveError}, math::{solve_linear_system, BigInt, Intersection, Line2, Rational, Rectangle, Vector3, Zero}};
+
+/// Three integers separated by commas, which may be padded with spaces to line up
+fn parse_coordinates(parser: &mut Parser) -> Result<Vector3<i128>, ParseError> {
+    let start = *parser;
+    let coordinates: [i128; 3] = parser.separated(",", |parser| parser.integer::<i128>())?
+        .try_into().map_err(|_| start.error("three coordinates"))?;
+    Ok(Vector3::from(coordinates))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Hailstone {
+    pub position: Vector3<i128>,
+    pub velocity: Vector3<i128>,
+}
+
+impl Hailstone {
+    /// Whether both hailstones, moving in the plane of the x and y axes, are going to pass the same point inside `area`,
+    /// not necessarily at the same time
+    fn projection_intersects_in(&self, rhs: &Hailstone, area: &Rectangle<i128>) -> bool {
+        let self_path = self.projected_path();
+        let rhs_path = rhs.projected_path();
+        let zero = Rational::zero();
+
+        match self_path.intersection(&rhs_path) {
+            Intersection::None => false,
+            Intersection::Point(crossing) => {
+                crossing.lhs_parameter >= zero && crossing.rhs_parameter >= zero &&
+                    area.map(Rational::from_integer).contains(&crossing.point)
+            },
+            Intersection::Coincident(_) => {
+                // The parameters along the path of this hailstone of the points inside the area that are ahead of both
+                let Some((enter, leave)) = self_path.parameters_inside(area) else {
+                    return false;
+                };
+                let rhs_start = self_path.parameter_of(&rhs_path.origin);
+                let (start, end) = if self_path.direction.dot(&rhs_path.direction) > 0 {
+                    (enter.max(rhs_start).max(zero), leave)
+                } else {
+                    (enter.max(zero), leave.min(rhs_start))
+                };
+                start <= end
+            }
+        }
+    }
+
+    fn projected_path(&self) -> Line2<i128> {
+        Line2 { origin: self.position.xy(), direction: self.velocity.xy() }
+    }
+}
+
+impl Hailstone {
+    /// A position and a velocity separated by `@`, such as `19, 13, 30 @ -2,  1, -2`
+    fn parse(parser: &mut Parser) -> Result<Hailstone, ParseError> {
+        let position = parse_coordinates(parser)?;
+        parser.spaces();
+        parser.literal("@")?;
+        parser.spaces();
+        let velocity = parse_coordinates(parser)?;
+
+        Ok(Self {
+            position,
+            velocity
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct HailstoneCollection(Vec<Hailstone>);
+
+impl HailstoneCollection {
+    pub fn intersection_count_inside(&self, area: &Rectangle<i128>) -> usize {
+        let mut count = 0usize;
+        for i in 0..self.0.len() {
+            for j in 0..i {
+                if self.0[i].projection_intersects_in(&self.0[j], area) {
+                    count += 1;
+                }
+            }
+        }
+
+        count
+    }
+
+    /// The sum of the coordinates of the position a rock has to be thrown from at time 0 to hit every hailstone
+    ///
+    /// In the frame of the first hailstone, the rock passes through the origin, so its position `p` and velocity `v`
+    /// satisfy `(p - p_i) × (v - v_i) = 0` for every other hailstone as well as `p × v = 0`. Subtracting the latter
+    /// leaves equations that are linear in `p` and `v`. Eliminating them can take intermediate values well beyond
+    /// `i128` for hailstones far apart, so this is done in big integers.
+    pub fn rock_position_sum(&self) -> Result<BigInt, SolveError> {
+        // Four hailstones relative to the first for x and y, of which the last two also give z
+        if self.0.len() < 5 {
+            return Err(SolveError::unsupported(format!("{} hailstones are too few to find the rock, it takes 5", self.0.len())));
+        }
+        let unsolvable = || SolveError::unsupported("the hailstones do not determine a single throw of the rock");
+
+        let origin = self.0[0];
+        let relative: Vec<Hailstone> = self.0[1..].iter()
+            .map(|hailstone| Hailstone {
+                position: hailstone.position - origin.position,
+                velocity: hailstone.velocity - origin.velocity,
+            })
+            .collect();
+        let rational = |value: i128| Rational::from_integer(BigInt::from(value));
+
+        // The z coordinate of the cross product only involves x and y
+        let [x, y, velocity_x, _] = relative.windows(4)
+            .find_map(|hailstones| {
+                let matrix = hailstones.iter()
+                    .map(|h| vec![rational(h.velocity.y), rational(-h.velocity.x), rational(-h.position.y), rational(h.position.x)])
+                    .collect();
+                let rhs = hailstones.iter()
+                    .map(|h| rational(h.position.x) * rational(h.velocity.y) - rational(h.position.y) * rational(h.velocity.x))
+                    .collect();
+                solve_linear_system(matrix, rhs)
+            })
+            .ok_or_else(unsolvable)?
+            .try_into().map_err(|_| unsolvable())?;
+
+        // Then the y coordinate of the cross product gives z, with x known
+        let [z, _] = relative.windows(2)
+            .find_map(|hailstones| {
+                let matrix = hailstones.iter()
+                    .map(|h| vec![rational(h.velocity.x), rational(-h.position.x)])
+                    .collect();
+                let rhs = hailstones.iter()
+                    .map(|h| rational(h.position.z) * rational(h.velocity.x) - rational(h.position.x) * rational(h.velocity.z)
+                        + rational(h.velocity.z) * x.clone()
+                        - rational(h.position.z) * velocity_x.clone())
+                    .collect();
+                solve_linear_system(matrix, rhs)
+            })
+            .ok_or_else(unsolvable)?
+            .try_into().map_err(|_| unsolvable())?;
+
+        let sum = (x + y + z).to_integer()
+            .ok_or(SolveError::unsupported("the rock is not thrown from integer coordinates"))?;
+        Ok(sum + BigInt::from(origin.position.x) + BigInt::from(origin.position.y) + BigInt::from(origin.position.z))
+    }
+}
+
+impl FromStr for HailstoneCollection {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(HailstoneCollection(Parser::parse_all(s, |parser| parser.lines(Hailstone::parse))?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::app::math::Vector2;
+    use super::*;
+
+    fn hailstone(position: [i128; 2], velocity: [i128; 2]) -> Hailstone {
+        Hailstone {
+            position: Vector3::new(position[0], position[1], 0),
+            velocity: Vector3::new(velocity[0], velocity[1], 0),
+        }
+    }
+
+    /// Whether the paths meet, checking that it does not depend on the order of the hailstones
+    fn intersects(lhs: Hailstone, rhs: Hailstone) -> bool {
+        let area = Rectangle { min: Vector2::new(0, 0), max: Vector2::new(10, 10) };
+        let intersects = lhs.projection_intersects_in(&rhs, &area);
+        assert_eq!(rhs.projection_intersects_in(&lhs, &area), intersects, "{:?} and {:?}", lhs, rhs);
+        intersects
+    }
+
+    #[test]
+    fn should_cross_ahead_of_both() {
+        assert!(intersects(hailstone([2, 5], [1, 0]), hailstone([5, 2], [0, 1])));
+        // The second hailstone has already passed the crossing
+        assert!(!intersects(hailstone([2, 5], [1, 0]), hailstone([5, 8], [0, 1])));
+        // Parallel paths that never meet
+        assert!(!intersects(hailstone([1, 1], [1, 1]), hailstone([1, 2], [1, 1])));
+    }
+
+    #[test]
+    fn should_meet_on_shared_path_moving_same_way() {
+        assert!(intersects(hailstone([1, 1], [1, 1]), hailstone([3, 3], [2, 2])));
+        // Ahead of both, but in the negative direction
+        assert!(intersects(hailstone([5, 5], [-1, -1]), hailstone([3, 3], [-2, -2])));
+        // Both have left the area
+        assert!(!intersects(hailstone([11, 11], [1, 1]), hailstone([12, 12], [1, 1])));
+        assert!(!intersects(hailstone([-1, -1], [-1, -1]), hailstone([-3, -3], [-1, -1])));
+        // Both are yet to enter the area
+        assert!(intersects(hailstone([-3, 2], [1, 0]), hailstone([-6, 2], [3, 0])));
+    }
+
+    #[test]
+    fn should_meet_on_shared_path_moving_opposite_ways() {
+        // Towards each other, inside the area and partly outside it
+        assert!(intersects(hailstone([1, 1], [1, 1]), hailstone([5, 5], [-1, -1])));
+        assert!(intersects(hailstone([8, 8], [1, 1]), hailstone([15, 15], [-1, -1])));
+        // Towards each other, but only outside the area
+        assert!(!intersects(hailstone([11, 11], [1, 1]), hailstone([15, 15], [-1, -1])));
+        // Away from each other, so they only shared points in the past
+        assert!(!intersects(hailstone([5, 5], [1, 1]), hailstone([1, 1], [-1, -1])));
+        // Right next to each other
+        assert!(intersects(hailstone([4, 0], [0, 1]), hailstone([4, 0], [0, -3])));
+    }
+
+    #[test]
+    fn should_not_find_rock_from_too_few_or_parallel_hailstones() {
+        let too_few = "19, 13, 30 @ -2, 1, -2\n18, 19, 22 @ -1, -1, -2\n20, 25, 34 @ -2, -2, -4".parse::<HailstoneCollection>().unwrap();
+        assert!(matches!(too_few.rock_position_sum(), Err(SolveError::Unsupported(_))));
+
+        // Every hailstone moving the same way leaves every system singular
+        let parallel = (0..5).map(|index| format!("{}, {}, 0 @ 1, 1, 1", index, 2 * index)).collect::<Vec<_>>().join("\n");
+        assert!(matches!(parallel.parse::<HailstoneCollection>().unwrap().rock_position_sum(), Err(SolveError::Unsupported(_))));
+    }
+}
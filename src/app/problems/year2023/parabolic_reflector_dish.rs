@@ -0,0 +1,30 @@
+#[cfg(feature = "ssr")]
+mod platform_space;
+#[cfg(feature = "ssr")]
+mod platform;
+#[cfg(feature = "ssr")]
+mod generator;
+
+#[cfg(feature = "ssr")]
+use crate::app::common::{find_orbit, ParseError};
+#[cfg(feature = "ssr")]
+use self::platform::Platform;
+
+use crate::as_server_fn_with_timing;
+
+as_server_fn_with_timing! {
+    fn solve(part: ProblemPart, input: String) -> Result<usize, ParseError> {
+        let platform = input.parse::<Platform>()?;
+
+        Ok(match part {
+            ProblemPart::Part1 => {
+                platform.get_total_load()
+            },
+            ProblemPart::Part2 => {
+                let orbit = find_orbit(platform, |platform| *platform = platform.cycle());
+
+                orbit.get(1_000_000_000).get_unshifted_load()
+            }
+        })
+    }
+}
@@ -0,0 +1,17 @@
+use crate::app::common::Random;
+
+pub const MAX_SIZE: usize = 500;
+
+/// Generates a `size` by `size` platform of rounded rocks and cube-shaped rocks
+pub fn generate(random: &mut Random, size: usize) -> String {
+    let size = size.max(1);
+
+    (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| *random.choose(&['O', 'O', '#', '.', '.', '.', '.', '.', '.', '.']))
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
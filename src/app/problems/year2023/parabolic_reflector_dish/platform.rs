@@ -0,0 +1,101 @@
+use std::fmt::{Debug, Formatter};
+use std::str::FromStr;
+use crate::app::common::{Direction, Grid, ParseError, Parser};
+use super::platform_space::PlatformSpaceType;
+
+/// Rolls the rounded rocks in the strip as far towards its start as they go
+fn tilt_strip(strip: &mut [PlatformSpaceType]) {
+    let mut free_index = 0usize;
+
+    for i in 0..strip.len() {
+        match strip[i] {
+            PlatformSpaceType::Empty => {},
+            PlatformSpaceType::Round => {
+                strip[i] = PlatformSpaceType::Empty;
+                strip[free_index] = PlatformSpaceType::Round;
+                free_index += 1;
+            },
+            PlatformSpaceType::Cube => {
+                free_index = i + 1;
+            }
+        }
+    }
+}
+
+/// The load of the rounded rocks in the strip on its start, if they were rolled towards it
+fn get_strip_total_load<'a>(strip: impl Iterator<Item = &'a PlatformSpaceType>, length: usize) -> usize {
+    let mut total_load = 0usize;
+    let mut weight = length;
+
+    for (i, space_type) in strip.enumerate() {
+        match space_type {
+            PlatformSpaceType::Empty => {},
+            PlatformSpaceType::Round => {
+                total_load += weight;
+                weight -= 1;
+            },
+            PlatformSpaceType::Cube => {
+                weight = length - i - 1;
+            },
+        }
+    }
+
+    total_load
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Platform(Grid<PlatformSpaceType>);
+
+impl Platform {
+    pub fn get_unshifted_load(&self) -> usize {
+        let height = self.0.height();
+        self.0.rows().enumerate()
+            .map(|(y, row)| (height - y) * row.iter().filter(|space_type| **space_type == PlatformSpaceType::Round).count())
+            .sum()
+    }
+
+    pub fn get_total_load(&self) -> usize {
+        self.0.columns()
+            .map(|column| get_strip_total_load(column, self.0.height()))
+            .sum()
+    }
+
+    pub fn cycle(&self) -> Self {
+        self.tilt(Direction::North)
+            .tilt(Direction::West)
+            .tilt(Direction::South)
+            .tilt(Direction::East)
+    }
+
+    fn tilt(&self, direction: Direction) -> Self {
+        // Rocks roll along the rows when tilting east or west, and along the columns, i.e. the transposed rows, otherwise
+        let along_rows = direction == Direction::East || direction == Direction::West;
+        let mut spaces = if along_rows { self.0.clone() } else { self.0.transpose() };
+
+        for strip in spaces.rows_mut() {
+            if direction == Direction::North || direction == Direction::West {
+                tilt_strip(strip);
+            } else {
+                strip.reverse();
+                tilt_strip(strip);
+                strip.reverse();
+            }
+        }
+
+        Platform(if along_rows { spaces } else { spaces.transpose() })
+    }
+}
+
+impl Debug for Platform {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", self.0.render(|space_type| space_type.into()))
+    }
+}
+
+impl FromStr for Platform {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Platform(Parser::parse_all(s, |parser| parser.grid(|c| PlatformSpaceType::try_from(c).ok()))?))
+    }
+}
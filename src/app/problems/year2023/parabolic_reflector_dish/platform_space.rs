@@ -0,0 +1,29 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PlatformSpaceType {
+    Round,
+    Cube,
+    Empty
+}
+
+impl TryFrom<char> for PlatformSpaceType {
+    type Error = char;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(PlatformSpaceType::Empty),
+            'O' => Ok(PlatformSpaceType::Round),
+            '#' => Ok(PlatformSpaceType::Cube),
+            _ => Err(value)
+        }
+    }
+}
+
+impl From<&PlatformSpaceType> for char {
+    fn from(value: &PlatformSpaceType) -> Self {
+        match value {
+            PlatformSpaceType::Empty => '.',
+            PlatformSpaceType::Round => 'O',
+            PlatformSpaceType::Cube => '#',
+        }
+    }
+}
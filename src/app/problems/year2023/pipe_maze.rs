@@ -0,0 +1,27 @@
+#[cfg(feature="ssr")]
+mod pipe_grid;
+#[cfg(feature="ssr")]
+mod grid_loop;
+#[cfg(feature="ssr")]
+mod pipe;
+#[cfg(feature="ssr")]
+mod generator;
+
+#[cfg(feature="ssr")]
+use crate::app::common::ParseError;
+#[cfg(feature="ssr")]
+use self::{grid_loop::GridLoop, pipe_grid::PipeGrid};
+
+use crate::as_server_fn_with_timing;
+
+as_server_fn_with_timing! {
+    fn solve(part: ProblemPart, input: String) -> Result<usize, ParseError> {
+        let grid = input.parse::<PipeGrid>()?;
+        let grid_loop: GridLoop = grid.loop_iter().collect();
+
+        Ok(match part {
+            ProblemPart::Part1 => grid_loop.diameter(),
+            ProblemPart::Part2 => grid_loop.enclosed_tile_count()
+        })
+    }
+}
@@ -0,0 +1,59 @@
+use crate::app::common::{Polyomino, Random};
+
+pub const MAX_SIZE: usize = 500;
+
+const PIPES: [char; 7] = ['|', '-', 'L', 'J', '7', 'F', '.'];
+
+/// Generates a `size` by `size` field with a single loop through the start, surrounded by junk pipes
+pub fn generate(random: &mut Random, size: usize) -> String {
+    let size = size.max(3);
+
+    let cell_count = 1 + random.below((size - 1) * (size - 1) / 2 + 1);
+    let polyomino = Polyomino::random(random, size - 1, size - 1, cell_count);
+    let boundary = polyomino.boundary();
+
+    let mut field: Vec<Vec<char>> = (0..size)
+        .map(|_| (0..size).map(|_| *random.choose(&PIPES)).collect())
+        .collect();
+
+    for (index, &(x, y)) in boundary.iter().enumerate() {
+        let previous = boundary[(index + boundary.len() - 1) % boundary.len()];
+        let next = boundary[(index + 1) % boundary.len()];
+        field[y][x] = pipe_between((x, y), previous, next);
+    }
+
+    // Junk next to the start must not connect to it, or the shape of the start would be ambiguous
+    let (start_x, start_y) = *random.choose(&boundary);
+    for (dx, dy) in [(0, -1), (1, 0), (0, 1), (-1, 0)] {
+        if let (Some(x), Some(y)) = (start_x.checked_add_signed(dx), start_y.checked_add_signed(dy)) {
+            if x < size && y < size && !boundary.contains(&(x, y)) {
+                field[y][x] = '.';
+            }
+        }
+    }
+    field[start_y][start_x] = 'S';
+
+    field.into_iter()
+        .map(|row| row.into_iter().collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn pipe_between(location: (usize, usize), previous: (usize, usize), next: (usize, usize)) -> char {
+    let direction_to = |other: (usize, usize)| {
+        if other.1 < location.1 { 'N' } else if other.0 > location.0 { 'E' } else if other.1 > location.1 { 'S' } else { 'W' }
+    };
+
+    let mut directions = [direction_to(previous), direction_to(next)];
+    directions.sort();
+
+    match directions {
+        ['N', 'S'] => '|',
+        ['E', 'W'] => '-',
+        ['E', 'N'] => 'L',
+        ['N', 'W'] => 'J',
+        ['S', 'W'] => '7',
+        ['E', 'S'] => 'F',
+        _ => unreachable!("a loop never turns back on itself")
+    }
+}
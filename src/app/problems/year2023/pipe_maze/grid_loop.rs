@@ -0,0 +1,27 @@
+use crate::app::common::LatticePolygon;
+
+/// The tiles of the loop, in order along it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GridLoop(Vec<(usize, usize)>);
+
+impl GridLoop {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn diameter(&self) -> usize {
+        self.len() / 2
+    }
+
+    /// The number of tiles enclosed by the loop, with the centers of the tiles as lattice points
+    pub fn enclosed_tile_count(&self) -> usize {
+        let polygon = LatticePolygon::new(self.0.iter().map(|(x, y)| (*x as isize, *y as isize)).collect());
+        polygon.interior_count() as usize
+    }
+}
+
+impl FromIterator<(usize, usize)> for GridLoop {
+    fn from_iter<T: IntoIterator<Item=(usize, usize)>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
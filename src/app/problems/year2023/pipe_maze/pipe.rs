@@ -0,0 +1,26 @@
+use crate::app::common::Direction;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Pipe{
+    Some([Direction; 2]),
+    None,
+    Start
+}
+
+impl TryFrom<char> for Pipe {
+    type Error = char;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '|' => Ok(Pipe::Some([Direction::North, Direction::South])),
+            '-' => Ok(Pipe::Some([Direction::East, Direction::West])),
+            'L' => Ok(Pipe::Some([Direction::North, Direction::East])),
+            'J' => Ok(Pipe::Some([Direction::North, Direction::West])),
+            '7' => Ok(Pipe::Some([Direction::South, Direction::West])),
+            'F' => Ok(Pipe::Some([Direction::South, Direction::East])),
+            '.' => Ok(Pipe::None),
+            'S' => Ok(Pipe::Start),
+            _ => Err(value)
+        }
+    }
+}
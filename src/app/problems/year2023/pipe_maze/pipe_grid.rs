@@ -0,0 +1,96 @@
+use std::str::FromStr;
+use crate::app::common::{Direction, Grid, ParseError, Parser};
+use super::pipe::Pipe;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct GridHeading {
+    position: (usize, usize),
+    heading: Direction
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PipeGrid {
+    squares: Grid<Option<[Direction; 2]>>,
+    start: (usize, usize),
+}
+
+impl PipeGrid {
+    pub fn loop_iter(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        LoopIter {
+            grid: &self,
+            heading: GridHeading {
+                position: self.start,
+                heading: self.squares[self.start].unwrap()[0],
+            },
+            first: true
+        }
+    }
+
+    fn next_heading(&self, heading: GridHeading) -> GridHeading {
+        let position = self.squares.step(heading.position, heading.heading).unwrap();
+        let headings: Vec<_> = self.squares[position].unwrap().into_iter()
+            .filter(|direction| *direction != heading.heading.opposite())
+            .collect();
+        let heading = headings[0];
+
+        GridHeading {
+            position,
+            heading
+        }
+    }
+}
+
+impl FromStr for PipeGrid {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Parser::parse_all(s, |parser| {
+            let grid_start = *parser;
+            let pipes = parser.grid(|c| Pipe::try_from(c).ok())?;
+            let start = grid_start.grid_position(&pipes, "start tile", |pipe| *pipe == Pipe::Start)?;
+            let mut squares = pipes.map(|pipe| match pipe {
+                Pipe::Some(directions) => Some(*directions),
+                Pipe::None | Pipe::Start => None, // we fix the start below
+            });
+
+            let directions: Vec<_> = Direction::all()
+                .filter(|direction|
+                    squares.step(start, *direction)
+                        .is_some_and(|neighbor|
+                            squares[neighbor].is_some_and(|nds| nds.contains(&direction.opposite())))
+                )
+                .collect();
+            let &[first, second] = directions.as_slice() else {
+                return Err(grid_start.grid_error(start, "start tile connected to exactly two pipes"));
+            };
+            squares[start] = Some([first, second]);
+
+            Ok(PipeGrid {
+                squares,
+                start,
+            })
+        })
+    }
+}
+
+pub struct LoopIter<'a> {
+    grid: &'a PipeGrid,
+    heading: GridHeading,
+    first: bool
+}
+
+impl<'a> Iterator for LoopIter<'a> {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.first && self.heading.position == self.grid.start {
+            return None;
+        }
+        let heading = self.heading;
+
+        self.heading = self.grid.next_heading(self.heading);
+        self.first = false;
+
+        Some(heading.position)
+    }
+}
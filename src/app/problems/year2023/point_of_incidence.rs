@@ -4,18 +4,22 @@ mod mirror;
 mod volcanic_land;
 #[cfg(feature = "ssr")]
 mod volcanic_patch;
+#[cfg(feature = "ssr")]
+mod generator;
 
+#[cfg(feature = "ssr")]
+use crate::app::common::{ParseError, Parser};
 #[cfg(feature = "ssr")]
 use self::volcanic_land::VolcanicLand;
 
 use crate::as_server_fn_with_timing;
 
 as_server_fn_with_timing! {
-    fn solve(part: ProblemPart, input: String) -> usize {
-        let lands = input.split("\n\n")
-            .map(|land_str| -> VolcanicLand { land_str.into() });
+    fn solve(part: ProblemPart, input: String) -> Result<usize, ParseError> {
+        let lands = Parser::parse_all(&input, |parser| parser.sections(VolcanicLand::parse))?
+            .into_iter();
 
-        match part {
+        Ok(match part {
             ProblemPart::Part1 =>
                 lands.map(|land| land.find_mirror())
                     .map(|mirror| mirror.weight())
@@ -24,6 +28,6 @@ as_server_fn_with_timing! {
                 lands.map(|land| land.find_smudged_mirror())
                     .map(|mirror| mirror.weight())
                     .sum(),
-        }
+        })
     }
 }
@@ -0,0 +1,46 @@
+use crate::app::common::Random;
+
+pub const MAX_SIZE: usize = 10_000;
+
+/// Generates `size` patterns, each with a perfect vertical mirror and a horizontal mirror with a single smudge
+pub fn generate(random: &mut Random, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| generate_pattern(random))
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+fn generate_pattern(random: &mut Random) -> String {
+    let width = 5 + random.below(13);
+    let height = 5 + random.below(13);
+
+    // Columns from `unreflected_width` on are mirrored, so smudges in the columns before it keep the mirror perfect
+    let reflected_half = 1 + random.below((width - 1) / 2);
+    let unreflected_width = width - 2 * reflected_half;
+    let mirror_column = unreflected_width + reflected_half;
+
+    let rows_above = 1 + random.below(height - 1);
+    let reflected_rows = rows_above.min(height - rows_above);
+
+    let mut rows: Vec<Vec<char>> = Vec::with_capacity(height);
+    for y in 0..height {
+        if y >= rows_above && y < rows_above + reflected_rows {
+            rows.push(rows[2 * rows_above - 1 - y].clone());
+        } else {
+            let mut row: Vec<char> = (0..mirror_column).map(|_| *random.choose(&['#', '.'])).collect();
+            for x in mirror_column..width {
+                row.push(row[2 * mirror_column - 1 - x]);
+            }
+            rows.push(row);
+        }
+    }
+
+    let smudge_y = rows_above + random.below(reflected_rows);
+    let smudge_x = random.below(unreflected_width);
+    rows[smudge_y][smudge_x] = if rows[smudge_y][smudge_x] == '#' { '.' } else { '#' };
+
+    rows.into_iter()
+        .map(|row| row.into_iter().collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -1,17 +1,10 @@
+use crate::app::common::{ParseError, Parser};
 use super::{mirror::Mirror,volcanic_patch::VolcanicPatchType};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct VolcanicStrip(Vec<VolcanicPatchType>);
 
 impl VolcanicStrip {
-    fn new() -> Self {
-        Self(Vec::new())
-    }
-
-    fn push(&mut self, patch_type: VolcanicPatchType) {
-        self.0.push(patch_type);
-    }
-
     fn difference(&self, other: &VolcanicStrip) -> usize {
         self.0.iter()
             .zip(other.0.iter())
@@ -27,6 +20,17 @@ pub struct VolcanicLand {
 }
 
 impl VolcanicLand {
+    pub fn parse(parser: &mut Parser) -> Result<VolcanicLand, ParseError> {
+        let patches = parser.grid(|c| VolcanicPatchType::try_from(c).ok())?;
+        let horizontal_strips = patches.rows().map(|row| VolcanicStrip(row.to_vec())).collect();
+        let vertical_strips = patches.columns().map(|column| VolcanicStrip(column.copied().collect())).collect();
+
+        Ok(VolcanicLand {
+            horizontal_strips,
+            vertical_strips
+        })
+    }
+
     pub fn find_mirror(&self) -> Mirror {
         Mirror::range(self.get_width(), self.get_height())
             .find(|mirror| self.is_mirror(*mirror))
@@ -81,28 +85,3 @@ impl VolcanicLand {
         self.horizontal_strips.len()
     }
 }
-
-impl From<&str> for VolcanicLand {
-    fn from(value: &str) -> Self {
-        let mut horizontal_strips: Vec<VolcanicStrip> = Vec::new();
-        let mut vertical_strips: Vec<VolcanicStrip> = Vec::new();
-
-        for (y, line) in value.lines().enumerate() {
-            horizontal_strips.push(VolcanicStrip::new());
-
-            for (x, patch_type) in line.chars().map(|c| -> VolcanicPatchType { c.into() }).enumerate() {
-                if y == 0 {
-                    vertical_strips.push(VolcanicStrip::new());
-                }
-
-                horizontal_strips[y].push(patch_type);
-                vertical_strips[x].push(patch_type);
-            }
-        }
-
-        VolcanicLand {
-            horizontal_strips,
-            vertical_strips
-        }
-    }
-}
@@ -0,0 +1,17 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VolcanicPatchType {
+    Ash,
+    Rock
+}
+
+impl TryFrom<char> for VolcanicPatchType {
+    type Error = char;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(VolcanicPatchType::Ash),
+            '#' => Ok(VolcanicPatchType::Rock),
+            _ => Err(value)
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,111 @@
+#[cfg(feature = "ssr")]
+mod module;
+#[cfg(feature = "ssr")]
+mod generator;
+
+#[cfg(feature = "ssr")]
+use std::collections::HashSet;
+#[cfg(feature = "ssr")]
+use self::module::{ModuleCollection, ModuleConfiguration};
+#[cfg(feature = "ssr")]
+use crate::app::{common::{find_cycle, ParseError, Parser, SolveError}, math::lcm_of};
+
+use crate::{as_graph_export_server_fn, as_server_fn_with_timing};
+
+// Format notes:
+// * A single conjunction sends pulses to `rx`, so `rx` gets a low pulse once that conjunction has remembered a high
+//   pulse from each of its inputs
+// * Each of those inputs is fed by its own subcounter: a cycle of modules that the broadcaster starts at a single
+//   module, and that sends a high pulse to the input exactly once every period (which `subcounter_period` checks)
+
+/// The independent subcounters feeding `output`, as the module the broadcaster starts each at and the input of
+/// `output` it feeds
+#[cfg(feature = "ssr")]
+fn subcounters<'a>(configuration: &ModuleConfiguration<'a>, output: &'a str) -> Result<Vec<(&'a str, &'a str)>, SolveError> {
+    let graph = configuration.as_graph();
+    let beyond_output = graph.reachable_from(&output);
+    let counters: Vec<HashSet<&str>> = graph.strongly_connected_components().into_iter()
+        .filter(|component| component.len() > 1 && !beyond_output.contains(component[0]))
+        .map(|component| component.into_iter().collect())
+        .collect();
+
+    configuration.inputs_of(output).iter()
+        .map(|input| {
+            let mut feeding = counters.iter()
+                .filter(|counter| graph.reachable_from(counter.iter().next().unwrap()).contains(input));
+            let counter = match (feeding.next(), feeding.next()) {
+                (Some(counter), None) => counter,
+                _ => return Err(SolveError::unsupported(format!("{} is not fed by a single subcounter", input))),
+            };
+
+            let mut entries = configuration.destinations_of("broadcaster").iter()
+                .filter(|destination| counter.contains(*destination));
+            match (entries.next(), entries.next()) {
+                (Some(entry), None) => Ok((*entry, *input)),
+                _ => Err(SolveError::unsupported(format!("the subcounter feeding {} is not started at a single module", input))),
+            }
+        })
+        .collect()
+}
+
+/// The number of button presses after which the subcounter started at `entry` first sends a high pulse from `input` to
+/// `output`, and after which it keeps sending one every that many presses
+#[cfg(feature = "ssr")]
+fn subcounter_period<'a>(modules: &ModuleCollection<'a>, entry: &'a str, input: &'a str, output: &'a str) -> Result<usize, SolveError> {
+    let cycle = find_cycle(modules.clone(), |modules| {
+        modules.trigger(entry, input, output);
+    });
+
+    // Every press after the tail is like the one a period earlier, so these are all the presses that can differ
+    let mut modules = modules.clone();
+    let high_pulse_presses: Vec<usize> = (1..=cycle.tail_length + cycle.period)
+        .filter(|_| modules.trigger(entry, input, output))
+        .collect();
+
+    match high_pulse_presses[..] {
+        [press] if press == cycle.period && cycle.tail_length < press => Ok(press),
+        _ => Err(SolveError::unsupported(format!("{} does not get a high pulse exactly once every {} presses", input, cycle.period))),
+    }
+}
+
+as_server_fn_with_timing! {
+    fn solve(part: ProblemPart, input: String) -> Result<usize, SolveError> {
+        let configuration = Parser::parse_all(&input, ModuleConfiguration::parse)?;
+
+        Ok(match part {
+            ProblemPart::Part1 => {
+                let mut module_collection = configuration.into_modules();
+                let total = 1000usize;
+                let mut high_pulse_count = 0usize;
+                let mut low_pulse_count = 0usize;
+
+                for _ in 0..total {
+                    let (high, low) = module_collection.click_button();
+                    high_pulse_count += high;
+                    low_pulse_count += low;
+                }
+
+                high_pulse_count * low_pulse_count
+            },
+            ProblemPart::Part2 => {
+                let &[output] = configuration.inputs_of("rx") else {
+                    return Err(SolveError::unsupported("rx does not get pulses from a single module"));
+                };
+                let subcounters = subcounters(&configuration, output)?;
+                let module_collection = configuration.into_modules();
+
+                let periods = subcounters.into_iter()
+                    .map(|(entry, input)| subcounter_period(&module_collection, entry, input, output))
+                    .collect::<Result<Vec<usize>, SolveError>>()?;
+
+                lcm_of(periods).ok_or(SolveError::unsupported("the presses until rx gets a low pulse do not fit a usize"))?
+            }
+        })
+    }
+}
+
+as_graph_export_server_fn! {
+    fn graph(input: &str) -> Result<ModuleConfiguration<'_>, ParseError> {
+        Parser::parse_all(input, ModuleConfiguration::parse)
+    }
+}
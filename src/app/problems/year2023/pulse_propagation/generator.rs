@@ -0,0 +1,60 @@
+use std::collections::HashSet;
+use crate::app::common::Random;
+
+pub const MAX_SIZE: usize = 200;
+
+const COUNTER_COUNT: usize = 4;
+
+/// Generates a configuration of about `size` modules, shaped like the puzzle input: the broadcaster feeds four
+/// binary counters of flip-flops, each of which signals a conjunction feeding `rx` when it reaches its period
+pub fn generate(random: &mut Random, size: usize) -> String {
+    let bit_count = (size.saturating_sub(2) / COUNTER_COUNT).saturating_sub(2).clamp(2, 15);
+    let mut used_names = HashSet::from(["rx".to_string()]);
+    let mut new_name = |random: &mut Random| loop {
+        let name = random.lowercase_word(2);
+        if used_names.insert(name.clone()) {
+            return name;
+        }
+    };
+
+    let output = new_name(random);
+    let mut lines = vec![format!("&{} -> rx", output)];
+    let mut first_flip_flops = Vec::with_capacity(COUNTER_COUNT);
+
+    for _ in 0..COUNTER_COUNT {
+        let flip_flops: Vec<String> = (0..bit_count).map(|_| new_name(random)).collect();
+        let hub = new_name(random);
+        let inverter = new_name(random);
+
+        // The lowest and highest bits are always set, so the period uses all flip-flops
+        let period = (1usize << (bit_count - 1)) | 1 | (random.below(1 << bit_count) & ((1 << (bit_count - 1)) - 1));
+
+        let mut hub_destinations = vec![flip_flops[0].clone()];
+        for (bit, flip_flop) in flip_flops.iter().enumerate() {
+            let mut destinations = Vec::with_capacity(2);
+            if let Some(next) = flip_flops.get(bit + 1) {
+                destinations.push(next.clone());
+            }
+            if period & (1 << bit) != 0 {
+                destinations.push(hub.clone());
+            } else {
+                hub_destinations.push(flip_flop.clone());
+            }
+
+            random.shuffle(&mut destinations);
+            lines.push(format!("%{} -> {}", flip_flop, destinations.join(", ")));
+        }
+
+        hub_destinations.push(inverter.clone());
+        random.shuffle(&mut hub_destinations);
+        lines.push(format!("&{} -> {}", hub, hub_destinations.join(", ")));
+        lines.push(format!("&{} -> {}", inverter, output));
+
+        first_flip_flops.push(flip_flops[0].clone());
+    }
+
+    lines.push(format!("broadcaster -> {}", first_flip_flops.join(", ")));
+    random.shuffle(&mut lines);
+
+    lines.join("\n")
+}
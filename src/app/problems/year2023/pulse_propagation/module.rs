@@ -1,4 +1,5 @@
 use std::collections::{HashMap, VecDeque};
+use crate::app::common::{AdjacencyHashGraph, ExportEdge, ExportNode, GraphExport, ParseError, Parser};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ModuleType {
@@ -15,27 +16,58 @@ pub struct ModuleConfiguration<'a> {
 }
 
 impl<'a> ModuleConfiguration<'a> {
-    pub fn from_str(s: &'a str) -> ModuleConfiguration<'a> {
+    /// A module on each line, such as `%a -> b, c`
+    pub fn parse(parser: &mut Parser<'a>) -> Result<ModuleConfiguration<'a>, ParseError> {
         let mut module_configuration = ModuleConfiguration::new();
-        for line in s.lines() {
-            let (from, destinations) = line.split_once(" -> ").unwrap();
-            let (module_type, from) = if from == "broadcaster" {
-                (ModuleType::Broadcaster, from)
+        let lines = parser.lines(|parser| {
+            let module_type = if parser.try_literal("%") {
+                ModuleType::FlipFlop
+            } else if parser.try_literal("&") {
+                ModuleType::Conjunction
             } else {
-                let (module_type, from) = from.split_at(1);
-                let module_type = match module_type {
-                    "%" => ModuleType::FlipFlop,
-                    "&" => ModuleType::Conjunction,
-                    _ => panic!("Invalid module type \"{module_type}\""),
-                };
-                (module_type, from)
+                ModuleType::Broadcaster
             };
-            let destinations: Vec<_> = destinations.split(", ").collect();
+            let start = *parser;
+            let from = parser.word()?;
+            if module_type == ModuleType::Broadcaster && from != "broadcaster" {
+                return Err(start.error("\"broadcaster\", or a module name after \"%\" or \"&\""));
+            }
+            parser.spaces();
+            parser.literal("->")?;
+            parser.spaces();
+            let destinations = parser.separated(",", |parser| parser.word())?;
 
+            Ok((module_type, from, destinations))
+        })?;
+        for (module_type, from, destinations) in lines {
             module_configuration.insert_line(module_type, from, destinations);
         }
 
-        module_configuration
+        Ok(module_configuration)
+    }
+
+    /// The modules that send pulses to the module
+    pub fn inputs_of(&self, name: &str) -> &[&'a str] {
+        self.adjacency_reverse.get(name).map_or(&[], |inputs| inputs.as_slice())
+    }
+
+    /// The modules the module sends pulses to
+    pub fn destinations_of(&self, name: &str) -> &[&'a str] {
+        self.adjacency.get(name).map_or(&[], |destinations| destinations.as_slice())
+    }
+
+    /// The network as a graph, including the modules that only receive pulses
+    pub fn as_graph(&self) -> AdjacencyHashGraph<&'a str> {
+        let mut graph = AdjacencyHashGraph::new();
+        for (from, destinations) in self.adjacency.iter() {
+            graph.insert_vertex(*from);
+            for to in destinations.iter() {
+                graph.insert_vertex(*to);
+                graph.insert_edge(from, *to);
+            }
+        }
+
+        graph
     }
 
     pub fn into_modules(mut self) -> ModuleCollection<'a> {
@@ -45,7 +77,7 @@ impl<'a> ModuleConfiguration<'a> {
             match self.type_map.get(node).unwrap() {
                 ModuleType::Broadcaster => module_collection.insert_broadcaster(node, destinations),
                 ModuleType::FlipFlop => module_collection.insert_flip_flop(node, destinations),
-                ModuleType::Conjunction => module_collection.insert_conjunction(node, destinations, self.adjacency_reverse.remove(node).unwrap())
+                ModuleType::Conjunction => module_collection.insert_conjunction(node, destinations, self.adjacency_reverse.remove(node).unwrap_or_default())
             }
         }
 
@@ -77,6 +109,37 @@ impl<'a> ModuleConfiguration<'a> {
     }
 }
 
+impl<'a> GraphExport for ModuleConfiguration<'a> {
+    /// Modules are drawn with a shape per type; modules that only receive pulses, like `rx`, keep the default shape
+    fn nodes(&self) -> Vec<ExportNode> {
+        let receivers = self.adjacency_reverse.keys()
+            .filter(|name| !self.type_map.contains_key(*name))
+            .map(|name| ExportNode { id: name.to_string(), shape: None });
+
+        self.type_map.iter()
+            .map(|(name, module_type)| ExportNode {
+                id: name.to_string(),
+                shape: Some(match module_type {
+                    ModuleType::Broadcaster => "doublecircle",
+                    ModuleType::FlipFlop => "box",
+                    ModuleType::Conjunction => "diamond",
+                }),
+            })
+            .chain(receivers)
+            .collect()
+    }
+
+    fn edges(&self) -> Vec<ExportEdge> {
+        self.adjacency.iter()
+            .flat_map(|(from, destinations)| destinations.iter().map(|to| ExportEdge {
+                from: from.to_string(),
+                to: to.to_string(),
+                label: None,
+            }))
+            .collect()
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 enum Pulse<'a> {
     High { from: &'a str, to: &'a str },
@@ -154,36 +217,44 @@ impl<'a> Module<'a> {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ModuleCollection<'a> {
     module_map: HashMap<&'a str, Module<'a>>
 }
 
 impl<'a> ModuleCollection<'a> {
-    pub fn click_button(&mut self, index: usize) -> (usize, usize) {
+    pub fn click_button(&mut self) -> (usize, usize) {
         let mut high_pulse_count = 0usize;
         let mut low_pulse_count = 0usize;
-        let mut pulse_queue: VecDeque<Pulse<'a>> = VecDeque::from([Pulse::Low { from: "button", to: "broadcaster" }]);
+
+        self.propagate(Pulse::Low { from: "button", to: "broadcaster" }, |pulse| match pulse {
+            Pulse::High { .. } => high_pulse_count += 1,
+            Pulse::Low { .. } => low_pulse_count += 1,
+        });
+
+        (high_pulse_count, low_pulse_count)
+    }
+
+    /// Sends a low pulse from the broadcaster to `entry` only, and returns whether `from` sent a high pulse to `to`
+    pub fn trigger(&mut self, entry: &'a str, from: &'a str, to: &'a str) -> bool {
+        let mut is_sent = false;
+
+        self.propagate(Pulse::Low { from: "broadcaster", to: entry }, |pulse| {
+            is_sent |= *pulse == Pulse::High { from, to };
+        });
+
+        is_sent
+    }
+
+    /// Handles the pulse and every pulse it causes, in the order they are sent
+    fn propagate(&mut self, pulse: Pulse<'a>, mut on_pulse: impl FnMut(&Pulse<'a>)) {
+        let mut pulse_queue: VecDeque<Pulse<'a>> = VecDeque::from([pulse]);
 
         while let Some(pulse) = pulse_queue.pop_front() {
+            on_pulse(&pulse);
+
             let pulses = match pulse {
                 Pulse::Low { from, to } => {
-                    low_pulse_count += 1;
-
-                    // Some heavy handed cheating here >_>
-                    if to == "ln" {
-                        leptos::logging::log!("ln hit at click {}", index);
-                    }
-                    if to == "db" {
-                        leptos::logging::log!("db hit at click {}", index);
-                    }
-                    if to == "vq" {
-                        leptos::logging::log!("vq hit at click {}", index);
-                    }
-                    if to == "tf" {
-                        leptos::logging::log!("tf hit at click {}", index);
-                    }
-
                     if let Some(module) = self.module_map.get_mut(to) {
                         module.receive_low_pulse(from)
                     } else {
@@ -191,7 +262,6 @@ impl<'a> ModuleCollection<'a> {
                     }
                 },
                 Pulse::High { from, to } => {
-                    high_pulse_count += 1;
                     if let Some(module) = self.module_map.get_mut(to) {
                         module.receive_high_pulse(from)
                     } else {
@@ -204,8 +274,6 @@ impl<'a> ModuleCollection<'a> {
                 pulse_queue.push_back(pulse);
             }
         }
-
-        (high_pulse_count, low_pulse_count)
     }
 
     fn new() -> ModuleCollection<'a> {
@@ -2,19 +2,23 @@
 mod sand_slab;
 #[cfg(feature = "ssr")]
 mod sand_stack;
+#[cfg(feature = "ssr")]
+mod generator;
 
+#[cfg(feature = "ssr")]
+use crate::app::common::ParseError;
 #[cfg(feature = "ssr")]
 use self::{sand_slab::SandSlabSnapshot, sand_stack::AsSandStack};
 
 use crate::as_server_fn_with_timing;
 
 as_server_fn_with_timing! {
-    fn solve(part: ProblemPart, input: String) -> usize {
-        let stack = input.parse::<SandSlabSnapshot>().unwrap()
+    fn solve(part: ProblemPart, input: String) -> Result<usize, ParseError> {
+        let stack = input.parse::<SandSlabSnapshot>()?
             .as_sand_stack();
-        match part {
+        Ok(match part {
             ProblemPart::Part1 => stack.disintegrateable_slab_count(),
             ProblemPart::Part2 => stack.load_bearing_score()
-        }
+        })
     }
 }
\ No newline at end of file
@@ -0,0 +1,46 @@
+use std::collections::HashSet;
+use crate::app::common::Random;
+
+pub const MAX_SIZE: usize = 10_000;
+
+const FOOTPRINT: usize = 10;
+
+/// Generates a snapshot of `size` slabs falling on a 10 by 10 area, none of which overlap
+pub fn generate(random: &mut Random, size: usize) -> String {
+    let size = size.max(1);
+    let mut occupied: HashSet<(usize, usize, usize)> = HashSet::new();
+
+    (0..size)
+        .map(|_| {
+            let length = random.below(4);
+            let axis = random.below(3);
+            let mut start = (random.below(FOOTPRINT), random.below(FOOTPRINT), 1 + random.below(2 * size));
+            match axis {
+                0 => start.0 = start.0.min(FOOTPRINT - 1 - length),
+                1 => start.1 = start.1.min(FOOTPRINT - 1 - length),
+                _ => {}
+            }
+
+            let cubes_at = |start: (usize, usize, usize)| -> Vec<(usize, usize, usize)> {
+                (0..=length)
+                    .map(|offset| match axis {
+                        0 => (start.0 + offset, start.1, start.2),
+                        1 => (start.0, start.1 + offset, start.2),
+                        _ => (start.0, start.1, start.2 + offset),
+                    })
+                    .collect()
+            };
+
+            while cubes_at(start).iter().any(|cube| occupied.contains(cube)) {
+                start.2 += 1;
+            }
+
+            let cubes = cubes_at(start);
+            let end = *cubes.last().unwrap();
+            occupied.extend(cubes);
+
+            format!("{},{},{}~{},{},{}", start.0, start.1, start.2, end.0, end.1, end.2)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
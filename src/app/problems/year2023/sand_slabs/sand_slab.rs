@@ -0,0 +1,111 @@
+use std::collections::BTreeMap;
+use std::fmt::{Debug, Formatter};
+use std::ops::Deref;
+use std::str::FromStr;
+use crate::app::{common::{ParseError, Parser}, math::{Cuboid, Vector3}};
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SandSlab(Cuboid<usize>);
+
+impl SandSlab {
+    /// The lowest level the slab takes up
+    pub fn bottom(&self) -> usize {
+        self.0.min.z
+    }
+
+    /// The highest level the slab takes up
+    pub fn top(&self) -> usize {
+        self.0.max.z
+    }
+
+    pub fn infinite() -> SandSlab {
+        SandSlab(Cuboid {
+            min: Vector3::new(0, 0, 0),
+            max: Vector3::new(usize::MAX, usize::MAX, 0)
+        })
+    }
+
+    /// Whether one of the slabs is above the other, seen from above
+    pub fn overlaps(&self, rhs: &SandSlab) -> bool {
+        self.0.footprint().overlaps(&rhs.0.footprint())
+    }
+
+    /// Two corners of a slab, such as `1,0,1~1,2,1`
+    pub fn parse(parser: &mut Parser) -> Result<SandSlab, ParseError> {
+        let start = parse_corner(parser)?;
+        parser.literal("~")?;
+        let end = parse_corner(parser)?;
+
+        Ok(SandSlab(Cuboid::from_corners(start, end)))
+    }
+
+    pub fn with_z_start(&self, z_start: usize) -> SandSlab {
+        SandSlab(self.0.moved_to(Vector3 { z: z_start, ..self.0.min }))
+    }
+}
+
+fn parse_corner(parser: &mut Parser) -> Result<Vector3<usize>, ParseError> {
+    let x = parser.unsigned::<usize>()?;
+    parser.literal(",")?;
+    let y = parser.unsigned::<usize>()?;
+    parser.literal(",")?;
+    let z = parser.unsigned::<usize>()?;
+
+    Ok(Vector3::new(x, y, z))
+}
+
+impl Debug for SandSlab {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Cuboid { min, max } = self.0;
+        let [x, y, z] = [(min.x, max.x), (min.y, max.y), (min.z, max.z)].map(|(min, max)| {
+            if min == max { min.to_string() } else { format!("{}~{}", min, max) }
+        });
+        write!(f, "[{},{},{}]", x, y, z)
+    }
+}
+
+pub struct SandSlabSnapshot{
+    slab_bottoms: BTreeMap<usize, Vec<SandSlab>>,
+    height: usize
+}
+
+impl SandSlabSnapshot {
+    pub fn height(&self) -> usize {
+        self.height
+    }
+}
+
+impl Deref for SandSlabSnapshot {
+    type Target = BTreeMap<usize, Vec<SandSlab>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.slab_bottoms
+    }
+}
+
+impl FromStr for SandSlabSnapshot {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut slab_bottoms: BTreeMap<usize, Vec<SandSlab>> = BTreeMap::new();
+        let mut height = 0usize;
+
+        for slab in Parser::parse_all(s, |parser| parser.lines(SandSlab::parse))? {
+
+            if slab.top() > height {
+                height = slab.top();
+            }
+
+            if let Some(slabs) = slab_bottoms.get_mut(&slab.bottom()) {
+                slabs.push(slab);
+            } else {
+                slab_bottoms.insert(slab.bottom(), vec![slab]);
+            }
+        }
+
+        Ok(SandSlabSnapshot{
+            slab_bottoms,
+            height
+        })
+    }
+}
@@ -1,4 +1,5 @@
-use std::collections::{BTreeMap, HashMap, HashSet};
+use std::collections::HashMap;
+use crate::app::common::AdjacencyHashGraph;
 use super::sand_slab::{SandSlab, SandSlabSnapshot};
 
 pub trait AsSandStack {
@@ -34,35 +35,43 @@ impl SandStack {
             .count()
     }
 
+    /// The number of slabs that would fall, summed over every slab that could be disintegrated
+    ///
+    /// A slab falls exactly when a slab that dominates it in the support graph is disintegrated, so this counts the
+    /// dominators of every slab, other than the floor.
     pub fn load_bearing_score(&self) -> usize {
-        self.supporting.keys()
-            .map(|s| self.load_on(s))
-            .sum()
+        let floor = SandSlab::infinite();
+        let graph = self.as_graph(floor);
+        let dominators = graph.dominators(&floor);
+
+        // A dominator always comes before the slabs it dominates, so its depth is known by then
+        let mut depths: HashMap<SandSlab, usize> = HashMap::from([(floor, 0)]);
+        for slab in graph.topological_order().unwrap() {
+            if let Some(dominator) = dominators.get(&slab) {
+                depths.insert(slab, depths[dominator] + 1);
+            }
+        }
+
+        depths.values().map(|depth| depth.saturating_sub(1)).sum()
     }
 
-    fn load_on(&self, slab: &SandSlab) -> usize {
-        let mut load = 0usize;
-        let mut slabs: BTreeMap<usize, HashSet<SandSlab>> = BTreeMap::from([(slab.z.end, HashSet::from([*slab]))]);
-
-        while let Some((_, supporting)) = slabs.pop_first() {
-            let supporting = &supporting;
-            let supported_slabs: HashSet<_> = supporting.iter()
-                .flat_map(|s| self.supporting.get(&s).unwrap().iter())
-                .collect();
-
-            for supported_slab in supported_slabs {
-                if self.supported_by.get(supported_slab).unwrap().iter().all(|s| supporting.contains(s)) {
-                    load += 1;
-                    if let Some(set) = slabs.get_mut(&supported_slab.z.end) {
-                        set.insert(*supported_slab);
-                    } else {
-                        slabs.insert(supported_slab.z.end, HashSet::from([*supported_slab]));
-                    }
-                }
+    /// The support relation as a graph, with edges from each slab to the slabs resting on it, starting at `floor`
+    fn as_graph(&self, floor: SandSlab) -> AdjacencyHashGraph<SandSlab> {
+        let mut graph = AdjacencyHashGraph::new();
+        graph.insert_vertex(floor);
+
+        for (slab, supporting) in self.supporting.iter() {
+            graph.insert_vertex(*slab);
+            if !self.supported_by.contains_key(slab) {
+                graph.insert_edge(&floor, *slab);
+            }
+            for supported_slab in supporting.iter() {
+                graph.insert_vertex(*supported_slab);
+                graph.insert_edge(slab, *supported_slab);
             }
         }
 
-        load
+        graph
     }
 }
 
@@ -84,7 +93,7 @@ struct SandStackBuilder {
 impl SandStackBuilder {
     fn with_capacity(slab_count: usize, max_height: usize) -> SandStackBuilder {
         // Allocate and fill up front
-        let mut slab_tops = vec![vec![]; max_height];
+        let mut slab_tops = vec![vec![]; max_height + 1];
         slab_tops[0].push(SandSlab::infinite());
 
         SandStackBuilder {
@@ -119,6 +128,6 @@ impl SandStackBuilder {
             self.supported_by.insert(slab, supported_by);
         }
 
-        self.slab_tops[slab.z.end].push(slab);
+        self.slab_tops[slab.top()].push(slab);
     }
 }
@@ -1,26 +1,30 @@
 #[cfg(feature = "ssr")]
 mod cards;
+#[cfg(feature = "ssr")]
+mod generator;
 
 #[cfg(feature = "ssr")]
 use self::cards::{AsScratchcardCopy, Scratchcard};
+#[cfg(feature = "ssr")]
+use crate::app::common::{ParseError, Parser};
 
 use crate::as_server_fn_with_timing;
 
 as_server_fn_with_timing! {
-    fn solve(part: ProblemPart, input: String) -> usize {
-        match part {
+    fn solve(part: ProblemPart, input: String) -> Result<usize, ParseError> {
+        let cards = Parser::parse_all(&input, |parser| parser.lines(Scratchcard::parse))?;
+
+        Ok(match part {
             ProblemPart::Part1 => {
-                input.lines()
-                    .map(|x| -> Scratchcard { x.into() })
+                cards.iter()
                     .map(|x| x.value())
                     .sum()
             },
             ProblemPart::Part2 => {
-                input.lines()
-                    .map(|x| -> Scratchcard { x.into() })
+                cards.into_iter()
                     .process_copies()
                     .sum()
             }
-        }
+        })
     }
 }
@@ -1,4 +1,5 @@
 use std::collections::{HashSet, VecDeque};
+use crate::app::common::{ParseError, Parser};
 
 #[derive(Clone)]
 pub struct Scratchcard {
@@ -14,6 +15,24 @@ impl Scratchcard {
         }
     }
 
+    /// A card on a line, such as `Card 1: 41 48 83 | 83 86  6`
+    pub fn parse(parser: &mut Parser) -> Result<Scratchcard, ParseError> {
+        let (_, winning_numbers) = parser.key_value(
+            |parser| {
+                parser.literal("Card")?;
+                parser.spaces();
+                parser.unsigned::<usize>()
+            },
+            |parser| parser.spaced(|parser| parser.unsigned::<usize>()),
+        )?;
+        parser.spaces();
+        parser.literal("|")?;
+        parser.spaces();
+        let own_numbers = parser.spaced(|parser| parser.unsigned::<usize>())?;
+
+        Ok(Scratchcard::new(winning_numbers.into_iter().collect(), own_numbers))
+    }
+
     pub fn value(&self) -> usize {
         let numbers = self.own_numbers.iter()
             .filter(|own_number| self.winning_numbers.contains(own_number))
@@ -33,53 +52,6 @@ impl Scratchcard {
     }
 }
 
-impl From<&str> for Scratchcard {
-    fn from(value: &str) -> Self {
-        let (_, card_def) = value.split_once(": ").unwrap();
-        let (winning_numbers_def, own_numbers_def) = card_def.split_once(" | ").unwrap();
-        let winning_numbers = winning_numbers_def.chars().card_values().collect::<HashSet<usize>>();
-        let own_numbers = own_numbers_def.chars().card_values().collect::<Vec<usize>>();
-
-        Scratchcard::new(winning_numbers, own_numbers)
-    }
-}
-
-struct CardValueIterator<T: Iterator<Item = char>> {
-    iter: T
-}
-
-impl<T: Iterator<Item = char>> Iterator for CardValueIterator<T> {
-    type Item = usize;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        if let Some(first) = self.iter.next() {
-            let mut combined = String::with_capacity(2);
-            if first != ' ' {
-                combined.push(first);
-            }
-
-            let second = self.iter.next().unwrap();
-            combined.push(second);
-
-            self.iter.next();
-
-            Some(combined.parse::<usize>().unwrap())
-        } else {
-            None
-        }
-    }
-}
-
-trait AsCardValues<T: Iterator<Item = char>> {
-    fn card_values(self) -> CardValueIterator<T>;
-}
-
-impl<T: Iterator<Item = char>> AsCardValues<T> for T {
-    fn card_values(self) -> CardValueIterator<T> {
-        CardValueIterator { iter: self }
-    }
-}
-
 pub struct ScratchcardCopyIterator<T: Iterator<Item = Scratchcard>> {
     iter: T,
     extra_copies: VecDeque<usize>,
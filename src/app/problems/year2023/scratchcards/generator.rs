@@ -0,0 +1,44 @@
+use crate::app::common::Random;
+
+pub const MAX_SIZE: usize = 10_000;
+
+const WINNING_COUNT: usize = 5;
+const OWN_COUNT: usize = 8;
+
+/// Generates `size` scratchcards, which never win copies of cards past the end of the table
+pub fn generate(random: &mut Random, size: usize) -> String {
+    let size = size.max(1);
+
+    (1..=size)
+        .map(|card_id| {
+            let mut numbers: Vec<usize> = (1..100).collect();
+            random.shuffle(&mut numbers);
+
+            // Mostly losing cards, so the number of copies does not explode
+            let matching_count = if random.chance(3, 4) { 0 } else { 1 + random.below(4) };
+            let matching_count = matching_count.min(size - card_id);
+
+            let winning_numbers = &numbers[..WINNING_COUNT];
+            let mut own_numbers: Vec<usize> = winning_numbers[..matching_count].iter()
+                .chain(numbers[WINNING_COUNT..WINNING_COUNT + OWN_COUNT - matching_count].iter())
+                .copied()
+                .collect();
+            random.shuffle(&mut own_numbers);
+
+            format!(
+                "Card {:>4}: {} | {}",
+                card_id,
+                format_numbers(winning_numbers),
+                format_numbers(&own_numbers)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn format_numbers(numbers: &[usize]) -> String {
+    numbers.iter()
+        .map(|number| format!("{:>2}", number))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
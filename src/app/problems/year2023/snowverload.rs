@@ -0,0 +1,27 @@
+#[cfg(feature = "ssr")]
+mod component_config;
+#[cfg(feature = "ssr")]
+mod generator;
+
+#[cfg(feature = "ssr")]
+use self::component_config::{SnowComponent, SnowComponentConfig};
+#[cfg(feature = "ssr")]
+use crate::app::common::{AdjacencyHashGraph, ParseError, Parser};
+
+use crate::{as_graph_export_server_fn, as_server_fn_with_timing};
+
+as_server_fn_with_timing! {
+    fn solve(_part: ProblemPart, input: String) -> Result<usize, ParseError> {
+        let config = Parser::parse_all(&input, SnowComponentConfig::parse)?;
+        let cut = config.as_graph().minimum_cut().unwrap();
+        let (left, right) = cut.partition_sizes;
+
+        Ok(left * right)
+    }
+}
+
+as_graph_export_server_fn! {
+    fn graph(input: &str) -> Result<AdjacencyHashGraph<SnowComponent<'_>>, ParseError> {
+        Ok(Parser::parse_all(input, SnowComponentConfig::parse)?.as_graph())
+    }
+}
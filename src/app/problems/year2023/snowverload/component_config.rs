@@ -1,5 +1,5 @@
 use std::fmt::{Debug, Formatter};
-use crate::app::common::AdjacencyHashGraph;
+use crate::app::common::{AdjacencyHashGraph, ParseError, Parser};
 
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub struct SnowComponent<'a>(&'a str);
@@ -14,24 +14,16 @@ impl<'a> Debug for SnowComponent<'a> {
 pub struct SnowComponentConfig<'a>(Vec<(SnowComponent<'a>, Vec<SnowComponent<'a>>)>);
 
 impl<'a> SnowComponentConfig<'a> {
-    pub fn from_str(s: &'a str) -> SnowComponentConfig<'a> {
-        SnowComponentConfig(s.lines()
-            .map(|line| {
-                let (first, adjacent) = line.split_once(": ").unwrap();
-                let first = SnowComponent(first);
-                let adjacent: Vec<_> = adjacent.split(' ').map(|c| SnowComponent(c)).collect();
-
-                (first, adjacent)
-            })
-            .collect())
-    }
-
-    pub fn edges(&self) -> Vec<(&SnowComponent<'a>, &SnowComponent<'a>)> {
-        self.0.iter()
-            .flat_map(|(from, adjacent)| {
-                adjacent.iter().map(move |to| (from, to))
-            })
-            .collect()
+    /// A component and the ones it is connected to on each line, such as `jqt: rhn xhk nvd`
+    pub fn parse(parser: &mut Parser<'a>) -> Result<SnowComponentConfig<'a>, ParseError> {
+        let lines = parser.lines(|parser| {
+            parser.key_value(
+                |parser| parser.word().map(SnowComponent),
+                |parser| parser.spaced(|parser| parser.word().map(SnowComponent)),
+            )
+        })?;
+
+        Ok(SnowComponentConfig(lines))
     }
 
     pub fn as_graph(&self) -> AdjacencyHashGraph<SnowComponent<'a>> {
@@ -0,0 +1,71 @@
+use std::collections::HashSet;
+use crate::app::common::Random;
+
+pub const MAX_SIZE: usize = 2000;
+
+const MIN_DEGREE: usize = 4;
+
+/// Generates `size` components in two well connected groups, with exactly three wires between the groups
+pub fn generate(random: &mut Random, size: usize) -> String {
+    let size = size.max(2 * (MIN_DEGREE + 1));
+
+    let mut used_names: HashSet<String> = HashSet::new();
+    let names: Vec<String> = (0..size)
+        .map(|_| loop {
+            let name = random.lowercase_word(3);
+            if used_names.insert(name.clone()) {
+                break name;
+            }
+        })
+        .collect();
+
+    let left_size = size / 2;
+    let mut wires = Vec::new();
+    wires.extend(generate_group(random, 0..left_size));
+    wires.extend(generate_group(random, left_size..size));
+    for _ in 0..3 {
+        loop {
+            let wire = (random.below(left_size), left_size + random.below(size - left_size));
+            if !wires.contains(&wire) {
+                wires.push(wire);
+                break;
+            }
+        }
+    }
+
+    // Each wire is listed once, on the line of either of its components
+    let mut adjacent: Vec<Vec<usize>> = vec![Vec::new(); size];
+    for (from, to) in wires {
+        if random.chance(1, 2) {
+            adjacent[from].push(to);
+        } else {
+            adjacent[to].push(from);
+        }
+    }
+
+    let mut lines: Vec<String> = adjacent.iter().enumerate()
+        .filter(|(_, adjacent)| !adjacent.is_empty())
+        .map(|(component, adjacent)| {
+            let adjacent: Vec<&str> = adjacent.iter().map(|&other| names[other].as_str()).collect();
+            format!("{}: {}", names[component], adjacent.join(" "))
+        })
+        .collect();
+    random.shuffle(&mut lines);
+
+    lines.join("\n")
+}
+
+/// Wires connecting the group so that cutting fewer than four wires never splits it: the first components are
+/// all wired together, and every later one is wired to four earlier ones
+fn generate_group(random: &mut Random, components: std::ops::Range<usize>) -> Vec<(usize, usize)> {
+    let start = components.start;
+    let mut wires = Vec::new();
+
+    for component in components {
+        let mut earlier: Vec<usize> = (start..component).collect();
+        random.shuffle(&mut earlier);
+        wires.extend(earlier.into_iter().take(MIN_DEGREE).map(|other| (other, component)));
+    }
+
+    wires
+}
@@ -0,0 +1,107 @@
+#[cfg(feature = "ssr")]
+mod garden;
+#[cfg(feature = "ssr")]
+mod parallel_universe_walker;
+#[cfg(feature = "ssr")]
+mod generator;
+
+#[cfg(feature = "ssr")]
+use crate::app::{common::ParseError, math::{lagrange_interpolate, BigInt, Rational}};
+#[cfg(feature = "ssr")]
+use self::{garden::Garden, parallel_universe_walker::AsParallelUniverseWalker};
+
+use crate::as_server_fn_with_timing;
+
+/// The number of plots reachable in exactly `step_count` steps on the garden repeated infinitely in every direction
+///
+/// Assumes what seems to hold for the actual inputs:
+/// * the garden is square, and the start is in the dead center
+/// * the outer columns and rows are empty
+/// * the center column and row are empty
+/// * rocks are "sufficiently sparse" (no visitable squares are left behind after traveling around the edges)
+///
+/// Then every garden walked across adds another ring of gardens reached in the same way, so the number of plots is a
+/// quadratic polynomial in the number of gardens walked across, and three samples determine it.
+#[cfg(feature = "ssr")]
+fn count_tiled_plots(garden: &Garden, step_count: usize) -> BigInt {
+    let edge_length = garden.width();
+    let sample_step_counts: Vec<usize> = (0..3).map(|gardens| step_count % edge_length + gardens * edge_length).collect();
+    let distances = garden.tiled_distances(*sample_step_counts.last().unwrap());
+
+    let rational = |value: usize| Rational::from_integer(BigInt::from(value));
+    let samples: Vec<_> = sample_step_counts.iter()
+        .map(|&sample_step_count| {
+            let plot_count = distances.values()
+                .filter(|distance| **distance <= sample_step_count && **distance % 2 == sample_step_count % 2)
+                .count();
+            (rational(sample_step_count), rational(plot_count))
+        })
+        .collect();
+
+    lagrange_interpolate(&samples, rational(step_count)).to_integer().unwrap()
+}
+
+as_server_fn_with_timing! {
+    fn solve(part: ProblemPart, input: String) -> Result<BigInt, ParseError> {
+        let garden = input.parse::<Garden>()?;
+
+        Ok(match part {
+            ProblemPart::Part1 => {
+                let walker = garden.as_parallel_universe_walker();
+
+                BigInt::from(walker.possible_step_count_after(64))
+            },
+            ProblemPart::Part2 => count_tiled_plots(&garden, 26_501_365)
+        })
+    }
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+    use std::collections::HashSet;
+    use crate::app::common::{check_property, Random};
+    use super::*;
+
+    /// Walks the infinitely repeated garden one step at a time
+    fn reference_tiled_plots(input: &str, step_count: usize) -> usize {
+        let tiles: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+        let edge_length = tiles.len() as i64;
+        let start = (edge_length / 2, edge_length / 2);
+        let is_rock = |(x, y): (i64, i64)| tiles[y.rem_euclid(edge_length) as usize][x.rem_euclid(edge_length) as usize] == '#';
+
+        let mut reached = HashSet::from([start]);
+        for _ in 0..step_count {
+            reached = reached.iter()
+                .flat_map(|&(x, y)| [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)])
+                .filter(|plot| !is_rock(*plot))
+                .collect();
+        }
+
+        reached.len()
+    }
+
+    /// Any number of steps past the edge of a garden, with the walk crossing a few more gardens before that
+    fn step_count(input: &str, remainder: usize, gardens_away: usize) -> usize {
+        let edge_length = input.lines().count();
+        remainder % edge_length + gardens_away * edge_length
+    }
+
+    #[test]
+    fn should_match_reference_tiled_plots() {
+        check_property(
+            20,
+            |random: &mut Random, size| {
+                let input = generator::generate(random, 4 * (size / 2) + 7);
+                let remainder = random.below(input.lines().count());
+                (input, remainder, 2 + random.below(3))
+            },
+            |(input, remainder, gardens_away)| if *gardens_away > 2 { vec![(input.clone(), *remainder, gardens_away - 1)] } else { vec![] },
+            |(input, remainder, gardens_away)| {
+                let garden = input.parse::<Garden>().unwrap();
+                let step_count = step_count(input, *remainder, *gardens_away);
+                let (actual, expected) = (count_tiled_plots(&garden, step_count), reference_tiled_plots(input, step_count));
+                if actual == BigInt::from(expected) { Ok(()) } else { Err(format!("got {}, expected {} after {} steps", actual, expected, step_count)) }
+            },
+        );
+    }
+}
@@ -0,0 +1,99 @@
+use std::str::FromStr;
+use crate::app::common::{shortest_distances_in, DenseMap, Grid, GridIndex, ImplicitGraph, ParseError, Parser, WrappingGrid};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum GardenTile {
+    Plot,
+    Rock,
+    Start,
+}
+
+impl TryFrom<char> for GardenTile {
+    type Error = char;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(GardenTile::Plot),
+            '#' => Ok(GardenTile::Rock),
+            'S' => Ok(GardenTile::Start),
+            _ => Err(value)
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Garden {
+    tiles: Grid<GardenTile>,
+    start: (usize, usize),
+}
+
+impl Garden {
+    pub fn width(&self) -> usize {
+        self.tiles.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.tiles.height()
+    }
+
+    pub fn start(&self) -> (usize, usize) {
+        self.start
+    }
+
+    /// The number of steps needed to reach each plot that can be reached in at most `max_steps` steps, on the garden
+    /// repeated infinitely in every direction
+    ///
+    /// The distances are kept densely in the square around the start that holds every plot within `max_steps` of it.
+    pub fn tiled_distances(&self, max_steps: usize) -> DenseMap<(isize, isize), usize, GridIndex> {
+        let (x, y) = (self.start.0 as isize, self.start.1 as isize);
+        let reach = max_steps as isize;
+        let index = GridIndex::with_origin((x - reach, y - reach), 2 * max_steps + 1, 2 * max_steps + 1);
+        shortest_distances_in(&TiledGarden(self.tiles.wrapping()), (x, y), Some(max_steps), DenseMap::new(index))
+    }
+
+    pub fn adjacent_vertices(&self, coords: (usize, usize)) -> Vec<(usize, usize)>{
+        self.tiles.neighbors(coords)
+            .filter(|neighbor| self.tiles[*neighbor] != GardenTile::Rock)
+            .collect()
+    }
+}
+
+impl ImplicitGraph for Garden {
+    type Vertex = (usize, usize);
+
+    fn neighbors(&self, coords: &(usize, usize)) -> Vec<(usize, (usize, usize))> {
+        self.adjacent_vertices(*coords).into_iter().map(|coords| (1, coords)).collect()
+    }
+}
+
+/// The garden repeated infinitely in every direction, with the copies beyond its edges at negative or large coordinates
+struct TiledGarden<'a>(WrappingGrid<'a, GardenTile>);
+
+impl<'a> ImplicitGraph for TiledGarden<'a> {
+    type Vertex = (isize, isize);
+
+    fn neighbors(&self, &(x, y): &(isize, isize)) -> Vec<(usize, (isize, isize))> {
+        [(x - 1, y), (x, y - 1), (x + 1, y), (x, y + 1)].into_iter()
+            .filter(|plot| self.0[*plot] != GardenTile::Rock)
+            .map(|plot| (1, plot))
+            .collect()
+    }
+}
+
+impl FromStr for Garden {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (tiles, start) = Parser::parse_all(s, |parser| {
+            let grid_start = *parser;
+            let tiles = parser.grid(|c| GardenTile::try_from(c).ok())?;
+            let start = grid_start.grid_position(&tiles, "start tile", |tile| *tile == GardenTile::Start)?;
+            Ok((tiles, start))
+        })?;
+
+        Ok(Self {
+            tiles,
+            start
+        })
+    }
+}
@@ -0,0 +1,29 @@
+use crate::app::common::Random;
+
+pub const MAX_SIZE: usize = 1001;
+
+/// Generates a garden of `size` by `size` plots (rounded up to an odd size), shaped like the puzzle input: the start
+/// is in the center, and its row, its column and the edges are free of rocks
+pub fn generate(random: &mut Random, size: usize) -> String {
+    let size = size.max(5) | 1;
+    let center = size / 2;
+
+    (0..size)
+        .map(|y| {
+            (0..size)
+                .map(|x| {
+                    if x == center && y == center {
+                        'S'
+                    } else if x == center || y == center || x == 0 || y == 0 || x == size - 1 || y == size - 1 {
+                        '.'
+                    } else if random.chance(1, 6) {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
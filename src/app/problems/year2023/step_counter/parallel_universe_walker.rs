@@ -0,0 +1,67 @@
+use crate::app::common::{shortest_distances_in, DenseMap, GridIndex};
+use super::garden::Garden;
+
+pub trait AsParallelUniverseWalker {
+    fn as_parallel_universe_walker(&self) -> ParallelUniverseWalker<'_>;
+    fn as_parallel_universe_walker_from(&self, start: (usize, usize)) -> ParallelUniverseWalker<'_>;
+}
+
+impl AsParallelUniverseWalker for Garden {
+    fn as_parallel_universe_walker(&self) -> ParallelUniverseWalker<'_> {
+        self.as_parallel_universe_walker_from(self.start())
+    }
+
+    fn as_parallel_universe_walker_from(&self, start: (usize, usize)) -> ParallelUniverseWalker<'_> {
+        ParallelUniverseWalker {
+            garden: self,
+            start,
+        }
+    }
+}
+
+pub struct ParallelUniverseWalker<'a> {
+    garden: &'a Garden,
+    start: (usize, usize),
+}
+
+impl<'a> ParallelUniverseWalker<'a> {
+    pub fn possible_step_count_after(self, count: usize) -> usize {
+        self.plots(Some(count)).values()
+            .filter(|d| (**d % 2) == (count % 2))
+            .count()
+    }
+
+    /// The number of steps needed to reach each plot that can be reached in at most `max_steps` steps
+    fn plots(&self, max_steps: Option<usize>) -> DenseMap<(usize, usize), usize, GridIndex> {
+        let index = GridIndex::new(self.garden.width(), self.garden.height());
+        shortest_distances_in(self.garden, self.start, max_steps, DenseMap::new(index))
+    }
+}
+
+#[cfg(test)]
+mod benches {
+    extern crate test;
+
+    use test::Bencher;
+    use crate::app::common::{shortest_distances, Random};
+    use super::{super::generator, *};
+
+    /// A garden the size of the puzzle input
+    fn garden() -> Garden {
+        generator::generate(&mut Random::new(17), 131).parse().unwrap()
+    }
+
+    #[bench]
+    fn bench_hash_map_plots(bencher: &mut Bencher) {
+        let garden = garden();
+
+        bencher.iter(|| shortest_distances(&garden, garden.start(), Some(64)).len());
+    }
+
+    #[bench]
+    fn bench_dense_map_plots(bencher: &mut Bencher) {
+        let garden = garden();
+
+        bencher.iter(|| garden.as_parallel_universe_walker().plots(Some(64)).len());
+    }
+}
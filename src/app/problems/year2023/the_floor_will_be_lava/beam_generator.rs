@@ -1,4 +1,5 @@
 use std::collections::HashSet;
+use crate::app::common::{DenseSet, GridIndex};
 use super::{beams::BeamSegment, contraption::{BarrierType, Contraption}};
 
 #[derive(Debug, Clone)]
@@ -16,7 +17,7 @@ impl<'a> BeamCollection<'a> {
     }
 
     pub fn energized_tile_count(&self) -> usize {
-        let mut nodes: HashSet<(usize, usize)> = HashSet::with_capacity(self.beam_segments.len());
+        let mut nodes: DenseSet<(usize, usize), GridIndex> = DenseSet::new(GridIndex::new(self.contraption.width, self.contraption.height));
         let mut processed_segments: Vec<BeamSegment> = Vec::with_capacity(self.beam_segments.len());
         let mut internal_length = 0usize;
         let mut intersection_count = 0usize;
@@ -1,5 +1,7 @@
 use std::collections::BTreeMap;
 use std::ops::Bound::{Excluded, Unbounded};
+use std::str::FromStr;
+use crate::app::common::{ParseError, Parser};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum BarrierType {
@@ -74,40 +76,31 @@ impl Contraption {
     }
 }
 
-impl From<&str> for Contraption {
-    fn from(value: &str) -> Self {
-        let mut width = 0usize;
-        let mut height = 0usize;
-        let mut rows: Vec<BTreeMap<usize, BarrierNode>> = Vec::new();
-        let mut columns: Vec<BTreeMap<usize, BarrierNode>> = Vec::new();
-
-        for (y, line) in value.lines().enumerate() {
-            height += 1;
-            rows.push(BTreeMap::new());
-
-            for (x, c) in line.chars().enumerate() {
-                if y == 0 {
-                    width += 1;
-                    columns.push(BTreeMap::new());
-                }
-
-                if let Some(barrier_type) = BarrierType::from(c) {
-                    let barrier_node = BarrierNode {
-                        barrier_type,
-                        x,
-                        y,
-                    };
-                    columns[x].insert(y, barrier_node);
-                    rows[y].insert(x, barrier_node);
-                }
+impl FromStr for Contraption {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tiles = Parser::parse_all(s, |parser| parser.grid(|c| if c == '.' { Some(None) } else { BarrierType::from(c).map(Some) }))?;
+        let mut rows: Vec<BTreeMap<usize, BarrierNode>> = vec![BTreeMap::new(); tiles.height()];
+        let mut columns: Vec<BTreeMap<usize, BarrierNode>> = vec![BTreeMap::new(); tiles.width()];
+
+        for (x, y) in tiles.coords() {
+            if let Some(barrier_type) = tiles[(x, y)] {
+                let barrier_node = BarrierNode {
+                    barrier_type,
+                    x,
+                    y,
+                };
+                columns[x].insert(y, barrier_node);
+                rows[y].insert(x, barrier_node);
             }
         }
 
-        Self {
-            width,
-            height,
+        Ok(Self {
+            width: tiles.width(),
+            height: tiles.height(),
             rows,
             columns
-        }
+        })
     }
 }
@@ -0,0 +1,17 @@
+use crate::app::common::Random;
+
+pub const MAX_SIZE: usize = 500;
+
+/// Generates a `size` by `size` contraption of mirrors and splitters
+pub fn generate(random: &mut Random, size: usize) -> String {
+    let size = size.max(1);
+
+    (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| if random.chance(1, 6) { *random.choose(&['/', '\\', '|', '-']) } else { '.' })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,40 @@
+#[cfg(feature = "ssr")]
+mod trebuchet_state;
+#[cfg(feature = "ssr")]
+mod generator;
+
+#[cfg(feature = "ssr")]
+use crate::app::common::{ParseError, Parser};
+#[cfg(feature = "ssr")]
+use trebuchet_state::read_line_value;
+
+use crate::as_server_fn_with_timing;
+
+/// The first and last digit of the line as a two digit number, if it has a digit
+#[cfg(feature = "ssr")]
+fn read_plain_line_value(line: &str) -> Option<usize> {
+    let first_digit = line.chars().find_map(|c| c.to_digit(10))?;
+    let last_digit = line.chars().rev().find_map(|c| c.to_digit(10))?;
+
+    Some((first_digit * 10 + last_digit) as usize)
+}
+
+/// The sum of the values `line_value` reads from the lines of letters and digits
+#[cfg(feature = "ssr")]
+fn calibration_sum(input: &str, line_value: fn(&str) -> Option<usize>) -> Result<usize, ParseError> {
+    let values = Parser::parse_all(input, |parser| parser.lines(|parser| {
+        let start = *parser;
+        line_value(parser.word()?).ok_or_else(|| start.error("line with a digit"))
+    }))?;
+
+    Ok(values.into_iter().sum())
+}
+
+as_server_fn_with_timing! {
+    fn solve(part: ProblemPart, input: String) -> Result<usize, ParseError> {
+        match part {
+            ProblemPart::Part1 => calibration_sum(&input, read_plain_line_value),
+            ProblemPart::Part2 => calibration_sum(&input, read_line_value)
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use crate::app::common::Random;
+
+pub const MAX_SIZE: usize = 10_000;
+
+const SPELLED_DIGITS: [&str; 9] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+/// Generates `size` lines mixing letters, digits and spelled out digits, each with at least one digit
+pub fn generate(random: &mut Random, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| generate_line(random))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn generate_line(random: &mut Random) -> String {
+    let token_count = 2 + random.below(6);
+    let digit_index = random.below(token_count);
+
+    (0..token_count)
+        .map(|index| {
+            if index == digit_index || random.chance(1, 4) {
+                (1 + random.below(9)).to_string()
+            } else if random.chance(1, 3) {
+                random.choose(&SPELLED_DIGITS).to_string()
+            } else {
+                let length = 1 + random.below(4);
+                random.lowercase_word(length)
+            }
+        })
+        .collect()
+}
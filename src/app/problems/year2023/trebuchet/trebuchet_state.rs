@@ -114,7 +114,8 @@ impl TrebuchetState {
     }
 }
 
-pub fn read_line_value(s: &str) -> usize {
+/// The first and last digit of the line, spelled out or not, as a two digit number, if it has a digit
+pub fn read_line_value(s: &str) -> Option<usize> {
     let mut first_value: Option<usize> = None;
     let mut last_value: Option<usize> = None;
     let mut state = TrebuchetState::Empty;
@@ -134,5 +135,5 @@ pub fn read_line_value(s: &str) -> usize {
         }
     }
 
-    first_value.unwrap() * 10 + last_value.unwrap()
+    Some(first_value? * 10 + last_value?)
 }
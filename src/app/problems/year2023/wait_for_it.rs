@@ -0,0 +1,27 @@
+#[cfg(feature = "ssr")]
+mod race;
+#[cfg(feature = "ssr")]
+mod generator;
+
+#[cfg(feature = "ssr")]
+use crate::app::{common::ParseError, math::{sum_of_products, BigInt}};
+#[cfg(feature = "ssr")]
+use self::race::{LongRace, RaceProgram};
+
+use crate::as_server_fn_with_timing;
+
+as_server_fn_with_timing! {
+    fn solve(part: ProblemPart, input: String) -> Result<BigInt, ParseError> {
+        Ok(match part {
+            ProblemPart::Part1 => {
+                let race_program = input.parse::<RaceProgram>()?;
+                let leniencies: Vec<usize> = race_program.iter().map(|race| race.get_leniency()).collect();
+                sum_of_products([leniencies])
+            },
+            ProblemPart::Part2 => {
+                let race = input.parse::<LongRace>()?;
+                race.get_leniency()
+            }
+        })
+    }
+}
@@ -0,0 +1,26 @@
+use crate::app::common::Random;
+
+/// Part 1 multiplies the races and part 2 reads them as a single one, so the answers grow quickly with the size
+pub const MAX_SIZE: usize = 100;
+
+/// Generates `size` races, each of which can be won
+pub fn generate(random: &mut Random, size: usize) -> String {
+    let races: Vec<(String, String)> = (0..size.max(1))
+        .map(|_| {
+            let time = 7 + random.below(93);
+            let record = time * time / 4;
+            let distance = record / 2 + random.below(record - record / 2);
+            (time.to_string(), distance.to_string())
+        })
+        .collect();
+
+    let mut time_line = "Time:     ".to_string();
+    let mut distance_line = "Distance: ".to_string();
+    for (time, distance) in races {
+        let width = time.len().max(distance.len());
+        time_line.push_str(&format!(" {:>width$}", time, width = width + 1));
+        distance_line.push_str(&format!(" {:>width$}", distance, width = width + 1));
+    }
+
+    format!("{}\n{}", time_line, distance_line)
+}
@@ -0,0 +1,207 @@
+use std::ops::Deref;
+use std::str::FromStr;
+use crate::app::common::{ParseError, Parser};
+use crate::app::math::{BigInt, One, Zero};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Race {
+    time: usize,
+    distance: usize
+}
+
+impl Race {
+    /// Goes through big integers when the square of the time is too large for the floating point shortcut
+    pub fn get_leniency(&self) -> usize {
+        if self.is_precise() {
+            return self.float_leniency();
+        }
+
+        // Only holds strictly between 0 and the time can win, so there are fewer than the time
+        let long_race = LongRace { time: BigInt::from(self.time), distance: BigInt::from(self.distance) };
+        long_race.exact_leniency().to_usize().expect("leniency is less than the time")
+    }
+
+    /// Only exact if the race `is_precise`
+    fn float_leniency(&self) -> usize {
+        if 4 * self.distance >= self.time * self.time {
+            0
+        } else {
+            let is_odd = self.time % 2;
+            let discriminant: f64 = 0.25 * (self.time as f64) * (self.time as f64) - (self.distance as f64);
+            let root = discriminant.sqrt() - 1.0 + 0.5 * (is_odd as f64);
+            2 * (root.ceil() as usize) + 1 - is_odd
+        }
+    }
+
+    /// Whether the square of the time fits in a `usize` as well as exactly in an `f64`, so the leniency is exact
+    fn is_precise(&self) -> bool {
+        self.time.checked_mul(self.time).is_some_and(|square| square < 1 << f64::MANTISSA_DIGITS) && self.distance.checked_mul(4).is_some()
+    }
+}
+
+/// A race read as a single one, whose time and distance may be too long for a `usize`
+#[derive(Debug, Clone)]
+pub struct LongRace {
+    time: BigInt,
+    distance: BigInt,
+}
+
+impl LongRace {
+    pub fn get_leniency(&self) -> BigInt {
+        match self.time.to_usize().zip(self.distance.to_usize()) {
+            Some((time, distance)) => BigInt::from(Race { time, distance }.get_leniency()),
+            None => self.exact_leniency()
+        }
+    }
+
+    /// Counts the ways to hold the button for `hold` with `(2 * hold - time)^2 < time^2 - 4 * distance`
+    fn exact_leniency(&self) -> BigInt {
+        let discriminant = &(&self.time * &self.time) - &(&BigInt::from(4usize) * &self.distance);
+        if discriminant <= BigInt::zero() {
+            return BigInt::zero();
+        }
+
+        // The largest allowed `|2 * hold - time|`, which only counts if it has the same parity as the time
+        let root = discriminant.sqrt();
+        let bound = if &root * &root == discriminant { root - BigInt::one() } else { root };
+        let two = BigInt::from(2usize);
+        if &bound % &two == &self.time % &two {
+            bound + BigInt::one()
+        } else {
+            bound
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RaceProgram(Vec<Race>);
+
+impl Deref for RaceProgram {
+    type Target = Vec<Race>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// The times and the distances on two lines, each as the numbers parsed by `number`
+fn parse_sheet<'a, T>(parser: &mut Parser<'a>, mut number: impl FnMut(&mut Parser<'a>) -> Result<T, ParseError>) -> Result<(Vec<T>, Vec<T>), ParseError> {
+    let times = parser.labelled("Time", |parser| parser.spaced(&mut number))?;
+    parser.line_end()?;
+    let distance_start = *parser;
+    let distances = parser.labelled("Distance", |parser| parser.spaced(&mut number))?;
+    if distances.len() != times.len() {
+        return Err(distance_start.error(format!("{} distances", times.len())));
+    }
+
+    Ok((times, distances))
+}
+
+/// Numbers separated by spaces, read as the single number of all their digits
+fn parse_kerned_number(parser: &mut Parser) -> Result<BigInt, ParseError> {
+    let start = *parser;
+    let digits = parser.spaced(|parser| parser.unsigned::<String>())?.concat();
+
+    digits.parse::<BigInt>().map_err(|_| start.error("number"))
+}
+
+impl FromStr for RaceProgram {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (times, distances) = Parser::parse_all(s, |parser| parse_sheet(parser, |parser| parser.unsigned::<usize>()))?;
+
+        Ok(RaceProgram(times.into_iter().zip(distances).map(|(time, distance)| Race { time, distance }).collect()))
+    }
+}
+
+impl FromStr for LongRace {
+    type Err = ParseError;
+
+    /// Reads the numbers on each line as one, ignoring the spaces between them
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Parser::parse_all(s, |parser| {
+            let time = parser.labelled("Time", parse_kerned_number)?;
+            parser.line_end()?;
+            let distance = parser.labelled("Distance", parse_kerned_number)?;
+
+            Ok(LongRace {
+                time,
+                distance
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::app::common::{check_property, shrink_number};
+    use super::*;
+
+    /// Tries every way to hold the button
+    fn reference_leniency(race: &Race) -> usize {
+        (0..=race.time)
+            .filter(|hold| hold * (race.time - hold) > race.distance)
+            .count()
+    }
+
+    fn shrink(race: &Race) -> Vec<Race> {
+        let shorter = shrink_number(race.time).into_iter()
+            .map(|time| Race { time, distance: race.distance });
+        let closer = shrink_number(race.distance).into_iter()
+            .map(|distance| Race { time: race.time, distance });
+
+        shorter.chain(closer).collect()
+    }
+
+    #[test]
+    fn should_match_reference_leniency() {
+        check_property(
+            1000,
+            |random, size| {
+                let time = random.below(10usize.pow(size as u32 / 2 + 1));
+                // Mostly near the best distance, where the rounding matters
+                let best = (time / 2) * (time - time / 2);
+                let distance = best - random.below(best.min(1000) + 1);
+                Race { time, distance }
+            },
+            shrink,
+            |race| {
+                let (actual, expected) = (race.get_leniency(), reference_leniency(race));
+                let long_race = LongRace { time: BigInt::from(race.time), distance: BigInt::from(race.distance) };
+                if actual != expected {
+                    Err(format!("got {}, expected {}", actual, expected))
+                } else if long_race.exact_leniency() != BigInt::from(expected) {
+                    Err(format!("got {} exactly, expected {}", long_race.exact_leniency(), expected))
+                } else {
+                    Ok(())
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn should_find_leniency_of_races_too_long_for_floats() {
+        let race = Race { time: usize::MAX, distance: usize::MAX / 2 };
+        let long_race = LongRace { time: BigInt::from(race.time), distance: BigInt::from(race.distance) };
+
+        assert_eq!(BigInt::from(race.get_leniency()), long_race.exact_leniency());
+    }
+
+    #[test]
+    fn should_find_leniency_beyond_usize() {
+        let race = "Time:      100000000000000000000000000000\nDistance:  2499999999999999999999999999999999999999999999999999999999".parse::<LongRace>().unwrap();
+
+        // Holding for half the time only just beats the record
+        assert_eq!(race.get_leniency(), BigInt::one());
+    }
+
+    #[test]
+    fn should_join_digits_of_long_race() {
+        let race = "Time:      7  15   30\nDistance:  9  40  200\n".parse::<LongRace>().unwrap();
+        assert_eq!(race.get_leniency(), BigInt::from(71503usize));
+
+        let error = "Time:  7 x\nDistance: 9".parse::<LongRace>().unwrap_err();
+        assert_eq!((error.line, error.column), (1, 9));
+    }
+}
@@ -1,9 +1,12 @@
+#![cfg_attr(test, feature(test))]
+
 extern crate core;
 
 use cfg_if::cfg_if;
 pub mod app;
 pub mod error_template;
 pub mod fileserv;
+pub mod server;
 
 cfg_if! { if #[cfg(feature = "hydrate")] {
     use leptos::*;
@@ -1,11 +1,14 @@
 #[cfg(feature = "ssr")]
 #[tokio::main]
 async fn main() {
-    use axum::{routing::post, Router};
     use leptos::*;
-    use leptos_axum::{generate_route_list, LeptosRoutes};
-    use aoc2023::app::*;
-    use aoc2023::fileserv::file_and_error_handler;
+    use aoc2023::server::router;
+
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.first().is_some_and(|command| command == "generate") {
+        generate(&args[1..]);
+        return;
+    }
 
     simple_logger::init_with_level(log::Level::Debug).expect("couldn't initialize logging");
 
@@ -17,14 +20,9 @@ async fn main() {
     let conf = get_configuration(None).await.unwrap();
     let leptos_options = conf.leptos_options;
     let addr = leptos_options.site_addr;
-    let routes = generate_route_list(App);
 
     // build our application with a route
-    let app = Router::new()
-        .route("/api/*fn_name", post(leptos_axum::handle_server_fns))
-        .leptos_routes(&leptos_options, routes, App)
-        .fallback(file_and_error_handler)
-        .with_state(leptos_options);
+    let app = router(leptos_options);
 
     // run our app with hyper
     // `axum::Server` is a re-export of `hyper::Server`
@@ -35,6 +33,42 @@ async fn main() {
         .unwrap();
 }
 
+/// Prints a generated input: `aoc2023 generate <year> <slug> [--seed <seed>] [--size <size>]`
+#[cfg(feature = "ssr")]
+fn generate(args: &[String]) {
+    use aoc2023::app::YEARS;
+
+    const USAGE: &str = "usage: aoc2023 generate <year> <slug> [--seed <seed>] [--size <size>]";
+
+    let [year, slug, options @ ..] = args else { exit_with(USAGE) };
+    let mut seed = 1u64;
+    let mut size = 10usize;
+    for option in options.chunks(2) {
+        match option {
+            [name, value] if name == "--seed" => seed = value.parse().unwrap_or_else(|_| exit_with(USAGE)),
+            [name, value] if name == "--size" => size = value.parse().unwrap_or_else(|_| exit_with(USAGE)),
+            _ => exit_with(USAGE),
+        }
+    }
+
+    let problem = YEARS.iter()
+        .filter(|problem_year| problem_year.year.to_string() == *year)
+        .flat_map(|problem_year| problem_year.problems.iter())
+        .find(|problem| problem.slug == *slug)
+        .unwrap_or_else(|| exit_with(&format!("no problem {} in {}", slug, year)));
+
+    match (problem.generate)(seed, size) {
+        Ok(input) => println!("{}", input),
+        Err(error) => exit_with(&error),
+    }
+}
+
+#[cfg(feature = "ssr")]
+fn exit_with(message: &str) -> ! {
+    eprintln!("{}", message);
+    std::process::exit(1)
+}
+
 #[cfg(not(feature = "ssr"))]
 pub fn main() {
     // no client-side main function
@@ -0,0 +1,20 @@
+use cfg_if::cfg_if;
+
+cfg_if! { if #[cfg(feature = "ssr")] {
+    use axum::{routing::post, Router};
+    use leptos::*;
+    use leptos_axum::{generate_route_list, LeptosRoutes};
+    use crate::app::App;
+    use crate::fileserv::file_and_error_handler;
+
+    /// Builds the router serving the app, its server functions and its static files
+    pub fn router(leptos_options: LeptosOptions) -> Router {
+        let routes = generate_route_list(App);
+
+        Router::new()
+            .route("/api/*fn_name", post(leptos_axum::handle_server_fns))
+            .leptos_routes(&leptos_options, routes, App)
+            .fallback(file_and_error_handler)
+            .with_state(leptos_options)
+    }
+}}
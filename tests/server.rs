@@ -0,0 +1,332 @@
+#![cfg(feature = "ssr")]
+
+use aoc2023::app::{ProblemInfo, YEARS};
+use aoc2023::server::router;
+use axum::{
+    body::Body,
+    http::{header, Request, StatusCode},
+    Router,
+};
+use leptos::LeptosOptions;
+use serde_json::Value;
+use tower::ServiceExt;
+
+/// An example input of a problem, with its expected solution
+struct Example {
+    slug: &'static str,
+    part: &'static str,
+    input: &'static str,
+    solution: &'static str,
+}
+
+macro_rules! example {
+    ($slug:literal, $part:literal, $file:literal, $solution:literal) => {
+        Example {
+            slug: $slug,
+            part: $part,
+            input: include_str!(concat!("examples/2023/", $file)),
+            solution: $solution,
+        }
+    };
+}
+
+/// Mostly the examples from the problem statements. Part 2 of pulse propagation needs an `rx` module, and part 2 of
+/// step counter only works on actual inputs, so those are left out.
+const EXAMPLES: &[Example] = &[
+    example!("trebuchet", "Part1", "trebuchet.txt", "142"),
+    example!("trebuchet", "Part2", "trebuchet_part2.txt", "281"),
+    example!("cube_conundrum", "Part1", "cube_conundrum.txt", "8"),
+    example!("cube_conundrum", "Part2", "cube_conundrum.txt", "2286"),
+    example!("gear_ratios", "Part1", "gear_ratios.txt", "4361"),
+    example!("gear_ratios", "Part2", "gear_ratios.txt", "467835"),
+    example!("scratchcards", "Part1", "scratchcards.txt", "13"),
+    example!("scratchcards", "Part2", "scratchcards.txt", "30"),
+    example!("if_you_give_seed_fertilizer", "Part1", "if_you_give_seed_fertilizer.txt", "35"),
+    example!("if_you_give_seed_fertilizer", "Part2", "if_you_give_seed_fertilizer.txt", "46"),
+    example!("wait_for_it", "Part1", "wait_for_it.txt", "288"),
+    example!("wait_for_it", "Part2", "wait_for_it.txt", "71503"),
+    example!("camel_cards", "Part1", "camel_cards.txt", "6440"),
+    example!("camel_cards", "Part2", "camel_cards.txt", "5905"),
+    example!("haunted_wasteland", "Part1", "haunted_wasteland.txt", "2"),
+    example!("haunted_wasteland", "Part2", "haunted_wasteland_part2.txt", "6"),
+    example!("mirage_maintenance", "Part1", "mirage_maintenance.txt", "114"),
+    example!("mirage_maintenance", "Part2", "mirage_maintenance.txt", "2"),
+    example!("pipe_maze", "Part1", "pipe_maze.txt", "8"),
+    example!("pipe_maze", "Part2", "pipe_maze_part2.txt", "4"),
+    example!("cosmic_expansion", "Part1", "cosmic_expansion.txt", "374"),
+    example!("cosmic_expansion", "Part2", "cosmic_expansion.txt", "82000210"),
+    example!("hot_springs", "Part1", "hot_springs.txt", "21"),
+    example!("hot_springs", "Part2", "hot_springs.txt", "525152"),
+    example!("point_of_incidence", "Part1", "point_of_incidence.txt", "405"),
+    example!("point_of_incidence", "Part2", "point_of_incidence.txt", "400"),
+    example!("parabolic_reflector_dish", "Part1", "parabolic_reflector_dish.txt", "136"),
+    example!("parabolic_reflector_dish", "Part2", "parabolic_reflector_dish.txt", "64"),
+    example!("lens_library", "Part1", "lens_library.txt", "1320"),
+    example!("lens_library", "Part2", "lens_library.txt", "145"),
+    example!("the_floor_will_be_lava", "Part1", "the_floor_will_be_lava.txt", "46"),
+    example!("the_floor_will_be_lava", "Part2", "the_floor_will_be_lava.txt", "51"),
+    example!("clumsy_crucible", "Part1", "clumsy_crucible.txt", "102"),
+    example!("clumsy_crucible", "Part2", "clumsy_crucible.txt", "94"),
+    example!("lavaduct_lagoon", "Part1", "lavaduct_lagoon.txt", "62"),
+    example!("lavaduct_lagoon", "Part2", "lavaduct_lagoon.txt", "952408144115"),
+    example!("aplenty", "Part1", "aplenty.txt", "19114"),
+    example!("aplenty", "Part2", "aplenty.txt", "167409079868000"),
+    example!("pulse_propagation", "Part1", "pulse_propagation.txt", "32000000"),
+    example!("step_counter", "Part1", "step_counter.txt", "42"),
+    example!("sand_slabs", "Part1", "sand_slabs.txt", "5"),
+    example!("sand_slabs", "Part2", "sand_slabs.txt", "7"),
+    example!("long_walk", "Part1", "long_walk.txt", "94"),
+    example!("long_walk", "Part2", "long_walk.txt", "154"),
+    example!("never_tell_me_the_odds", "Part1", "never_tell_me_the_odds.txt", "0"),
+    example!("never_tell_me_the_odds", "Part2", "never_tell_me_the_odds.txt", "47"),
+    example!("snowverload", "Part1", "snowverload.txt", "54"),
+];
+
+fn app() -> Router {
+    let leptos_options = LeptosOptions::builder()
+        .output_name("aoc2023")
+        .build();
+
+    router(leptos_options)
+}
+
+fn find_problem(year: u16, slug: &str) -> ProblemInfo {
+    YEARS.iter()
+        .find(|problem_year| problem_year.year == year)
+        .and_then(|problem_year| problem_year.problems.iter().find(|problem| problem.slug == slug))
+        .copied()
+        .unwrap_or_else(|| panic!("{} is not registered for {}", slug, year))
+}
+
+async fn read_body(response: axum::response::Response) -> String {
+    let bytes = hyper::body::to_bytes(response.into_body()).await.unwrap();
+    String::from_utf8(bytes.to_vec()).unwrap()
+}
+
+/// The text of the first `<h2>` element, ignoring its attributes
+fn heading(html: &str) -> Option<&str> {
+    let (_, rest) = html.split_once("<h2")?;
+    let (_, rest) = rest.split_once('>')?;
+    let (heading, _) = rest.split_once("</h2>")?;
+    Some(heading)
+}
+
+async fn post(app: &Router, uri: String, fields: &[(&str, &str)]) -> (StatusCode, String) {
+    let body = serde_urlencoded::to_string(fields).unwrap();
+    let request = Request::builder()
+        .method("POST")
+        .uri(uri)
+        .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .header(header::ACCEPT, "application/json")
+        .body(Body::from(body))
+        .unwrap();
+
+    let response = app.clone().oneshot(request).await.unwrap();
+    (response.status(), read_body(response).await)
+}
+
+async fn solve(app: &Router, problem: &ProblemInfo, part: &str, input: &str) -> Value {
+    solve_with_fields(app, problem, &[("part", part), ("input", input)]).await
+}
+
+async fn solve_with_fields(app: &Router, problem: &ProblemInfo, fields: &[(&str, &str)]) -> Value {
+    let body = serde_urlencoded::to_string(fields).unwrap();
+    let request = Request::builder()
+        .method("POST")
+        .uri((problem.solve_path)())
+        .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .header(header::ACCEPT, "application/json")
+        .body(Body::from(body))
+        .unwrap();
+
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let body = read_body(response).await;
+    assert_eq!(status, StatusCode::OK, "{} {:?} failed: {}", problem.slug, fields.first(), body);
+
+    serde_json::from_str(&body).unwrap()
+}
+
+#[tokio::test]
+async fn should_solve_examples() {
+    let app = app();
+
+    for example in EXAMPLES {
+        let problem = find_problem(2023, example.slug);
+        let response = solve(&app, &problem, example.part, example.input).await;
+
+        assert_eq!(response["solution"], example.solution, "{} {}", example.slug, example.part);
+        assert!(response["duration_description"].is_string());
+        // Only the problems that keep statistics report them
+        assert_eq!(response["statistics"].is_string(), example.slug == "hot_springs", "{} {}", example.slug, example.part);
+    }
+}
+
+#[tokio::test]
+async fn should_solve_on_requested_thread_count() {
+    let app = app();
+    let problem = find_problem(2023, "the_floor_will_be_lava");
+    let input = include_str!("examples/2023/the_floor_will_be_lava.txt");
+
+    let core_count = std::thread::available_parallelism().map_or(1, |count| count.get());
+
+    for threads in [1, 3, 1_000_000] {
+        let response = solve_with_fields(&app, &problem, &[("part", "Part2"), ("input", input), ("threads", &threads.to_string())]).await;
+
+        assert_eq!(response["solution"], "51");
+        // No more threads than there are cores, and a single one without the parallel feature
+        let expected = if cfg!(feature = "parallel") { threads.min(core_count) } else { 1 };
+        assert_eq!(response["thread_count"], expected);
+        assert!(response["busy_duration_description"].is_string());
+    }
+}
+
+#[tokio::test]
+async fn should_report_where_input_is_malformed() {
+    let app = app();
+    let problem = find_problem(2023, "cube_conundrum");
+
+    let (status, body) = post(&app, (problem.solve_path)(), &[("part", "Part1"), ("input", "Game 1: 3 blue\nGame 2: 4 purple")]).await;
+    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert!(body.contains("line 2, column 11"), "{}", body);
+
+    // Well-formed tiles, but no tile to start from
+    for slug in ["pipe_maze", "step_counter", "long_walk"] {
+        let problem = find_problem(2023, slug);
+        let (status, body) = post(&app, (problem.solve_path)(), &[("part", "Part1"), ("input", "###\n###\n###")]).await;
+        assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR, "{}", slug);
+        assert!(body.contains("line 1, column 1: expected"), "{}: {}", slug, body);
+    }
+}
+
+#[tokio::test]
+async fn should_report_unsupported_input() {
+    let app = app();
+    let problem = find_problem(2023, "pulse_propagation");
+
+    // Well-formed, but nothing sends pulses to rx
+    let (status, body) = post(&app, (problem.solve_path)(), &[("part", "Part2"), ("input", "broadcaster -> a\n&a -> b")]).await;
+    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert!(body.contains("unsupported input: rx does not get pulses from a single module"), "{}", body);
+}
+
+#[tokio::test]
+async fn should_generate_solvable_inputs() {
+    let app = app();
+
+    for problem_year in YEARS {
+        for problem in problem_year.problems {
+            let body = serde_urlencoded::to_string([("seed", "7"), ("size", "3")]).unwrap();
+            let request = Request::builder()
+                .method("POST")
+                .uri((problem.generate_path)())
+                .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+                .header(header::ACCEPT, "application/json")
+                .body(Body::from(body))
+                .unwrap();
+
+            let response = app.clone().oneshot(request).await.unwrap();
+            let status = response.status();
+            let body = read_body(response).await;
+            assert_eq!(status, StatusCode::OK, "{} failed to generate: {}", problem.slug, body);
+
+            let input: String = serde_json::from_str(&body).unwrap();
+            assert_eq!(Ok(&input), (problem.generate)(7, 3).as_ref(), "{} is not reproducible", problem.slug);
+
+            for part in ["Part1", "Part2"] {
+                let response = solve(&app, problem, part, &input).await;
+                assert!(response["solution"].is_string(), "{} could not solve its generated input in {}", problem.slug, part);
+            }
+        }
+    }
+}
+
+#[tokio::test]
+async fn should_export_graphs_of_examples() {
+    let app = app();
+
+    for example in EXAMPLES.iter().filter(|example| example.part == "Part1") {
+        let problem = find_problem(2023, example.slug);
+        let Some(export_graph_path) = problem.export_graph_path else { continue };
+
+        for format in ["Dot", "Json"] {
+            let body = serde_urlencoded::to_string([("input", example.input), ("format", format)]).unwrap();
+            let request = Request::builder()
+                .method("POST")
+                .uri(export_graph_path())
+                .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+                .header(header::ACCEPT, "application/json")
+                .body(Body::from(body))
+                .unwrap();
+
+            let response = app.clone().oneshot(request).await.unwrap();
+            let status = response.status();
+            let body = read_body(response).await;
+            assert_eq!(status, StatusCode::OK, "{} failed to export {}: {}", example.slug, format, body);
+
+            let response: Value = serde_json::from_str(&body).unwrap();
+            assert_eq!(response["format"], format);
+            let content = response["content"].as_str().unwrap();
+            if format == "Dot" {
+                assert!(content.starts_with("digraph {") || content.starts_with("graph {"), "{} exported {}", example.slug, content);
+            } else {
+                let graph: Value = serde_json::from_str(content).unwrap();
+                assert!(graph["nodes"].as_array().is_some_and(|nodes| !nodes.is_empty()), "{} exported no nodes", example.slug);
+                assert!(graph["edges"].as_array().is_some_and(|edges| !edges.is_empty()), "{} exported no edges", example.slug);
+            }
+        }
+    }
+}
+
+#[tokio::test]
+async fn should_report_where_exported_input_is_malformed() {
+    let app = app();
+    let problem = find_problem(2023, "pulse_propagation");
+
+    let (status, body) = post(&app, (problem.export_graph_path.unwrap())(), &[("input", "broadcaster -> a\n%a -> "), ("format", "Dot")]).await;
+    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert!(body.contains("line 2, column 7"), "{}", body);
+}
+
+#[tokio::test]
+async fn should_serve_problem_pages() {
+    let app = app();
+
+    for problem_year in YEARS {
+        for problem in problem_year.problems {
+            let request = Request::builder()
+                .uri(format!("/{}/problems/{}", problem_year.year, problem.slug))
+                .body(Body::empty())
+                .unwrap();
+
+            let response = app.clone().oneshot(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK, "{}", problem.slug);
+
+            let html = read_body(response).await;
+            assert!(heading(&html).is_some_and(|heading| heading.contains(problem.title)), "{} has no heading", problem.slug);
+            assert!(html.contains(&format!("action=\"{}\"", (problem.solve_path)())), "{} has no form", problem.slug);
+            assert!(html.contains(&format!("action=\"{}\"", (problem.generate_path)())), "{} has no generator", problem.slug);
+            if let Some(export_graph_path) = problem.export_graph_path {
+                assert!(html.contains(&format!("action=\"{}\"", export_graph_path())), "{} has no graph export", problem.slug);
+            }
+        }
+    }
+}
+
+#[tokio::test]
+async fn should_list_years_on_home_page() {
+    let app = app();
+    let request = Request::builder()
+        .uri("/")
+        .body(Body::empty())
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let html = read_body(response).await;
+    assert!(html.contains("Welcome to Advent of Code!"));
+    for problem_year in YEARS {
+        assert!(html.contains(&format!("{}/problems\"", problem_year.year)));
+    }
+}